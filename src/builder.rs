@@ -0,0 +1,209 @@
+//! The [`RandomSplitter`] builder for configurable splits.
+
+use crate::{
+    chunk_sizes, deal_with_random_extras, eq_part_sizes, resolve_parts, shuffle_and_carve, EmptyPartsPolicy, Parts,
+    RemainderStrategy, SplitError,
+};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use rand::rngs::StdRng;
+#[cfg(feature = "std")]
+use rand::SeedableRng;
+use rand::Rng;
+
+/// How the shuffled elements are assigned to a fixed number of parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Strategy {
+    /// Elements are dealt out one by one, as in [`split_rand_vec_eq`](crate::split_rand_vec_eq).
+    #[default]
+    RoundRobin,
+    /// The shuffled vector is cut into blocks, as in [`split_rand_vec_eq_contiguous`](crate::split_rand_vec_eq_contiguous).
+    Contiguous,
+}
+
+/// Builder for configurable random splits.
+///
+/// Exactly one of [`parts`](RandomSplitter::parts) and [`chunk_size`](RandomSplitter::chunk_size) must be set.
+/// The other options default to the behavior of [`split_rand_vec_eq`](crate::split_rand_vec_eq) and
+/// [`split_rand_vec`](crate::split_rand_vec). A splitter can be reused for several calls to
+/// [`split`](RandomSplitter::split). With a seed, every call shuffles with a freshly seeded RNG, so equal inputs
+/// produce equal outputs.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use rust_helpers::{RandomSplitter, Strategy};
+/// let splitter = RandomSplitter::new().seed(42).parts(4).strategy(Strategy::Contiguous);
+/// let parts = splitter.split((0..10).collect::<Vec<i32>>()).unwrap();
+/// assert_eq!(parts.sizes(), vec![3, 3, 2, 2]);
+/// assert_eq!(parts, splitter.split((0..10).collect()).unwrap());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RandomSplitter {
+    seed: Option<u64>,
+    parts: Option<usize>,
+    chunk_size: Option<usize>,
+    strategy: Strategy,
+    remainder: RemainderStrategy,
+    empty_parts: EmptyPartsPolicy,
+}
+
+impl RandomSplitter {
+    /// Creates a splitter with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shuffles with an RNG seeded from `seed` instead of the thread RNG.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Splits into `parts` parts of equal size.
+    pub fn parts(mut self, parts: usize) -> Self {
+        self.parts = Some(parts);
+        self
+    }
+
+    /// Splits into chunks of `chunk_size` elements.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Sets how elements are assigned when splitting into a number of parts.
+    pub fn strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets how leftover elements are handled when splitting into chunks.
+    pub fn remainder(mut self, remainder: RemainderStrategy) -> Self {
+        self.remainder = remainder;
+        self
+    }
+
+    /// Sets what happens when more parts are requested than there are elements.
+    pub fn empty_parts(mut self, policy: EmptyPartsPolicy) -> Self {
+        self.empty_parts = policy;
+        self
+    }
+
+    /// Splits `vec` according to the configured options.
+    ///
+    /// Returns [`SplitError::InvalidConfig`] if both or neither of `parts` and `chunk_size` are set, and the
+    /// errors of the underlying split otherwise.
+    #[cfg(feature = "std")]
+    pub fn split<T>(&self, vec: Vec<T>) -> Result<Parts<T>, SplitError> {
+        match self.seed {
+            Some(seed) => self.split_with_rng(vec, &mut StdRng::seed_from_u64(seed)),
+            None => self.split_with_rng(vec, &mut rand::rng()),
+        }
+    }
+
+    /// Like [`split`](RandomSplitter::split), but shuffles with the given RNG and ignores the configured seed.
+    pub fn split_with_rng<T, R: Rng + ?Sized>(&self, vec: Vec<T>, rng: &mut R) -> Result<Parts<T>, SplitError> {
+        match (self.parts, self.chunk_size) {
+            (Some(_), Some(_)) => Err(SplitError::InvalidConfig("parts and chunk_size are mutually exclusive")),
+            (None, None) => Err(SplitError::InvalidConfig("either parts or chunk_size must be set")),
+            (Some(parts), None) => match resolve_parts(vec.len(), parts, self.empty_parts)? {
+                0 => Ok(Vec::new().into()),
+                parts => match self.strategy {
+                    Strategy::RoundRobin => Ok(deal_with_random_extras(vec, parts, rng).into()),
+                    Strategy::Contiguous => {
+                        let sizes = eq_part_sizes(vec.len(), parts);
+                        Ok(shuffle_and_carve(vec, &sizes, rng).into())
+                    }
+                },
+            },
+            (None, Some(chunk_size)) => {
+                let sizes = chunk_sizes(vec.len(), chunk_size, self.remainder)?;
+                Ok(shuffle_and_carve(vec, &sizes, rng).into())
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{split_rand_vec_eq_seeded, split_rand_vec_seeded};
+
+    fn data() -> Vec<i32> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn test_random_splitter_defaults() {
+        let mut sizes = RandomSplitter::new().parts(3).split(data()).unwrap().sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![3, 3, 4]);
+        let chunks = RandomSplitter::new().chunk_size(4).split(data()).unwrap();
+        assert_eq!(chunks.sizes(), vec![4, 4, 2]);
+    }
+
+    #[test]
+    fn test_random_splitter_seed() {
+        let splitter = RandomSplitter::new().seed(7).parts(3);
+        assert_eq!(splitter.split(data()).unwrap(), splitter.split(data()).unwrap());
+        assert_eq!(splitter.split(data()).unwrap().into_inner(), split_rand_vec_eq_seeded(data(), 3, 7));
+
+        let splitter = RandomSplitter::new().seed(7).chunk_size(3);
+        assert_eq!(splitter.split(data()).unwrap().into_inner(), split_rand_vec_seeded(data(), 3, 7));
+    }
+
+    #[test]
+    fn test_random_splitter_strategy() {
+        let parts = RandomSplitter::new().parts(4).strategy(Strategy::Contiguous).split(data()).unwrap();
+        assert_eq!(parts.sizes(), vec![3, 3, 2, 2]);
+        let mut all = parts.flatten();
+        all.sort();
+        assert_eq!(all, data());
+    }
+
+    #[test]
+    fn test_random_splitter_remainder() {
+        let chunks = RandomSplitter::new().chunk_size(3).remainder(RemainderStrategy::DropRemainder).split(data()).unwrap();
+        assert_eq!(chunks.sizes(), vec![3, 3, 3]);
+        let strict = RandomSplitter::new().chunk_size(3).remainder(RemainderStrategy::Strict).split(data());
+        assert_eq!(strict, Err(SplitError::Indivisible { len: 10, chunk_size: 3 }));
+    }
+
+    #[test]
+    fn test_random_splitter_empty_parts() {
+        let splitter = RandomSplitter::new().parts(5);
+        assert_eq!(splitter.split(vec![1, 2]).unwrap().len(), 5);
+        let shrunk = splitter.empty_parts(EmptyPartsPolicy::ShrinkToLen).split(vec![1, 2]).unwrap();
+        assert_eq!(shrunk.sizes(), vec![1, 1]);
+        let err = splitter.empty_parts(EmptyPartsPolicy::Error).split(vec![1, 2]);
+        assert_eq!(err, Err(SplitError::TooManyParts { parts: 5, len: 2 }));
+    }
+
+    #[test]
+    fn test_random_splitter_invalid_config() {
+        let both = RandomSplitter::new().parts(2).chunk_size(2).split(data());
+        assert_eq!(both, Err(SplitError::InvalidConfig("parts and chunk_size are mutually exclusive")));
+        let neither = RandomSplitter::new().split(data());
+        assert_eq!(neither, Err(SplitError::InvalidConfig("either parts or chunk_size must be set")));
+        assert_eq!(RandomSplitter::new().parts(0).split(data()), Err(SplitError::ZeroParts));
+        assert_eq!(RandomSplitter::new().chunk_size(0).split(data()), Err(SplitError::ZeroChunkSize));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_random_splitter_serde_round_trip() {
+        let splitter = RandomSplitter::new()
+            .seed(3)
+            .parts(4)
+            .strategy(Strategy::Contiguous)
+            .empty_parts(EmptyPartsPolicy::ShrinkToLen);
+        let json = serde_json::to_string(&splitter).unwrap();
+        let restored: RandomSplitter = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, splitter);
+        assert_eq!(restored.split(data()), splitter.split(data()));
+    }
+}
@@ -0,0 +1,512 @@
+//! Random splits that honor constraints on where particular elements end up.
+
+use crate::{eq_part_sizes, SplitError};
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+/// How many randomized assignments [`split_rand_vec_eq_apart`] tries before giving up.
+const APART_ATTEMPTS: usize = 100;
+
+/// Randomizes the order of a vec and splits it into parts of equal size, with some elements pinned to given parts.
+///
+/// Each pinned element is placed into its designated part first, and the remaining elements fill the free slots at
+/// random. Part sizes still differ by at most one; the larger parts are the first ones unless pins require otherwise.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into.
+/// * `pins` - Pairs of an element and the index of the part it must end up in. Each pin takes one matching element
+///   out of `vec`, so an element that occurs twice can be pinned twice.
+///
+/// # Returns
+/// The parts, with the pinned elements at the front of their parts, or an error if `parts` is 0, a pin refers to a
+/// part that does not exist ([`SplitError::PartOutOfRange`]) or to an element that is not in `vec`
+/// ([`SplitError::ItemNotFound`]), or more elements are pinned to a part than fit into a balanced split
+/// ([`SplitError::Unsatisfiable`]).
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_eq_pinned;
+/// let people = vec!["Ann", "Ben", "Cem", "Dana", "Eli", "Finn"];
+/// let groups = split_rand_vec_eq_pinned(people, 2, &[("Ann", 0), ("Ben", 0)]).unwrap();
+/// assert_eq!(&groups[0][..2], &["Ann", "Ben"]);
+/// assert_eq!(groups[1].len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq_pinned<T: PartialEq>(vec: Vec<T>, parts: usize, pins: &[(T, usize)]) -> Result<Vec<Vec<T>>, SplitError> {
+    split_rand_vec_eq_pinned_with_rng(vec, parts, pins, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_eq_pinned`], but shuffles with the given RNG.
+pub fn split_rand_vec_eq_pinned_with_rng<T: PartialEq, R: Rng + ?Sized>(
+    mut vec: Vec<T>,
+    parts: usize,
+    pins: &[(T, usize)],
+    rng: &mut R,
+) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    let len = vec.len();
+    let mut result: Vec<Vec<T>> = (0..parts).map(|_| Vec::new()).collect();
+    for (index, (item, part)) in pins.iter().enumerate() {
+        if *part >= parts {
+            return Err(SplitError::PartOutOfRange { part: *part, parts });
+        }
+        let position = vec.iter().position(|e| e == item).ok_or(SplitError::ItemNotFound { index })?;
+        result[*part].push(vec.swap_remove(position));
+    }
+    let minimums: Vec<usize> = result.iter().map(Vec::len).collect();
+    let sizes = balanced_sizes(len, &minimums)?;
+    vec.shuffle(rng);
+    let mut rest = vec.into_iter();
+    for (part, size) in result.iter_mut().zip(sizes) {
+        part.reserve_exact(size - part.len());
+        part.extend(rest.by_ref().take(size - part.len()));
+    }
+    Ok(result)
+}
+
+/// Randomizes the order of a vec and splits it into parts of equal size, keeping given pairs in different parts.
+///
+/// The constrained elements are assigned first, most constrained first, each to a random part with a free slot
+/// that does not contain an element it must be kept apart from. The remaining elements fill the free slots at
+/// random. If an attempt gets stuck, it is retried with a new random order, up to a fixed number of attempts.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into. The first `len % parts` parts contain one element
+///   more than the others.
+/// * `apart` - Pairs of elements that must not end up in the same part. Each element refers to its first
+///   occurrence in `vec`. If both elements of a pair are equal, the second one refers to the second occurrence, so
+///   two copies of a value can be kept apart.
+///
+/// # Returns
+/// The parts, or an error if `parts` is 0, an element of a pair is not in `vec` ([`SplitError::ItemNotFound`]), or
+/// no valid assignment was found ([`SplitError::Unsatisfiable`]). The latter is the case when, for example, three
+/// elements must all be kept apart from each other but there are only two parts.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_eq_apart;
+/// let kids = vec!["Ann", "Ben", "Cem", "Dana", "Eli", "Finn"];
+/// let teams = split_rand_vec_eq_apart(kids, 2, &[("Ann", "Ben"), ("Cem", "Dana")]).unwrap();
+/// assert!(teams.iter().all(|team| !(team.contains(&"Ann") && team.contains(&"Ben"))));
+/// assert!(teams.iter().all(|team| !(team.contains(&"Cem") && team.contains(&"Dana"))));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq_apart<T: PartialEq>(vec: Vec<T>, parts: usize, apart: &[(T, T)]) -> Result<Vec<Vec<T>>, SplitError> {
+    split_rand_vec_eq_apart_with_rng(vec, parts, apart, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_eq_apart`], but shuffles with the given RNG.
+pub fn split_rand_vec_eq_apart_with_rng<T: PartialEq, R: Rng + ?Sized>(
+    vec: Vec<T>,
+    parts: usize,
+    apart: &[(T, T)],
+    rng: &mut R,
+) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    let mut pairs = Vec::with_capacity(apart.len());
+    for (index, (a, b)) in apart.iter().enumerate() {
+        let find = |item: &T, skip: Option<usize>| {
+            (0..vec.len()).find(|&i| Some(i) != skip && vec[i] == *item).ok_or(SplitError::ItemNotFound { index })
+        };
+        let a = find(a, None)?;
+        pairs.push((a, find(b, Some(a))?));
+    }
+    let mut indexed: Vec<(usize, T)> = vec.into_iter().enumerate().collect();
+    indexed.shuffle(rng);
+    let mut slot = vec![0; indexed.len()];
+    for (new, (old, _)) in indexed.iter().enumerate() {
+        slot[*old] = new;
+    }
+    let vec: Vec<T> = indexed.into_iter().map(|(_, e)| e).collect();
+    let mut conflicts: Vec<Vec<usize>> = vec.iter().map(|_| Vec::new()).collect();
+    for (a, b) in pairs {
+        conflicts[slot[a]].push(slot[b]);
+        conflicts[slot[b]].push(slot[a]);
+    }
+    let sizes = eq_part_sizes(vec.len(), parts);
+    let mut order: Vec<usize> = (0..vec.len()).filter(|&i| !conflicts[i].is_empty()).collect();
+    for _ in 0..APART_ATTEMPTS {
+        order.shuffle(rng);
+        order.sort_by_key(|&i| Reverse(conflicts[i].len()));
+        if let Some(assignment) = assign_apart(&order, &conflicts, &sizes, rng) {
+            let mut result: Vec<Vec<T>> = sizes.iter().map(|&size| Vec::with_capacity(size)).collect();
+            for (e, part) in vec.into_iter().zip(assignment) {
+                result[part].push(e);
+            }
+            return Ok(result);
+        }
+    }
+    Err(SplitError::Unsatisfiable)
+}
+
+/// Assigns the elements in `order` to random parts that have room and hold none of their conflicts, then fills
+/// the remaining slots with the other elements in index order. Returns the part of every element, or `None` if
+/// an element in `order` could not be placed.
+fn assign_apart<R: Rng + ?Sized>(order: &[usize], conflicts: &[Vec<usize>], sizes: &[usize], rng: &mut R) -> Option<Vec<usize>> {
+    let mut part_of: Vec<Option<usize>> = vec![None; conflicts.len()];
+    let mut free = sizes.to_vec();
+    for &i in order {
+        let allowed: Vec<usize> = (0..free.len())
+            .filter(|&part| free[part] > 0 && conflicts[i].iter().all(|&j| part_of[j] != Some(part)))
+            .collect();
+        let total: usize = allowed.iter().map(|&part| free[part]).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut slot = rng.random_range(0..total);
+        for &part in &allowed {
+            if slot < free[part] {
+                free[part] -= 1;
+                part_of[i] = Some(part);
+                break;
+            }
+            slot -= free[part];
+        }
+    }
+    let mut open = free.iter().enumerate().flat_map(|(part, &count)| core::iter::repeat_n(part, count));
+    Some(part_of.into_iter().map(|part| part.or_else(|| open.next()).expect("every element has a slot")).collect())
+}
+
+/// Randomizes the order of a vec and splits it into parts of nearly equal size, keeping given pairs in the same part.
+///
+/// The pairs are joined into clusters, so `(a, b)` and `(b, c)` keep `a`, `b` and `c` together. The clusters are
+/// assigned as a whole, largest first, to random parts with room left, backtracking whenever a cluster no longer
+/// fits. The unconstrained elements then fill the remaining slots at random. The search is exhaustive, so it can
+/// take long for many clusters that only just fit.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into.
+/// * `together` - Pairs of elements that must end up in the same part. Each element refers to its first occurrence
+///   in `vec`.
+///
+/// # Returns
+/// The parts, whose sizes differ by at most one, or an error if `parts` is 0, an element of a pair is not in `vec`
+/// ([`SplitError::ItemNotFound`]), or the clusters cannot be arranged into such parts ([`SplitError::Unsatisfiable`]).
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_eq_together;
+/// let kids = vec!["Ann", "Ben", "Cem", "Dana", "Eli", "Finn"];
+/// let teams = split_rand_vec_eq_together(kids, 2, &[("Ann", "Ben")]).unwrap();
+/// assert!(teams.iter().any(|team| team.contains(&"Ann") && team.contains(&"Ben")));
+/// assert_eq!(teams[0].len() + teams[1].len(), 6);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq_together<T: PartialEq>(vec: Vec<T>, parts: usize, together: &[(T, T)]) -> Result<Vec<Vec<T>>, SplitError> {
+    split_rand_vec_eq_together_with_rng(vec, parts, together, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_eq_together`], but shuffles with the given RNG.
+pub fn split_rand_vec_eq_together_with_rng<T: PartialEq, R: Rng + ?Sized>(
+    vec: Vec<T>,
+    parts: usize,
+    together: &[(T, T)],
+    rng: &mut R,
+) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    let len = vec.len();
+    let mut root: Vec<usize> = (0..len).collect();
+    for (index, (a, b)) in together.iter().enumerate() {
+        let position = |item: &T| vec.iter().position(|e| e == item).ok_or(SplitError::ItemNotFound { index });
+        let (a, b) = (position(a)?, position(b)?);
+        let (a, b) = (find_root(&mut root, a), find_root(&mut root, b));
+        root[a] = b;
+    }
+
+    let mut cluster_of = vec![usize::MAX; len];
+    let mut clusters: Vec<Vec<T>> = Vec::new();
+    for (i, e) in vec.into_iter().enumerate() {
+        let r = find_root(&mut root, i);
+        if cluster_of[r] == usize::MAX {
+            cluster_of[r] = clusters.len();
+            clusters.push(Vec::new());
+        }
+        clusters[cluster_of[r]].push(e);
+    }
+    let (base, extra) = (len / parts, len % parts);
+    let (singles, mut groups): (Vec<Vec<T>>, Vec<Vec<T>>) =
+        clusters.into_iter().partition(|cluster| cluster.len() == 1);
+    if groups.iter().any(|group| group.len() > len.div_ceil(parts)) {
+        return Err(SplitError::Unsatisfiable);
+    }
+    groups.shuffle(rng);
+    groups.sort_by_key(|group| Reverse(group.len()));
+    let sizes: Vec<usize> = groups.iter().map(Vec::len).collect();
+    let (loads, group_part) = place_clusters(&sizes, parts, base, extra, rng).ok_or(SplitError::Unsatisfiable)?;
+
+    // The single elements top every part up to `base`, and `extra` parts to `base + 1`.
+    let full = loads.iter().filter(|&&load| load > base).count();
+    let mut open: Vec<usize> = (0..parts).filter(|&part| loads[part] <= base).collect();
+    open.shuffle(rng);
+    let mut targets: Vec<usize> = loads.iter().map(|&load| base.max(load)).collect();
+    for &part in open.iter().take(extra - full) {
+        targets[part] = base + 1;
+    }
+    let mut result: Vec<Vec<T>> = targets.iter().map(|&target| Vec::with_capacity(target)).collect();
+    for (group, part) in groups.into_iter().zip(group_part) {
+        result[part].extend(group);
+    }
+    let mut singles: Vec<T> = singles.into_iter().flatten().collect();
+    singles.shuffle(rng);
+    let mut singles = singles.into_iter();
+    for (part, target) in result.iter_mut().zip(targets) {
+        let missing = target - part.len();
+        part.extend(singles.by_ref().take(missing));
+        part.shuffle(rng);
+    }
+    Ok(result)
+}
+
+/// Assigns clusters of the given sizes, largest first, to parts so that no part gets more than `base + 1`
+/// elements and at most `extra` parts get that many, backtracking on dead ends.
+///
+/// Parts with equal loads are interchangeable, so only one of them is tried per cluster, and states that already
+/// failed are not explored again. Returns the load of every
+/// part and the part of every cluster, or `None` if no such assignment exists.
+fn place_clusters<R: Rng + ?Sized>(sizes: &[usize], parts: usize, base: usize, extra: usize, rng: &mut R) -> Option<(Vec<usize>, Vec<usize>)> {
+    let mut loads = vec![0; parts];
+    let mut cluster_part = vec![0; sizes.len()];
+    let mut choices: Vec<Vec<usize>> = Vec::with_capacity(sizes.len());
+    // States that are known to be dead ends: the next cluster and the sorted loads.
+    let mut dead: BTreeSet<(usize, Vec<usize>)> = BTreeSet::new();
+    let sorted = |loads: &[usize]| {
+        let mut loads = loads.to_vec();
+        loads.sort_unstable();
+        loads
+    };
+    let mut i = 0;
+    while i < sizes.len() {
+        if choices.len() == i && dead.contains(&(i, sorted(&loads))) {
+            choices.push(Vec::new());
+        } else if choices.len() == i {
+            let full = loads.iter().filter(|&&load| load > base).count();
+            let mut candidates: Vec<usize> = (0..parts).collect();
+            candidates.shuffle(rng);
+            let mut tried: Vec<usize> = Vec::new();
+            candidates.retain(|&part| {
+                let load = loads[part] + sizes[i];
+                let fits = load <= base || (load == base + 1 && full < extra);
+                let fresh = !tried.contains(&loads[part]);
+                if fits && fresh {
+                    tried.push(loads[part]);
+                }
+                fits && fresh
+            });
+            choices.push(candidates);
+        }
+        match choices[i].pop() {
+            Some(part) => {
+                loads[part] += sizes[i];
+                cluster_part[i] = part;
+                i += 1;
+            }
+            None => {
+                choices.pop();
+                dead.insert((i, sorted(&loads)));
+                i = i.checked_sub(1)?;
+                loads[cluster_part[i]] -= sizes[i];
+            }
+        }
+    }
+    Some((loads, cluster_part))
+}
+
+/// Finds the representative of `i` in a union-find forest, halving paths on the way.
+fn find_root(root: &mut [usize], mut i: usize) -> usize {
+    while root[i] != i {
+        root[i] = root[root[i]];
+        i = root[i];
+    }
+    i
+}
+
+/// Computes part sizes that differ by at most one, add up to `len` and are at least `minimums`.
+///
+/// The extra elements beyond `len / parts` go to the parts that need them first, then to the first remaining parts.
+fn balanced_sizes(len: usize, minimums: &[usize]) -> Result<Vec<usize>, SplitError> {
+    let parts = minimums.len();
+    let (base, mut extra) = (len / parts, len % parts);
+    let mut sizes = vec![base; parts];
+    for (size, &min) in sizes.iter_mut().zip(minimums) {
+        if min > base + 1 || (min > base && extra == 0) {
+            return Err(SplitError::Unsatisfiable);
+        }
+        if min > base {
+            *size += 1;
+            extra -= 1;
+        }
+    }
+    for size in sizes.iter_mut().filter(|size| **size == base).take(extra) {
+        *size += 1;
+    }
+    Ok(sizes)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_rand_vec_eq_pinned() {
+        for _ in 0..200 {
+            let parts = split_rand_vec_eq_pinned((0..10).collect(), 3, &[(7, 2), (3, 0), (5, 2)]).unwrap();
+            assert!(parts[0].contains(&3));
+            assert!(parts[2].contains(&7) && parts[2].contains(&5));
+            let mut sizes: Vec<usize> = parts.iter().map(Vec::len).collect();
+            sizes.sort();
+            assert_eq!(sizes, vec![3, 3, 4]);
+            let mut all: Vec<i32> = parts.into_iter().flatten().collect();
+            all.sort();
+            assert_eq!(all, (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_pinned_moves_extra_slot() {
+        let parts = split_rand_vec_eq_pinned((0..5).collect(), 2, &[(0, 1), (1, 1), (2, 1)]).unwrap();
+        assert_eq!(parts[0].len(), 2);
+        assert_eq!(&parts[1][..3], &[0, 1, 2]);
+        let unpinned = split_rand_vec_eq_pinned((0..5).collect::<Vec<i32>>(), 2, &[]).unwrap();
+        assert_eq!(unpinned.iter().map(Vec::len).collect::<Vec<_>>(), vec![3, 2]);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_apart() {
+        let apart = [(0, 1), (2, 3), (4, 5), (0, 2)];
+        for _ in 0..200 {
+            let parts = split_rand_vec_eq_apart((0..10).collect(), 2, &apart).unwrap();
+            for (a, b) in apart {
+                assert!(parts.iter().all(|part| !(part.contains(&a) && part.contains(&b))));
+            }
+            assert_eq!(parts.iter().map(Vec::len).collect::<Vec<_>>(), vec![5, 5]);
+            let mut all: Vec<i32> = parts.into_iter().flatten().collect();
+            all.sort();
+            assert_eq!(all, (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_apart_unsatisfiable() {
+        let mutual = [(0, 1), (1, 2), (0, 2)];
+        assert_eq!(split_rand_vec_eq_apart((0..6).collect(), 2, &mutual), Err(SplitError::Unsatisfiable));
+        assert!(split_rand_vec_eq_apart((0..6).collect(), 3, &mutual).is_ok());
+        assert_eq!(split_rand_vec_eq_apart(vec![1, 2], 2, &[(1, 1)]), Err(SplitError::ItemNotFound { index: 0 }));
+        assert_eq!(split_rand_vec_eq_apart(vec![1, 2], 2, &[(1, 3)]), Err(SplitError::ItemNotFound { index: 0 }));
+        assert_eq!(split_rand_vec_eq_apart(vec![1, 2], 0, &[]), Err(SplitError::ZeroParts));
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_apart_refers_to_first_occurrences() {
+        // Equal by value, told apart by the second field.
+        #[derive(Debug)]
+        struct Tagged(u8, usize);
+        impl PartialEq for Tagged {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        let vec = || vec![Tagged(1, 0), Tagged(2, 1), Tagged(1, 2), Tagged(2, 3), Tagged(1, 4), Tagged(3, 5)];
+        let part_of = |parts: &[Vec<Tagged>], id: usize| parts.iter().position(|part| part.iter().any(|c| c.1 == id));
+        for _ in 0..200 {
+            let parts = split_rand_vec_eq_apart(vec(), 2, &[(Tagged(1, 9), Tagged(2, 9))]).unwrap();
+            assert_ne!(part_of(&parts, 0), part_of(&parts, 1));
+            let parts = split_rand_vec_eq_apart(vec(), 3, &[(Tagged(1, 9), Tagged(1, 9))]).unwrap();
+            assert_ne!(part_of(&parts, 0), part_of(&parts, 2));
+        }
+        assert!(split_rand_vec_eq_apart(vec![5, 5], 2, &[(5, 5)]).is_ok());
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_apart_rest_is_random() {
+        let runs = 2000;
+        let in_first = (0..runs)
+            .filter(|_| split_rand_vec_eq_apart((0..10).collect(), 2, &[(0, 1)]).unwrap()[0].contains(&9))
+            .count();
+        assert!((800..1200).contains(&in_first), "element 9 was in the first part {} times", in_first);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_together() {
+        let together = [(0, 1), (1, 2), (5, 6)];
+        for _ in 0..200 {
+            let parts = split_rand_vec_eq_together((0..12).collect(), 3, &together).unwrap();
+            for (a, b) in together {
+                assert!(parts.iter().any(|part| part.contains(&a) && part.contains(&b)));
+            }
+            let sizes: Vec<usize> = parts.iter().map(Vec::len).collect();
+            assert_eq!(sizes.iter().sum::<usize>(), 12);
+            assert!(sizes.iter().max().unwrap() - sizes.iter().min().unwrap() <= 1, "unbalanced sizes {:?}", sizes);
+        }
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_together_errors() {
+        assert_eq!(
+            split_rand_vec_eq_together((0..6).collect(), 2, &[(0, 1), (1, 2), (2, 3)]),
+            Err(SplitError::Unsatisfiable)
+        );
+        assert!(split_rand_vec_eq_together((0..6).collect(), 2, &[(0, 1), (1, 2)]).is_ok());
+        assert_eq!(split_rand_vec_eq_together(vec![1, 2], 2, &[(3, 1)]), Err(SplitError::ItemNotFound { index: 0 }));
+        assert_eq!(split_rand_vec_eq_together(vec![1, 2], 0, &[]), Err(SplitError::ZeroParts));
+        // Each of the clusters fits, but only one part may get the extra element.
+        assert_eq!(split_rand_vec_eq_together((0..4).collect(), 3, &[(0, 1), (2, 3)]), Err(SplitError::Unsatisfiable));
+        let pairs = [(0, 1), (2, 3), (4, 5)];
+        assert_eq!(split_rand_vec_eq_together((0..6).collect(), 2, &pairs), Err(SplitError::Unsatisfiable));
+        assert!(split_rand_vec_eq_together((0..7).collect(), 2, &pairs).is_ok());
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_together_gives_up_quickly_on_unsatisfiable_clusters() {
+        // 31 triples and a single element cannot form two parts of 47.
+        let together: Vec<(usize, usize)> =
+            (0..31).flat_map(|c| [(3 * c, 3 * c + 1), (3 * c + 1, 3 * c + 2)]).collect();
+        assert_eq!(split_rand_vec_eq_together((0..94).collect(), 2, &together), Err(SplitError::Unsatisfiable));
+        let parts = split_rand_vec_eq_together((0..96).collect(), 4, &together).unwrap();
+        assert!(parts.iter().all(|part| part.len() == 24));
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_together_finds_balanced_assignment() {
+        // Clusters of sizes 3, 3, 2, 2 and 2, which largest-first greedy dealing splits 7/5.
+        let together = [(0, 1), (1, 2), (3, 4), (4, 5), (6, 7), (8, 9), (10, 11)];
+        for _ in 0..200 {
+            let parts = split_rand_vec_eq_together((0..12).collect(), 2, &together).unwrap();
+            assert_eq!(parts.iter().map(Vec::len).collect::<Vec<_>>(), vec![6, 6]);
+            for (a, b) in together {
+                assert!(parts.iter().any(|part| part.contains(&a) && part.contains(&b)));
+            }
+        }
+        let parts = split_rand_vec_eq_together((0..13).collect(), 3, &together).unwrap();
+        let mut sizes: Vec<usize> = parts.iter().map(Vec::len).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![4, 4, 5]);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_pinned_errors() {
+        assert_eq!(split_rand_vec_eq_pinned(vec![1, 2], 0, &[]), Err(SplitError::ZeroParts));
+        assert_eq!(
+            split_rand_vec_eq_pinned(vec![1, 2, 3], 2, &[(1, 2)]),
+            Err(SplitError::PartOutOfRange { part: 2, parts: 2 })
+        );
+        assert_eq!(
+            split_rand_vec_eq_pinned(vec![1, 2, 3], 2, &[(1, 0), (9, 1)]),
+            Err(SplitError::ItemNotFound { index: 1 })
+        );
+        assert_eq!(
+            split_rand_vec_eq_pinned(vec![1, 2, 3], 2, &[(1, 0), (1, 1)]),
+            Err(SplitError::ItemNotFound { index: 1 })
+        );
+        assert_eq!(
+            split_rand_vec_eq_pinned(vec![1, 2, 3, 4], 2, &[(1, 0), (2, 0), (3, 0)]),
+            Err(SplitError::Unsatisfiable)
+        );
+    }
+}
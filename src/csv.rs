@@ -0,0 +1,105 @@
+//! CSV export of split results, e.g. for opening groupings in a spreadsheet.
+//!
+//! Fields are escaped as described in RFC 4180: a field containing a comma, a double quote or a line break is
+//! wrapped in double quotes, with inner double quotes doubled. Records end with `\r\n`.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+/// Converts parts to a CSV table with a `group` and a `member` column and one row per element.
+///
+/// Groups are numbered from 1 by their position. Empty parts produce no rows.
+/// # Arguments
+/// * `parts` - The parts to convert.
+///
+/// # Returns
+/// The CSV table, including a header row.
+/// # Example
+/// ```
+/// use rust_helpers::parts_to_csv;
+/// let parts = vec![vec!["Ann", "Doe, Ben"], vec!["Cem"]];
+/// assert_eq!(parts_to_csv(&parts), "group,member\r\n1,Ann\r\n1,\"Doe, Ben\"\r\n2,Cem\r\n");
+/// ```
+pub fn parts_to_csv<T: Display>(parts: &[Vec<T>]) -> String {
+    let mut csv = String::from("group,member\r\n");
+    for (i, part) in parts.iter().enumerate() {
+        for element in part {
+            push_record(&mut csv, &[(i + 1).to_string(), element.to_string()]);
+        }
+    }
+    csv
+}
+
+/// Converts parts to a CSV table with one column per part, headed `Group 1`, `Group 2` and so on.
+///
+/// Shorter parts are padded with empty cells, so every row has one cell per part.
+/// # Arguments
+/// * `parts` - The parts to convert.
+///
+/// # Returns
+/// The CSV table, including a header row.
+/// # Example
+/// ```
+/// use rust_helpers::parts_to_csv_wide;
+/// let parts = vec![vec!["Ann", "Ben"], vec!["Cem"]];
+/// assert_eq!(parts_to_csv_wide(&parts), "Group 1,Group 2\r\nAnn,Cem\r\nBen,\r\n");
+/// ```
+pub fn parts_to_csv_wide<T: Display>(parts: &[Vec<T>]) -> String {
+    let mut csv = String::new();
+    let header: Vec<String> = (1..=parts.len()).map(|i| format!("Group {}", i)).collect();
+    push_record(&mut csv, &header);
+    let rows = parts.iter().map(Vec::len).max().unwrap_or(0);
+    for row in 0..rows {
+        let record: Vec<String> = parts
+            .iter()
+            .map(|part| part.get(row).map_or_else(String::new, |element| element.to_string()))
+            .collect();
+        push_record(&mut csv, &record);
+    }
+    csv
+}
+
+fn push_record(csv: &mut String, fields: &[String]) {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            csv.push(',');
+        }
+        if field.contains([',', '"', '\r', '\n']) {
+            csv.push('"');
+            csv.push_str(&field.replace('"', "\"\""));
+            csv.push('"');
+        } else {
+            csv.push_str(field);
+        }
+    }
+    csv.push_str("\r\n");
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn parts() -> Vec<Vec<&'static str>> {
+        vec![vec!["Doe, Ann", "Ben \"Benny\" Roe"], vec![], vec!["Cem\nOzan"]]
+    }
+
+    #[test]
+    fn test_parts_to_csv_escapes_fields() {
+        assert_eq!(
+            parts_to_csv(&parts()),
+            "group,member\r\n1,\"Doe, Ann\"\r\n1,\"Ben \"\"Benny\"\" Roe\"\r\n3,\"Cem\nOzan\"\r\n"
+        );
+        assert_eq!(parts_to_csv::<u32>(&[]), "group,member\r\n");
+    }
+
+    #[test]
+    fn test_parts_to_csv_wide_pads_columns() {
+        assert_eq!(
+            parts_to_csv_wide(&parts()),
+            "Group 1,Group 2,Group 3\r\n\"Doe, Ann\",,\"Cem\nOzan\"\r\n\"Ben \"\"Benny\"\" Roe\",,\r\n"
+        );
+        assert_eq!(parts_to_csv_wide::<u32>(&[vec![], vec![]]), "Group 1,Group 2\r\n");
+    }
+}
@@ -0,0 +1,472 @@
+//! Error types returned by the fallible helpers.
+
+use crate::ItemStatus;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+/// Errors returned by the non-panicking functions of this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub enum SplitError {
+    /// A chunk size of zero was requested.
+    ZeroChunkSize,
+    /// A split into zero parts was requested.
+    ZeroParts,
+    /// More non-empty parts were requested than there are elements.
+    TooManyParts {
+        /// The number of requested parts.
+        parts: usize,
+        /// The number of available elements.
+        len: usize,
+    },
+    /// The number of elements is not divisible by the chunk size.
+    Indivisible {
+        /// The number of elements.
+        len: usize,
+        /// The requested chunk size.
+        chunk_size: usize,
+    },
+    /// No ratios were given.
+    EmptyRatios,
+    /// A ratio is negative, infinite or NaN.
+    InvalidRatio {
+        /// The position of the offending ratio.
+        index: usize,
+    },
+    /// All ratios are zero, so they cannot be normalized.
+    ZeroRatioSum,
+    /// The requested part sizes do not add up to the number of elements.
+    SizesMismatch {
+        /// The number of elements.
+        expected: usize,
+        /// The sum of the requested sizes, or `usize::MAX` if the sum overflows.
+        got: usize,
+    },
+    /// A fraction is not strictly between 0.0 and 1.0.
+    InvalidFraction,
+    /// The input contains no elements, but elements are required.
+    EmptyInput,
+    /// A part index is not smaller than the number of parts.
+    PartOutOfRange {
+        /// The offending part index.
+        part: usize,
+        /// The number of parts.
+        parts: usize,
+    },
+    /// An element referred to by a constraint is not in the input.
+    ItemNotFound {
+        /// The position of the offending constraint.
+        index: usize,
+    },
+    /// An index is not smaller than the number of elements.
+    IndexOutOfRange {
+        /// The offending index.
+        index: usize,
+        /// The number of elements.
+        len: usize,
+    },
+    /// An index occurs more than once.
+    DuplicateIndex {
+        /// The repeated index.
+        index: usize,
+    },
+    /// A part that must not be empty is empty.
+    EmptyPart {
+        /// The position of the empty part.
+        index: usize,
+    },
+    /// The part sizes differ by more than allowed.
+    Unbalanced {
+        /// The size of the smallest part.
+        smallest: usize,
+        /// The size of the largest part.
+        largest: usize,
+    },
+    /// The constraints cannot all be satisfied.
+    Unsatisfiable,
+    /// A name was given more than once.
+    DuplicateName(String),
+    /// The split configuration is invalid or contradictory.
+    InvalidConfig(&'static str),
+    /// Two inputs that must be equally long differ in length.
+    LengthMismatch {
+        /// The length of the first input.
+        left: usize,
+        /// The length of the second input.
+        right: usize,
+    },
+    /// The input has fewer elements than the operation needs.
+    TooFewElements {
+        /// The minimum number of elements.
+        required: usize,
+        /// The number of available elements.
+        len: usize,
+    },
+}
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SplitError::ZeroChunkSize => write!(f, "chunk size must be greater than zero"),
+            SplitError::ZeroParts => write!(f, "number of parts must be greater than zero"),
+            SplitError::TooManyParts { parts, len } => {
+                write!(f, "requested {} parts but only {} elements are available", parts, len)
+            }
+            SplitError::Indivisible { len, chunk_size } => {
+                write!(f, "{} elements cannot be split evenly into chunks of {}", len, chunk_size)
+            }
+            SplitError::EmptyRatios => write!(f, "at least one ratio is required"),
+            SplitError::InvalidRatio { index } => {
+                write!(f, "ratio at index {} must be finite and non-negative", index)
+            }
+            SplitError::ZeroRatioSum => write!(f, "ratios must not all be zero"),
+            SplitError::SizesMismatch { expected, got } => {
+                write!(f, "part sizes add up to {} but there are {} elements", got, expected)
+            }
+            SplitError::InvalidFraction => write!(f, "fraction must be strictly between 0.0 and 1.0"),
+            SplitError::EmptyInput => write!(f, "input must not be empty"),
+            SplitError::PartOutOfRange { part, parts } => {
+                write!(f, "part {} is out of range for {} parts", part, parts)
+            }
+            SplitError::ItemNotFound { index } => {
+                write!(f, "element of constraint at index {} is not in the input", index)
+            }
+            SplitError::IndexOutOfRange { index, len } => {
+                write!(f, "index {} is out of range for {} elements", index, len)
+            }
+            SplitError::DuplicateIndex { index } => write!(f, "index {} occurs more than once", index),
+            SplitError::EmptyPart { index } => write!(f, "part at index {} is empty", index),
+            SplitError::Unbalanced { smallest, largest } => {
+                write!(f, "part sizes range from {} to {}", smallest, largest)
+            }
+            SplitError::Unsatisfiable => write!(f, "the constraints cannot be satisfied"),
+            SplitError::DuplicateName(name) => write!(f, "name '{}' is given more than once", name),
+            SplitError::InvalidConfig(reason) => write!(f, "invalid split configuration: {}", reason),
+            SplitError::LengthMismatch { left, right } => {
+                write!(f, "inputs must have the same length, but have {} and {} elements", left, right)
+            }
+            SplitError::TooFewElements { required, len } => {
+                write!(f, "expected at least {} elements, got {}", required, len)
+            }
+        }
+    }
+}
+
+impl core::error::Error for SplitError {}
+
+/// Errors returned by the sampling functions of this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SampleError {
+    /// More distinct elements were requested than there are.
+    NotEnoughItems {
+        /// The number of requested elements.
+        requested: usize,
+        /// The number of available elements.
+        available: usize,
+    },
+    /// Elements were requested from an empty collection.
+    EmptyInput,
+    /// A weight is negative, infinite or NaN.
+    InvalidWeight {
+        /// The position of the offending weight.
+        index: usize,
+    },
+    /// Elements were requested, but all weights are zero.
+    ZeroWeightSum,
+    /// A probability is not between 0.0 and 1.0.
+    InvalidProbability,
+}
+
+impl fmt::Display for SampleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SampleError::NotEnoughItems { requested, available } => {
+                write!(f, "cannot sample {} distinct elements from {}", requested, available)
+            }
+            SampleError::EmptyInput => write!(f, "cannot sample from an empty collection"),
+            SampleError::InvalidWeight { index } => {
+                write!(f, "weight at index {} must be finite and non-negative", index)
+            }
+            SampleError::ZeroWeightSum => write!(f, "weights must not all be zero"),
+            SampleError::InvalidProbability => write!(f, "probability must be between 0.0 and 1.0"),
+        }
+    }
+}
+
+impl core::error::Error for SampleError {}
+
+/// Errors returned by the derangement functions of this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum DerangeError {
+    /// A single element cannot leave its position.
+    SingleElement,
+}
+
+impl fmt::Display for DerangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DerangeError::SingleElement => write!(f, "a single element cannot be moved away from its position"),
+        }
+    }
+}
+
+impl core::error::Error for DerangeError {}
+
+/// Error returned when elements cannot be ordered so that no two neighbours share a key.
+///
+/// This happens when more than half of the elements, rounded up, share the same key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NoValidArrangement {
+    /// The number of elements with the most frequent key.
+    pub count: usize,
+    /// The total number of elements.
+    pub len: usize,
+}
+
+impl fmt::Display for NoValidArrangement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} of {} elements share a key, so they cannot all be kept apart", self.count, self.len)
+    }
+}
+
+impl core::error::Error for NoValidArrangement {}
+
+/// Error returned when a range does not fit the slice it refers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RangeError {
+    /// The start of the range.
+    pub start: usize,
+    /// The end of the range.
+    pub end: usize,
+    /// The length of the slice.
+    pub len: usize,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "range {}..{} is out of bounds for {} elements", self.start, self.end, self.len)
+    }
+}
+
+impl core::error::Error for RangeError {}
+
+/// Error returned when items don't meet their requirements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsufficientItemsError<T> {
+    /// Every unmet requirement with its required and present count, in the order of the requirements.
+    pub missing: Vec<(T, ItemStatus)>,
+}
+
+impl<T: fmt::Display> fmt::Display for InsufficientItemsError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (item, status)) in self.missing.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "need {} '{}', found {}", status.required, item, status.present)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> core::error::Error for InsufficientItemsError<T> {}
+
+/// An item whose count lies outside its allowed range, as reported in [`RangeViolations`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CountViolation<T> {
+    /// The item.
+    pub item: T,
+    /// The allowed number of occurrences.
+    pub allowed: RangeInclusive<usize>,
+    /// The actual number of occurrences.
+    pub actual: usize,
+}
+
+impl<T> CountViolation<T> {
+    /// Returns `true` if the item occurs fewer times than allowed.
+    pub fn is_too_few(&self) -> bool {
+        self.actual < *self.allowed.start()
+    }
+
+    /// Returns `true` if the item occurs more often than allowed.
+    pub fn is_too_many(&self) -> bool {
+        self.actual > *self.allowed.end()
+    }
+}
+
+/// Error returned when item counts lie outside their allowed ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RangeViolations<T> {
+    /// Every violated range, in the order of the ranges.
+    pub violations: Vec<CountViolation<T>>,
+}
+
+impl<T: fmt::Display> fmt::Display for RangeViolations<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(
+                f,
+                "need {} to {} '{}', found {}",
+                violation.allowed.start(),
+                violation.allowed.end(),
+                violation.item,
+                violation.actual
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> core::error::Error for RangeViolations<T> {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_error_display() {
+        assert_eq!(SplitError::ZeroChunkSize.to_string(), "chunk size must be greater than zero");
+        assert_eq!(SplitError::ZeroParts.to_string(), "number of parts must be greater than zero");
+        assert_eq!(
+            SplitError::TooManyParts { parts: 5, len: 2 }.to_string(),
+            "requested 5 parts but only 2 elements are available"
+        );
+        assert_eq!(
+            SplitError::Indivisible { len: 7, chunk_size: 3 }.to_string(),
+            "7 elements cannot be split evenly into chunks of 3"
+        );
+        assert_eq!(
+            SplitError::InvalidRatio { index: 2 }.to_string(),
+            "ratio at index 2 must be finite and non-negative"
+        );
+        assert_eq!(
+            SplitError::SizesMismatch { expected: 10, got: 9 }.to_string(),
+            "part sizes add up to 9 but there are 10 elements"
+        );
+        assert_eq!(
+            SplitError::InvalidConfig("parts and chunk_size are mutually exclusive").to_string(),
+            "invalid split configuration: parts and chunk_size are mutually exclusive"
+        );
+        assert_eq!(
+            SplitError::LengthMismatch { left: 4, right: 3 }.to_string(),
+            "inputs must have the same length, but have 4 and 3 elements"
+        );
+        assert_eq!(
+            SplitError::TooFewElements { required: 2, len: 1 }.to_string(),
+            "expected at least 2 elements, got 1"
+        );
+    }
+
+    #[test]
+    fn test_split_error_display_mentions_numbers() {
+        let message = SplitError::TooManyParts { parts: 13, len: 8 }.to_string();
+        assert!(message.contains("13") && message.contains("8"));
+        let message = SplitError::Indivisible { len: 17, chunk_size: 5 }.to_string();
+        assert!(message.contains("17") && message.contains("5"));
+        let message = SplitError::SizesMismatch { expected: 21, got: 19 }.to_string();
+        assert!(message.contains("21") && message.contains("19"));
+        let message = SplitError::DuplicateName("Team Red".to_string()).to_string();
+        assert!(message.contains("'Team Red'"));
+        let message = SplitError::PartOutOfRange { part: 4, parts: 3 }.to_string();
+        assert!(message.contains("4") && message.contains("3"));
+        let message = SplitError::ItemNotFound { index: 2 }.to_string();
+        assert!(message.contains("2"));
+        let message = SplitError::EmptyPart { index: 5 }.to_string();
+        assert!(message.contains("5"));
+        let message = SplitError::Unbalanced { smallest: 2, largest: 6 }.to_string();
+        assert!(message.contains("2") && message.contains("6"));
+        let message = SplitError::IndexOutOfRange { index: 12, len: 9 }.to_string();
+        assert!(message.contains("12") && message.contains("9"));
+        let message = SplitError::DuplicateIndex { index: 4 }.to_string();
+        assert!(message.contains("4"));
+        let message = SplitError::InvalidRatio { index: 3 }.to_string();
+        assert!(message.contains("3"));
+    }
+
+    #[test]
+    fn test_split_error_is_std_error() {
+        let error: Box<dyn std::error::Error> = Box::new(SplitError::EmptyInput);
+        assert_eq!(error.to_string(), "input must not be empty");
+    }
+
+    #[test]
+    fn test_sample_error_display() {
+        assert_eq!(
+            SampleError::NotEnoughItems { requested: 5, available: 3 }.to_string(),
+            "cannot sample 5 distinct elements from 3"
+        );
+        assert_eq!(SampleError::EmptyInput.to_string(), "cannot sample from an empty collection");
+        assert_eq!(
+            SampleError::InvalidWeight { index: 1 }.to_string(),
+            "weight at index 1 must be finite and non-negative"
+        );
+        assert_eq!(SampleError::InvalidProbability.to_string(), "probability must be between 0.0 and 1.0");
+    }
+
+    #[test]
+    fn test_no_valid_arrangement_display() {
+        assert_eq!(
+            NoValidArrangement { count: 4, len: 6 }.to_string(),
+            "4 of 6 elements share a key, so they cannot all be kept apart"
+        );
+    }
+
+    #[test]
+    fn test_range_error_display() {
+        assert_eq!(RangeError { start: 2, end: 9, len: 5 }.to_string(), "range 2..9 is out of bounds for 5 elements");
+    }
+
+    #[test]
+    fn test_insufficient_items_error_display() {
+        let error = InsufficientItemsError {
+            missing: vec![
+                ("apple", ItemStatus { required: 3, present: 1 }),
+                ("egg", ItemStatus { required: 2, present: 0 }),
+            ],
+        };
+        assert_eq!(error.to_string(), "need 3 'apple', found 1; need 2 'egg', found 0");
+    }
+
+    #[test]
+    fn test_range_violations_display() {
+        let error = RangeViolations {
+            violations: vec![
+                CountViolation { item: "moderator", allowed: 2..=4, actual: 1 },
+                CountViolation { item: "photographer", allowed: 0..=1, actual: 3 },
+            ],
+        };
+        assert_eq!(error.to_string(), "need 2 to 4 'moderator', found 1; need 0 to 1 'photographer', found 3");
+        assert!(error.violations[0].is_too_few() && !error.violations[0].is_too_many());
+        assert!(error.violations[1].is_too_many() && !error.violations[1].is_too_few());
+    }
+
+    #[test]
+    fn test_derange_error_display() {
+        assert_eq!(DerangeError::SingleElement.to_string(), "a single element cannot be moved away from its position");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_split_error_serialize() {
+        assert_eq!(serde_json::to_string(&SplitError::ZeroParts).unwrap(), r#""ZeroParts""#);
+        assert_eq!(
+            serde_json::to_string(&SplitError::TooManyParts { parts: 5, len: 2 }).unwrap(),
+            r#"{"TooManyParts":{"parts":5,"len":2}}"#
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! Extension traits that make the split helpers available as methods.
+//!
+//! ```
+//! use rust_helpers::prelude::*;
+//! use std::collections::{BTreeSet, HashSet};
+//! let players: Vec<&str> = vec!["Ann", "Ben", "Cem", "Dana"];
+//! let teams = players.split_rand_eq(2);
+//! assert_eq!(teams.len(), 2);
+//!
+//! let tags: HashSet<&str> = ["red", "green", "blue"].into_iter().collect();
+//! assert_eq!(tags.sample_n(2).len(), 2);
+//! let ids: BTreeSet<u32> = (1..=6).collect();
+//! assert_eq!(ids.split_rand_eq(3)[0].len(), 2);
+//! ```
+
+use crate::{
+    partial_shuffle_k, split_rand_btreeset_eq, split_rand_hashset_eq, split_rand_vec, split_rand_vec_eq,
+    split_rand_vec_eq_seeded, split_rand_vec_seeded, try_split_rand_eq, try_split_rand_hashset_eq, try_split_rand_vec, try_split_rand_vec_eq,
+    SplitError,
+};
+use std::collections::{BTreeSet, HashSet};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Randomizing methods on `Vec<T>`, delegating to the free functions of this crate.
+///
+/// # Example
+/// ```
+/// use rust_helpers::prelude::*;
+/// let players = vec!["Ann", "Ben", "Cem", "Dana", "Eli"];
+/// let teams = players.split_rand_eq(2);
+/// assert_eq!(teams.len(), 2);
+/// assert_eq!(teams[0].len() + teams[1].len(), 5);
+/// ```
+pub trait VecRandExt<T>: Sized {
+    /// Splits into randomized chunks of `chunk_size`. See [`split_rand_vec`].
+    fn split_rand(self, chunk_size: usize) -> Vec<Vec<T>>;
+
+    /// Splits into randomized chunks of `chunk_size` with a seeded RNG. See [`split_rand_vec_seeded`].
+    fn split_rand_seeded(self, chunk_size: usize, seed: u64) -> Vec<Vec<T>>;
+
+    /// Splits into randomized chunks of `chunk_size`, returning an error on invalid arguments. See [`try_split_rand_vec`].
+    fn try_split_rand(self, chunk_size: usize) -> Result<Vec<Vec<T>>, SplitError>;
+
+    /// Splits into `parts` randomized parts of equal size. See [`split_rand_vec_eq`].
+    fn split_rand_eq(self, parts: usize) -> Vec<Vec<T>>;
+
+    /// Splits into `parts` randomized parts of equal size with a seeded RNG. See [`split_rand_vec_eq_seeded`].
+    fn split_rand_eq_seeded(self, parts: usize, seed: u64) -> Vec<Vec<T>>;
+
+    /// Splits into `parts` randomized parts of equal size, returning an error on invalid arguments. See [`try_split_rand_vec_eq`].
+    fn try_split_rand_eq(self, parts: usize) -> Result<Vec<Vec<T>>, SplitError>;
+
+    /// Returns the elements in random order.
+    fn shuffled(self) -> Self;
+
+    /// Returns the elements in random order, shuffled with the given RNG.
+    fn shuffled_with_rng<R: Rng + ?Sized>(self, rng: &mut R) -> Self;
+
+    /// Returns the elements in random order, shuffled with an RNG seeded from `seed`.
+    fn shuffled_seeded(self, seed: u64) -> Self;
+}
+
+impl<T> VecRandExt<T> for Vec<T> {
+    fn split_rand(self, chunk_size: usize) -> Vec<Vec<T>> {
+        split_rand_vec(self, chunk_size)
+    }
+
+    fn split_rand_seeded(self, chunk_size: usize, seed: u64) -> Vec<Vec<T>> {
+        split_rand_vec_seeded(self, chunk_size, seed)
+    }
+
+    fn try_split_rand(self, chunk_size: usize) -> Result<Vec<Vec<T>>, SplitError> {
+        try_split_rand_vec(self, chunk_size)
+    }
+
+    fn split_rand_eq(self, parts: usize) -> Vec<Vec<T>> {
+        split_rand_vec_eq(self, parts)
+    }
+
+    fn split_rand_eq_seeded(self, parts: usize, seed: u64) -> Vec<Vec<T>> {
+        split_rand_vec_eq_seeded(self, parts, seed)
+    }
+
+    fn try_split_rand_eq(self, parts: usize) -> Result<Vec<Vec<T>>, SplitError> {
+        try_split_rand_vec_eq(self, parts)
+    }
+
+    fn shuffled(self) -> Self {
+        self.shuffled_with_rng(&mut rand::rng())
+    }
+
+    fn shuffled_with_rng<R: Rng + ?Sized>(mut self, rng: &mut R) -> Self {
+        self.shuffle(rng);
+        self
+    }
+
+    fn shuffled_seeded(mut self, seed: u64) -> Self {
+        self.shuffle(&mut StdRng::seed_from_u64(seed));
+        self
+    }
+}
+
+/// Randomizing methods on sets, delegating to the free functions of this crate.
+///
+/// All methods consume the set, like [`split_rand_hashset_eq`] does.
+pub trait SetRandExt<T>: Sized {
+    /// Splits into `parts` randomized parts of equal size. See [`split_rand_hashset_eq`] and [`split_rand_btreeset_eq`].
+    fn split_rand_eq(self, parts: usize) -> Vec<Vec<T>>;
+
+    /// Splits into `parts` randomized parts of equal size, returning an error on invalid arguments. See [`try_split_rand_eq`].
+    fn try_split_rand_eq(self, parts: usize) -> Result<Vec<Vec<T>>, SplitError>;
+
+    /// Returns `n` distinct elements in random order, or all elements in random order if the set has fewer than `n`.
+    fn sample_n(self, n: usize) -> Vec<T>;
+
+    /// Like [`sample_n`](SetRandExt::sample_n), but draws with the given RNG.
+    fn sample_n_with_rng<R: Rng + ?Sized>(self, n: usize, rng: &mut R) -> Vec<T>;
+}
+
+impl<T> SetRandExt<T> for HashSet<T> {
+    fn split_rand_eq(self, parts: usize) -> Vec<Vec<T>> {
+        split_rand_hashset_eq(self, parts)
+    }
+
+    fn try_split_rand_eq(self, parts: usize) -> Result<Vec<Vec<T>>, SplitError> {
+        try_split_rand_hashset_eq(self, parts)
+    }
+
+    fn sample_n(self, n: usize) -> Vec<T> {
+        self.sample_n_with_rng(n, &mut rand::rng())
+    }
+
+    fn sample_n_with_rng<R: Rng + ?Sized>(self, n: usize, rng: &mut R) -> Vec<T> {
+        sample_up_to(self.into_iter().collect(), n, rng)
+    }
+}
+
+impl<T> SetRandExt<T> for BTreeSet<T> {
+    fn split_rand_eq(self, parts: usize) -> Vec<Vec<T>> {
+        split_rand_btreeset_eq(self, parts)
+    }
+
+    fn try_split_rand_eq(self, parts: usize) -> Result<Vec<Vec<T>>, SplitError> {
+        try_split_rand_eq(self, parts)
+    }
+
+    fn sample_n(self, n: usize) -> Vec<T> {
+        self.sample_n_with_rng(n, &mut rand::rng())
+    }
+
+    fn sample_n_with_rng<R: Rng + ?Sized>(self, n: usize, rng: &mut R) -> Vec<T> {
+        sample_up_to(self.into_iter().collect(), n, rng)
+    }
+}
+
+/// Draws up to `n` elements of `vec` in random order, shuffling only the drawn prefix.
+fn sample_up_to<T, R: Rng + ?Sized>(mut vec: Vec<T>, n: usize, rng: &mut R) -> Vec<T> {
+    partial_shuffle_k(&mut vec, n, rng);
+    vec.truncate(n);
+    vec
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec_rand_ext_split() {
+        let vec: Vec<i32> = (0..10).collect();
+        assert_eq!(vec.clone().split_rand(4).iter().map(Vec::len).collect::<Vec<_>>(), vec![4, 4, 2]);
+        let mut sizes: Vec<usize> = vec.clone().split_rand_eq(3).iter().map(Vec::len).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![3, 3, 4]);
+        assert_eq!(vec.clone().try_split_rand(0), Err(SplitError::ZeroChunkSize));
+        assert_eq!(vec.clone().try_split_rand_eq(0), Err(SplitError::ZeroParts));
+        assert_eq!(vec.clone().split_rand_seeded(3, 1), split_rand_vec_seeded(vec.clone(), 3, 1));
+        assert_eq!(vec.clone().split_rand_eq_seeded(3, 1), split_rand_vec_eq_seeded(vec, 3, 1));
+    }
+
+    #[test]
+    fn test_vec_rand_ext_shuffled() {
+        let vec: Vec<i32> = (0..50).collect();
+        let mut shuffled = vec.clone().shuffled();
+        shuffled.sort();
+        assert_eq!(shuffled, vec);
+        assert_eq!(vec.clone().shuffled_seeded(3), vec.clone().shuffled_seeded(3));
+        assert_ne!(vec.clone().shuffled_seeded(3), vec);
+        assert_eq!(vec.clone().shuffled_with_rng(&mut StdRng::seed_from_u64(3)), vec.clone().shuffled_seeded(3));
+    }
+
+    #[test]
+    fn test_set_rand_ext_hashset() {
+        let set: HashSet<i32> = (0..7).collect();
+        let mut sizes: Vec<usize> = set.clone().split_rand_eq(3).iter().map(Vec::len).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![2, 2, 3]);
+        assert_eq!(set.clone().try_split_rand_eq(0), Err(SplitError::ZeroParts));
+
+        let sample = set.clone().sample_n(4);
+        assert_eq!(sample.len(), 4);
+        assert!(sample.iter().all(|x| set.contains(x)));
+        assert_eq!(sample.iter().collect::<HashSet<_>>().len(), 4);
+        assert_eq!(set.sample_n(10).len(), 7);
+    }
+
+    #[test]
+    fn test_set_rand_ext_btreeset() {
+        let set: BTreeSet<i32> = (0..7).collect();
+        let parts = set.clone().split_rand_eq(2);
+        assert_eq!(parts.iter().map(Vec::len).collect::<Vec<_>>(), vec![4, 3]);
+        assert_eq!(parts.concat().into_iter().collect::<BTreeSet<_>>(), set);
+        assert_eq!(set.clone().try_split_rand_eq(0), Err(SplitError::ZeroParts));
+
+        let sample = set.clone().sample_n(3);
+        assert_eq!(sample.iter().collect::<BTreeSet<_>>().len(), 3);
+        assert!(BTreeSet::<i32>::new().sample_n(2).is_empty());
+
+        let sample = set.clone().sample_n_with_rng(3, &mut StdRng::seed_from_u64(5));
+        assert_eq!(sample, set.clone().sample_n_with_rng(3, &mut StdRng::seed_from_u64(5)));
+        assert_eq!(set.sample_n_with_rng(10, &mut StdRng::seed_from_u64(5)).len(), 7);
+    }
+}
@@ -0,0 +1,118 @@
+//! Human-readable formatting of split results.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+/// The layout used by [`format_parts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartsFormat {
+    /// One line per part, e.g. `Group 1: a, b, c`.
+    #[default]
+    Plain,
+    /// A heading per part followed by a bullet list of its elements.
+    Markdown,
+    /// All parts on a single line, separated by `; `.
+    Compact,
+}
+
+/// Formats parts for display, labelling them `Group 1`, `Group 2` and so on.
+///
+/// Empty parts are rendered as `(empty)`.
+/// # Arguments
+/// * `parts` - The parts to format.
+/// * `style` - The layout to use.
+///
+/// # Returns
+/// The formatted parts, without a trailing newline.
+/// # Example
+/// ```
+/// use rust_helpers::{format_parts, PartsFormat};
+/// let parts = vec![vec!["a", "b", "c"], vec!["d"], vec![]];
+/// assert_eq!(format_parts(&parts, PartsFormat::Plain), "Group 1: a, b, c\nGroup 2: d\nGroup 3: (empty)");
+/// assert_eq!(format_parts(&parts, PartsFormat::Compact), "Group 1: a, b, c; Group 2: d; Group 3: (empty)");
+/// ```
+pub fn format_parts<T: Display>(parts: &[Vec<T>], style: PartsFormat) -> String {
+    format_parts_with_labels(parts, &[], style)
+}
+
+/// Like [`format_parts`], but labels the parts with the given names.
+///
+/// Parts beyond the end of `labels` fall back to the `Group N` label, numbered from 1 by their position.
+/// # Example
+/// ```
+/// use rust_helpers::{format_parts_with_labels, PartsFormat};
+/// let parts = vec![vec!["Ann", "Ben"], vec!["Cem"]];
+/// let text = format_parts_with_labels(&parts, &["Team Red", "Team Blue"], PartsFormat::Markdown);
+/// assert_eq!(text, "## Team Red\n- Ann\n- Ben\n\n## Team Blue\n- Cem");
+/// ```
+pub fn format_parts_with_labels<T: Display>(parts: &[Vec<T>], labels: &[&str], style: PartsFormat) -> String {
+    let blocks: Vec<String> = parts
+        .iter()
+        .enumerate()
+        .map(|(i, part)| {
+            let label = labels.get(i).map_or_else(|| format!("Group {}", i + 1), |label| label.to_string());
+            let elements: Vec<String> = part.iter().map(|e| e.to_string()).collect();
+            match style {
+                PartsFormat::Plain | PartsFormat::Compact if elements.is_empty() => format!("{}: (empty)", label),
+                PartsFormat::Plain | PartsFormat::Compact => format!("{}: {}", label, elements.join(", ")),
+                PartsFormat::Markdown if elements.is_empty() => format!("## {}\n(empty)", label),
+                PartsFormat::Markdown => format!("## {}\n- {}", label, elements.join("\n- ")),
+            }
+        })
+        .collect();
+    let separator = match style {
+        PartsFormat::Plain => "\n",
+        PartsFormat::Markdown => "\n\n",
+        PartsFormat::Compact => "; ",
+    };
+    blocks.join(separator)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn parts() -> Vec<Vec<u32>> {
+        vec![vec![1, 2, 3], vec![], vec![4]]
+    }
+
+    #[test]
+    fn test_format_parts_plain() {
+        assert_eq!(format_parts(&parts(), PartsFormat::Plain), "Group 1: 1, 2, 3\nGroup 2: (empty)\nGroup 3: 4");
+        assert_eq!(PartsFormat::default(), PartsFormat::Plain);
+    }
+
+    #[test]
+    fn test_format_parts_markdown() {
+        assert_eq!(
+            format_parts(&parts(), PartsFormat::Markdown),
+            "## Group 1\n- 1\n- 2\n- 3\n\n## Group 2\n(empty)\n\n## Group 3\n- 4"
+        );
+    }
+
+    #[test]
+    fn test_format_parts_compact() {
+        assert_eq!(format_parts(&parts(), PartsFormat::Compact), "Group 1: 1, 2, 3; Group 2: (empty); Group 3: 4");
+        assert_eq!(format_parts::<u32>(&[], PartsFormat::Compact), "");
+    }
+
+    #[test]
+    fn test_format_parts_with_labels() {
+        assert_eq!(
+            format_parts_with_labels(&parts(), &["Red", "Blue"], PartsFormat::Plain),
+            "Red: 1, 2, 3\nBlue: (empty)\nGroup 3: 4"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parts_format_serde_round_trip() {
+        for style in [PartsFormat::Plain, PartsFormat::Markdown, PartsFormat::Compact] {
+            let json = serde_json::to_string(&style).unwrap();
+            assert_eq!(serde_json::from_str::<PartsFormat>(&json).unwrap(), style);
+        }
+    }
+}
@@ -0,0 +1,1620 @@
+//! Helpers for counting items and checking them against requirements.
+
+#[cfg(feature = "std")]
+use alloc::collections::BinaryHeap;
+use crate::InsufficientItemsError;
+#[cfg(feature = "std")]
+use crate::{CountViolation, RangeViolations};
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cmp::Reverse;
+#[cfg(feature = "std")]
+use core::ops::RangeInclusive;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+/// Counts how often each distinct item occurs in a slice.
+///
+/// Counting once and querying the map afterwards is much cheaper than scanning the slice once per item of interest.
+/// # Arguments
+/// * `items` - The items to count.
+///
+/// # Returns
+/// A map from every distinct item to the number of its occurrences. Items that don't occur are not in the map.
+/// # Example
+/// ```
+/// use rust_helpers::count_occurrences;
+/// let basket = ["apple", "egg", "apple", "milk", "apple"];
+/// let counts = count_occurrences(&basket);
+/// assert_eq!(counts[&"apple"], 3);
+/// assert_eq!(counts.get(&"bread"), None);
+/// ```
+#[cfg(feature = "std")]
+pub fn count_occurrences<T: Hash + Eq>(items: &[T]) -> HashMap<&T, usize> {
+    let mut counts = HashMap::new();
+    for item in items {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Like [`count_occurrences`], but clones the items into the map, so it doesn't borrow from the slice.
+/// # Example
+/// ```
+/// use rust_helpers::count_occurrences_owned;
+/// let counts = count_occurrences_owned(&[String::from("a"), String::from("b"), String::from("a")]);
+/// assert_eq!(counts["a"], 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn count_occurrences_owned<T: Hash + Eq + Clone>(items: &[T]) -> HashMap<T, usize> {
+    count_occurrences(items).into_iter().map(|(item, count)| (item.clone(), count)).collect()
+}
+
+/// Counts the items of a slice and sorts them by descending count.
+///
+/// Items with equal counts keep the order of their first occurrence, so the output is stable for a given input.
+/// # Arguments
+/// * `items` - The items to count. They must implement the `Clone` trait.
+///
+/// # Returns
+/// One `(item, count)` pair per distinct item, most frequent first.
+/// # Example
+/// ```
+/// use rust_helpers::tally;
+/// let votes = ["pizza", "sushi", "tacos", "sushi", "tacos", "sushi"];
+/// assert_eq!(tally(&votes), vec![("sushi", 3), ("tacos", 2), ("pizza", 1)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn tally<T: Hash + Eq + Clone>(items: &[T]) -> Vec<(T, usize)> {
+    tally_with_min_count(items, 0)
+}
+
+/// Like [`tally`], but drops items that occur fewer than `min_count` times.
+/// # Example
+/// ```
+/// use rust_helpers::tally_with_min_count;
+/// let votes = ["pizza", "sushi", "tacos", "sushi", "tacos", "sushi"];
+/// assert_eq!(tally_with_min_count(&votes, 2), vec![("sushi", 3), ("tacos", 2)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn tally_with_min_count<T: Hash + Eq + Clone>(items: &[T], min_count: usize) -> Vec<(T, usize)> {
+    let mut counts = counts_by_first_occurrence(items);
+    counts.retain(|&(_, count)| count >= min_count);
+    counts.sort_by_key(|&(_, count)| Reverse(count));
+    counts.into_iter().map(|(item, count)| (item.clone(), count)).collect()
+}
+
+/// Returns the `n` most frequent items of a slice with their counts.
+///
+/// Only a heap of `n` candidates is kept while selecting, so this is cheaper than a full [`tally`] when `n` is small.
+/// Items with equal counts are ranked by their first occurrence, the earlier one first; this also decides which
+/// items make it past the cutoff.
+/// # Arguments
+/// * `items` - The items to count. They must implement the `Clone` trait.
+/// * `n` - The number of items to return.
+///
+/// # Returns
+/// Up to `n` `(item, count)` pairs, most frequent first. Fewer are returned if there are fewer distinct items.
+/// # Example
+/// ```
+/// use rust_helpers::most_common;
+/// let words = ["a", "rose", "is", "a", "rose", "is", "a", "rose"];
+/// assert_eq!(most_common(&words, 2), vec![("a", 3), ("rose", 3)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn most_common<T: Hash + Eq + Clone>(items: &[T], n: usize) -> Vec<(T, usize)> {
+    let counts = counts_by_first_occurrence(items);
+    select_first_n(counts.iter().enumerate().map(|(position, &(_, count))| (Reverse(count), position)), n)
+        .into_iter()
+        .map(|(_, position)| (counts[position].0.clone(), counts[position].1))
+        .collect()
+}
+
+/// Returns the `n` least frequent items of a slice with their counts.
+///
+/// Like [`most_common`], items with equal counts are ranked by their first occurrence, the earlier one first.
+/// # Example
+/// ```
+/// use rust_helpers::least_common;
+/// let words = ["a", "rose", "is", "a", "rose", "is", "a", "rose", "blue"];
+/// assert_eq!(least_common(&words, 2), vec![("blue", 1), ("is", 2)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn least_common<T: Hash + Eq + Clone>(items: &[T], n: usize) -> Vec<(T, usize)> {
+    let counts = counts_by_first_occurrence(items);
+    select_first_n(counts.iter().enumerate().map(|(position, &(_, count))| (count, position)), n)
+        .into_iter()
+        .map(|(_, position)| (counts[position].0.clone(), counts[position].1))
+        .collect()
+}
+
+/// Returns the `n` smallest keys in ascending order, keeping at most `n + 1` of them in memory.
+#[cfg(feature = "std")]
+fn select_first_n<K: Ord>(keys: impl Iterator<Item = K>, n: usize) -> Vec<K> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut heap = BinaryHeap::with_capacity(n + 1);
+    for key in keys {
+        heap.push(key);
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec()
+}
+
+/// Checks whether any item occurs more than once in a slice, stopping at the first repeat.
+/// # Example
+/// ```
+/// use rust_helpers::has_duplicates;
+/// assert!(has_duplicates(&["Ann", "Ben", "Ann"]));
+/// assert!(!has_duplicates(&["Ann", "Ben", "Cem"]));
+/// ```
+#[cfg(feature = "std")]
+pub fn has_duplicates<T: Hash + Eq>(items: &[T]) -> bool {
+    let mut seen = HashSet::with_capacity(items.len());
+    !items.iter().all(|item| seen.insert(item))
+}
+
+/// Finds the items that occur more than once in a slice.
+/// # Arguments
+/// * `items` - The items to check. They must implement the `Clone` trait.
+///
+/// # Returns
+/// One `(item, count)` pair per duplicated item with its total number of occurrences, in the order of the first
+/// occurrences.
+/// # Example
+/// ```
+/// use rust_helpers::find_duplicates;
+/// let roster = ["Ann", "Ben", "Cem", "Ben", "Ann", "Ben"];
+/// assert_eq!(find_duplicates(&roster), vec![("Ann", 2), ("Ben", 3)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn find_duplicates<T: Hash + Eq + Clone>(items: &[T]) -> Vec<(T, usize)> {
+    counts_by_first_occurrence(items)
+        .into_iter()
+        .filter(|&(_, count)| count > 1)
+        .map(|(item, count)| (item.clone(), count))
+        .collect()
+}
+
+/// Finds the items of a slice that share a key with another item, e.g. two students with the same email.
+/// # Arguments
+/// * `items` - The items to check.
+/// * `key_fn` - Returns the key of an item. Items with equal keys are duplicates, even if they differ otherwise.
+///
+/// # Returns
+/// One `(key, items)` pair per duplicated key with all items that have it, in the order of the first occurrences.
+/// # Example
+/// ```
+/// use rust_helpers::duplicates_by_key;
+/// let students = [("Ann", "ann@example.com"), ("Ben", "ben@example.com"), ("Anna", "ann@example.com")];
+/// let duplicates = duplicates_by_key(&students, |student| student.1);
+/// assert_eq!(duplicates, vec![("ann@example.com", vec![&students[0], &students[2]])]);
+/// ```
+#[cfg(feature = "std")]
+pub fn duplicates_by_key<T, K: Hash + Eq, F: Fn(&T) -> K>(items: &[T], key_fn: F) -> Vec<(K, Vec<&T>)> {
+    let mut position: HashMap<K, usize> = HashMap::new();
+    let mut groups: Vec<Vec<&T>> = Vec::new();
+    for item in items {
+        let next = groups.len();
+        let i = *position.entry(key_fn(item)).or_insert(next);
+        if i == next {
+            groups.push(Vec::new());
+        }
+        groups[i].push(item);
+    }
+    let mut keys: Vec<Option<K>> = groups.iter().map(|_| None).collect();
+    for (key, i) in position {
+        if groups[i].len() > 1 {
+            keys[i] = Some(key);
+        }
+    }
+    keys.into_iter().zip(groups).filter_map(|(key, group)| key.map(|key| (key, group))).collect()
+}
+
+/// Removes repeated items from a vec, keeping the first occurrence of each in the original order.
+///
+/// Unlike `Vec::dedup`, repeats don't need to be next to each other, and unlike collecting into a `HashSet`, the
+/// order is kept.
+/// # Arguments
+/// * `items` - The items to deduplicate.
+///
+/// # Returns
+/// The first occurrence of every distinct item, in the order of `items`.
+/// # Example
+/// ```
+/// use rust_helpers::{split_rand_vec_eq, unique_preserving_order};
+/// let sign_ups = vec!["Ann", "Ben", "Ann", "Cem", "Ben"];
+/// let roster = unique_preserving_order(sign_ups);
+/// assert_eq!(roster, vec!["Ann", "Ben", "Cem"]);
+/// let teams = split_rand_vec_eq(roster, 2);
+/// assert_eq!(teams.concat().len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn unique_preserving_order<T: Hash + Eq>(items: Vec<T>) -> Vec<T> {
+    let mut seen = HashSet::with_capacity(items.len());
+    let keep: Vec<bool> = items.iter().map(|item| seen.insert(item)).collect();
+    items.into_iter().zip(keep).filter_map(|(item, keep)| keep.then_some(item)).collect()
+}
+
+/// Like [`unique_preserving_order`], but compares the items by a key, keeping the first item with each key.
+/// # Example
+/// ```
+/// use rust_helpers::unique_by_key_preserving_order;
+/// let sign_ups = vec![("Ann", "ann@example.com"), ("Anna", "ann@example.com"), ("Ben", "ben@example.com")];
+/// let roster = unique_by_key_preserving_order(sign_ups, |sign_up| sign_up.1);
+/// assert_eq!(roster, vec![("Ann", "ann@example.com"), ("Ben", "ben@example.com")]);
+/// ```
+#[cfg(feature = "std")]
+pub fn unique_by_key_preserving_order<T, K: Hash + Eq, F: Fn(&T) -> K>(items: Vec<T>, key_fn: F) -> Vec<T> {
+    let mut seen = HashSet::with_capacity(items.len());
+    items.into_iter().filter(|item| seen.insert(key_fn(item))).collect()
+}
+
+/// Like [`crate::check_sufficient_items`], but counts `checked_vector` once with a HashMap.
+///
+/// The check takes `O(n + m)` time for `n` checked items and `m` requirements instead of `O(n * m)`, which pays
+/// off for long requirement lists against large inventories.
+/// # Example
+/// ```
+/// use rust_helpers::check_sufficient_items_fast;
+/// let req_items = [("apple", 2), ("banana", 1), ("orange", 3)];
+/// let checked_vector = vec!["apple", "apple", "apple", "banana", "orange", "orange", "orange", "orange"];
+/// assert!(check_sufficient_items_fast(&req_items, &checked_vector));
+/// assert!(!check_sufficient_items_fast(&[("kiwi", 1)], &checked_vector));
+/// ```
+#[cfg(feature = "std")]
+pub fn check_sufficient_items_fast<T: Hash + Eq>(req_items: &[(T, usize)], checked_vector: &[T]) -> bool {
+    meets_requirements(req_items, &count_occurrences(checked_vector))
+}
+
+/// Counts how many times a slice meets the requirements, i.e. how many complete sets can be taken from it.
+///
+/// Requirements with a count of 0 are always met and don't limit the result. If an item is required more than
+/// once, the largest count applies, like in [`crate::check_sufficient_items`].
+/// # Arguments
+/// * `req_items` - A slice of tuples where each tuple contains an item and its count per set.
+/// * `inventory` - The available items. They are counted once.
+///
+/// # Returns
+/// The largest `k` such that `inventory` holds `k` times the required count of every item. This is 0 exactly if
+/// [`check_sufficient_items_fast`] returns `false`. If no requirement has a count above 0, any number of sets can be
+/// taken and `usize::MAX` is returned.
+/// # Example
+/// ```
+/// use rust_helpers::how_many_complete_sets;
+/// let pancake = [("egg", 2), ("flour", 1), ("salt", 0)];
+/// let pantry = ["egg", "flour", "egg", "egg", "flour", "egg", "egg", "flour"];
+/// assert_eq!(how_many_complete_sets(&pancake, &pantry), 2);
+/// assert_eq!(how_many_complete_sets(&[("milk", 1)], &pantry), 0);
+/// assert_eq!(how_many_complete_sets(&[("salt", 0)], &pantry), usize::MAX);
+/// ```
+#[cfg(feature = "std")]
+pub fn how_many_complete_sets<T: Hash + Eq>(req_items: &[(T, usize)], inventory: &[T]) -> usize {
+    let counts = count_occurrences(inventory);
+    req_items
+        .iter()
+        .filter(|(_, required)| *required > 0)
+        .map(|(item, required)| counts.get(item).copied().unwrap_or(0) / required)
+        .min()
+        .unwrap_or(usize::MAX)
+}
+
+/// Checks several requirement lists against the same slice, each on its own.
+///
+/// The slice is counted once, however many lists there are. Every list is judged like
+/// [`crate::check_sufficient_items`] does, against the full slice; use [`satisfiable_together`] to find out whether
+/// the lists can share the items.
+/// # Arguments
+/// * `requirement_sets` - The requirement lists, each a slice of tuples of an item and its required count.
+/// * `checked` - The items that are checked against every list.
+///
+/// # Returns
+/// One `bool` per list, in the order of `requirement_sets`, telling whether `checked` meets that list.
+/// # Example
+/// ```
+/// use rust_helpers::check_many_requirements;
+/// let volunteers = ["medic", "driver", "cook", "driver"];
+/// let rally: &[(&str, usize)] = &[("medic", 1), ("driver", 2)];
+/// let picnic: &[(&str, usize)] = &[("cook", 2)];
+/// assert_eq!(check_many_requirements(&[rally, picnic], &volunteers), vec![true, false]);
+/// ```
+#[cfg(feature = "std")]
+pub fn check_many_requirements<T: Hash + Eq>(requirement_sets: &[&[(T, usize)]], checked: &[T]) -> Vec<bool> {
+    let counts = count_occurrences(checked);
+    requirement_sets
+        .iter()
+        .map(|req_items| meets_requirements(req_items, &counts))
+        .collect()
+}
+
+/// Checks whether a slice meets several requirement lists at the same time, without using any item twice.
+///
+/// The counts of every item are added up over all lists, and within a list as well, before they are compared to the
+/// slice. The slice is counted once.
+/// # Arguments
+/// * `requirement_sets` - The requirement lists, each a slice of tuples of an item and its required count.
+/// * `checked` - The shared items.
+///
+/// # Returns
+/// `true` if `checked` holds enough items for all lists together. This implies that [`check_many_requirements`]
+/// returns only `true`, but not the other way round.
+/// # Example
+/// ```
+/// use rust_helpers::satisfiable_together;
+/// let volunteers = ["medic", "driver", "cook", "driver"];
+/// let rally: &[(&str, usize)] = &[("medic", 1), ("driver", 1)];
+/// let picnic: &[(&str, usize)] = &[("cook", 1), ("driver", 1)];
+/// assert!(satisfiable_together(&[rally, picnic], &volunteers));
+/// assert!(!satisfiable_together(&[rally, picnic, rally], &volunteers));
+/// ```
+#[cfg(feature = "std")]
+pub fn satisfiable_together<T: Hash + Eq>(requirement_sets: &[&[(T, usize)]], checked: &[T]) -> bool {
+    let counts = count_occurrences(checked);
+    let mut required: HashMap<&T, usize> = HashMap::new();
+    for (item, count) in requirement_sets.iter().flat_map(|req_items| req_items.iter()) {
+        *required.entry(item).or_insert(0) += count;
+    }
+    required.into_iter().all(|(item, required)| counts.get(item).copied().unwrap_or(0) >= required)
+}
+
+/// Removes the required items from an inventory and returns what is left.
+///
+/// If an item is required more than once, all of its counts are removed, so `[("egg", 1), ("egg", 1)]` removes two
+/// eggs. [`ensure_sufficient_items`] checks every requirement on its own instead and accepts a single egg for the
+/// same list; [`satisfiable_together`] adds the counts up like this function does. The operation is atomic, so if
+/// anything is lacking, nothing is removed.
+/// # Arguments
+/// * `req_items` - A slice of tuples where each tuple contains an item and the number of copies to remove.
+/// * `inventory` - The available items.
+///
+/// # Returns
+/// The remaining items in their original order, or an [`InsufficientItemsError`] with the required and present
+/// count of every item that is lacking. Which of several equal copies are removed is unspecified.
+/// # Example
+/// ```
+/// use rust_helpers::consume_items;
+/// let pantry = vec!["egg", "flour", "egg", "milk", "egg"];
+/// let left = consume_items(&[("egg", 2), ("flour", 1)], pantry.clone()).unwrap();
+/// assert_eq!(left, ["milk", "egg"]);
+/// let error = consume_items(&[("egg", 4), ("milk", 1)], pantry).unwrap_err();
+/// assert_eq!(error.to_string(), "need 4 'egg', found 3");
+/// ```
+#[cfg(feature = "std")]
+pub fn consume_items<T: Hash + Eq + Clone>(req_items: &[(T, usize)], mut inventory: Vec<T>) -> Result<Vec<T>, InsufficientItemsError<T>> {
+    consume_items_in_place(req_items, &mut inventory)?;
+    Ok(inventory)
+}
+
+/// Like [`consume_items`], but removes the required items from `inventory` in place.
+///
+/// If anything is lacking, `inventory` is left untouched.
+/// # Example
+/// ```
+/// use rust_helpers::consume_items_in_place;
+/// let mut pantry = vec!["egg", "flour", "egg"];
+/// assert!(consume_items_in_place(&[("egg", 1), ("milk", 1)], &mut pantry).is_err());
+/// assert_eq!(pantry, ["egg", "flour", "egg"]);
+/// consume_items_in_place(&[("egg", 1), ("egg", 1)], &mut pantry).unwrap();
+/// assert_eq!(pantry, ["flour"]);
+/// ```
+#[cfg(feature = "std")]
+pub fn consume_items_in_place<T: Hash + Eq + Clone>(req_items: &[(T, usize)], inventory: &mut Vec<T>) -> Result<(), InsufficientItemsError<T>> {
+    let mut remaining = expected_counts(req_items);
+    let counts = count_occurrences(inventory);
+    let mut listed = HashSet::with_capacity(req_items.len());
+    let missing: Vec<(T, ItemStatus)> = req_items
+        .iter()
+        .filter(|(item, _)| listed.insert(item))
+        .filter_map(|(item, _)| {
+            let status = ItemStatus {
+                required: remaining.get(item).copied().unwrap_or(0),
+                present: counts.get(item).copied().unwrap_or(0),
+            };
+            (!status.is_sufficient()).then(|| (item.clone(), status))
+        })
+        .collect();
+    if !missing.is_empty() {
+        return Err(InsufficientItemsError { missing });
+    }
+    inventory.retain(|item| !take_one(&mut remaining, item));
+    Ok(())
+}
+
+/// Removes one occurrence from `a` for every occurrence in `b`, treating both slices as multisets.
+///
+/// Values that occur only in `b` are ignored. The earliest occurrences in `a` are the ones cancelled.
+/// # Returns
+/// The remaining values of `a`, in the order of `a`.
+/// # Example
+/// ```
+/// use rust_helpers::multiset_subtract;
+/// let stock = ["bolt", "nut", "bolt", "washer", "bolt"];
+/// let packed = ["bolt", "bolt", "nut", "screw"];
+/// assert_eq!(multiset_subtract(&stock, &packed), ["washer", "bolt"]);
+/// ```
+#[cfg(feature = "std")]
+pub fn multiset_subtract<T: Hash + Eq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut cancel = count_occurrences(b);
+    a.iter().filter(|item| !take_one(&mut cancel, item)).cloned().collect()
+}
+
+/// Keeps the values that occur in both slices, each as often as in the slice where it is rarer.
+///
+/// The earliest occurrences in `a` are the ones kept.
+/// # Returns
+/// The common values, in the order of `a`.
+/// # Example
+/// ```
+/// use rust_helpers::multiset_intersection;
+/// let monday = ["ann", "ben", "ann", "cas", "ann"];
+/// let tuesday = ["ann", "cas", "ann", "dee"];
+/// assert_eq!(multiset_intersection(&monday, &tuesday), ["ann", "ann", "cas"]);
+/// ```
+#[cfg(feature = "std")]
+pub fn multiset_intersection<T: Hash + Eq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut available = count_occurrences(b);
+    a.iter().filter(|item| take_one(&mut available, item)).cloned().collect()
+}
+
+/// Lists the requirements that a slice doesn't meet, with how many items are lacking.
+///
+/// This is the detailed counterpart of [`crate::check_sufficient_items`]: the result is empty exactly if that
+/// function returns `true`.
+/// # Arguments
+/// * `req_items` - A slice of tuples where each tuple contains an item and its required count. Every requirement is
+///   checked on its own, even if the same item is listed twice.
+/// * `checked` - The items that are checked against the requirements.
+///
+/// # Returns
+/// One `(item, missing)` pair per unmet requirement, where `missing` is the required count minus the present count,
+/// in the order of `req_items`.
+/// # Example
+/// ```
+/// use rust_helpers::missing_items;
+/// let recipe = [("egg", 3), ("flour", 1), ("milk", 2)];
+/// let pantry = ["egg", "flour", "flour", "egg"];
+/// assert_eq!(missing_items(&recipe, &pantry), vec![("egg", 1), ("milk", 2)]);
+/// ```
+pub fn missing_items<T: PartialEq + Clone>(req_items: &[(T, usize)], checked: &[T]) -> Vec<(T, usize)> {
+    req_items
+        .iter()
+        .filter_map(|(item, required)| {
+            let present = checked.iter().filter(|x| *x == item).count();
+            (present < *required).then(|| (item.clone(), required - present))
+        })
+        .collect()
+}
+
+/// The required and present count of one required item in a [`SufficiencyReport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemStatus {
+    /// The number of items required.
+    pub required: usize,
+    /// The number of items present.
+    pub present: usize,
+}
+
+impl ItemStatus {
+    /// Returns `true` if at least the required number of items is present.
+    pub fn is_sufficient(&self) -> bool {
+        self.present >= self.required
+    }
+
+    /// Returns how many items are lacking, or 0 if the requirement is met.
+    pub fn shortfall(&self) -> usize {
+        self.required.saturating_sub(self.present)
+    }
+
+    /// Returns how many items are present beyond the requirement.
+    pub fn surplus(&self) -> usize {
+        self.present.saturating_sub(self.required)
+    }
+}
+
+/// The result of [`check_items_report`]: how a slice of items compares to a list of requirements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SufficiencyReport<T> {
+    requirements: Vec<(T, ItemStatus)>,
+    unrequested: Vec<(T, usize)>,
+}
+
+impl<T: PartialEq> SufficiencyReport<T> {
+    /// Returns `true` if every requirement is met.
+    pub fn is_sufficient(&self) -> bool {
+        self.requirements.iter().all(|(_, status)| status.is_sufficient())
+    }
+
+    /// Returns every required item with its status, in the order of the requirements.
+    pub fn requirements(&self) -> &[(T, ItemStatus)] {
+        &self.requirements
+    }
+
+    /// Returns the status of a required item, or `None` if it isn't required.
+    pub fn get(&self, item: &T) -> Option<ItemStatus> {
+        self.requirements.iter().find(|(required, _)| required == item).map(|(_, status)| *status)
+    }
+
+    /// Returns the unmet requirements with how many items are lacking.
+    pub fn missing(&self) -> Vec<(&T, usize)> {
+        self.requirements
+            .iter()
+            .filter(|(_, status)| status.shortfall() > 0)
+            .map(|(item, status)| (item, status.shortfall()))
+            .collect()
+    }
+
+    /// Returns the required items that are present more often than required, with the number of extra items.
+    pub fn surplus(&self) -> Vec<(&T, usize)> {
+        self.requirements
+            .iter()
+            .filter(|(_, status)| status.surplus() > 0)
+            .map(|(item, status)| (item, status.surplus()))
+            .collect()
+    }
+
+    /// Returns the present items that aren't required at all with their counts, in the order of their first
+    /// occurrence.
+    pub fn unrequested(&self) -> &[(T, usize)] {
+        &self.unrequested
+    }
+
+    /// Turns the report into `Ok(())` if every requirement is met, or an [`InsufficientItemsError`] listing the
+    /// unmet ones.
+    pub fn into_result(self) -> Result<(), InsufficientItemsError<T>> {
+        let missing: Vec<(T, ItemStatus)> =
+            self.requirements.into_iter().filter(|(_, status)| !status.is_sufficient()).collect();
+        if missing.is_empty() { Ok(()) } else { Err(InsufficientItemsError { missing }) }
+    }
+}
+
+/// Compares a slice of items to a list of requirements in a single pass over the slice.
+///
+/// If an item is required more than once, the largest count applies, so
+/// [`SufficiencyReport::is_sufficient`] agrees with [`crate::check_sufficient_items`].
+/// # Arguments
+/// * `req_items` - A slice of tuples where each tuple contains an item and its required count.
+/// * `checked` - The items that are checked against the requirements. They are counted once.
+///
+/// # Returns
+/// A [`SufficiencyReport`] with the required and present count of every required item and the counts of all
+/// present items that aren't required.
+/// # Example
+/// ```
+/// use rust_helpers::check_items_report;
+/// let order = [("chair", 4), ("table", 1)];
+/// let delivery = ["chair", "table", "chair", "lamp", "table"];
+/// let report = check_items_report(&order, &delivery);
+/// assert!(!report.is_sufficient());
+/// assert_eq!(report.missing(), vec![(&"chair", 2)]);
+/// assert_eq!(report.surplus(), vec![(&"table", 1)]);
+/// assert_eq!(report.unrequested(), [("lamp", 1)]);
+/// assert_eq!(report.get(&"table").map(|status| status.present), Some(2));
+/// ```
+#[cfg(feature = "std")]
+pub fn check_items_report<T: Hash + Eq + Clone>(req_items: &[(T, usize)], checked: &[T]) -> SufficiencyReport<T> {
+    let counts = counts_by_first_occurrence(checked);
+    let present: HashMap<&T, usize> = counts.iter().copied().collect();
+    let mut required: HashMap<&T, usize> = HashMap::with_capacity(req_items.len());
+    for (item, count) in req_items {
+        let entry = required.entry(item).or_insert(0);
+        *entry = (*entry).max(*count);
+    }
+    let mut requirements = Vec::with_capacity(required.len());
+    for (item, _) in req_items {
+        if let Some(required) = required.remove(item) {
+            let status = ItemStatus { required, present: present.get(item).copied().unwrap_or(0) };
+            requirements.push((item.clone(), status));
+        }
+    }
+    let listed: HashSet<&T> = req_items.iter().map(|(item, _)| item).collect();
+    let unrequested = counts
+        .into_iter()
+        .filter(|(item, _)| !listed.contains(item))
+        .map(|(item, count)| (item.clone(), count))
+        .collect();
+    SufficiencyReport { requirements, unrequested }
+}
+
+/// Checks that a slice contains sufficient items, returning an error that lists every unmet requirement.
+///
+/// This is the `Result` flavored counterpart of [`crate::check_sufficient_items`] for use with the `?` operator. It
+/// counts like [`check_items_report`].
+/// # Arguments
+/// * `req_items` - A slice of tuples where each tuple contains an item and its required count.
+/// * `checked` - The items that are checked against the requirements.
+///
+/// # Returns
+/// `Ok(())` if every requirement is met, and an [`InsufficientItemsError`] with the required and present count of
+/// every unmet requirement otherwise.
+/// # Example
+/// ```
+/// use rust_helpers::ensure_sufficient_items;
+/// let recipe = [("apple", 3), ("egg", 2), ("flour", 1)];
+/// let pantry = ["flour", "apple"];
+/// let error = ensure_sufficient_items(&recipe, &pantry).unwrap_err();
+/// assert_eq!(error.to_string(), "need 3 'apple', found 1; need 2 'egg', found 0");
+/// assert!(ensure_sufficient_items(&[("flour", 1)], &pantry).is_ok());
+/// ```
+#[cfg(feature = "std")]
+pub fn ensure_sufficient_items<T: Hash + Eq + Clone>(req_items: &[(T, usize)], checked: &[T]) -> Result<(), InsufficientItemsError<T>> {
+    check_items_report(req_items, checked).into_result()
+}
+
+/// Like [`check_sufficient_items_fast`], but counts the checked items by a key extracted with `key_fn`.
+///
+/// Items whose keys are equal count towards the same requirement, no matter how they differ otherwise. Each key is
+/// extracted once per checked item.
+/// # Arguments
+/// * `req_items` - A slice of tuples where each tuple contains a key and its required count.
+/// * `checked` - The items that are checked against the requirements.
+/// * `key_fn` - Extracts the key that an item is counted by.
+///
+/// # Returns
+/// `true` if `checked` holds at least the required number of items for every key.
+/// # Example
+/// ```
+/// use rust_helpers::check_sufficient_items_by_key;
+/// struct Item { sku: u32, shelf: &'static str }
+/// let stock = [Item { sku: 7, shelf: "A1" }, Item { sku: 7, shelf: "B4" }, Item { sku: 9, shelf: "A1" }];
+/// assert!(check_sufficient_items_by_key(&[(7, 2), (9, 1)], &stock, |item| item.sku));
+/// assert!(!check_sufficient_items_by_key(&[(9, 2)], &stock, |item| item.sku));
+/// ```
+#[cfg(feature = "std")]
+pub fn check_sufficient_items_by_key<T, K: Hash + Eq, F: Fn(&T) -> K>(req_items: &[(K, usize)], checked: &[T], key_fn: F) -> bool {
+    let mut counts: HashMap<K, usize> = HashMap::with_capacity(req_items.len());
+    for item in checked {
+        *counts.entry(key_fn(item)).or_insert(0) += 1;
+    }
+    req_items.iter().all(|(key, required)| counts.get(key).copied().unwrap_or(0) >= *required)
+}
+
+/// Like [`check_items_report`], but counts the checked items by a key extracted with `key_fn`.
+///
+/// The report lists keys instead of items: the unrequested entries are the keys of present items that no
+/// requirement names, in order of first occurrence.
+/// # Example
+/// ```
+/// use rust_helpers::check_items_report_by_key;
+/// struct Item { sku: &'static str, shelf: u8 }
+/// let stock = [Item { sku: "bolt", shelf: 1 }, Item { sku: "nut", shelf: 2 }, Item { sku: "bolt", shelf: 3 }];
+/// let report = check_items_report_by_key(&[("bolt", 3), ("nut", 1)], &stock, |item| item.sku);
+/// assert_eq!(report.missing(), vec![(&"bolt", 1)]);
+/// assert!(report.unrequested().is_empty());
+/// ```
+#[cfg(feature = "std")]
+pub fn check_items_report_by_key<T, K: Hash + Eq + Clone, F: Fn(&T) -> K>(req_items: &[(K, usize)], checked: &[T], key_fn: F) -> SufficiencyReport<K> {
+    let keys: Vec<K> = checked.iter().map(key_fn).collect();
+    check_items_report(req_items, &keys)
+}
+
+/// Like [`ensure_sufficient_items`], but counts the checked items by a key extracted with `key_fn`.
+/// # Example
+/// ```
+/// use rust_helpers::ensure_sufficient_items_by_key;
+/// struct Item { sku: &'static str, shelf: u8 }
+/// let stock = [Item { sku: "bolt", shelf: 1 }, Item { sku: "bolt", shelf: 3 }];
+/// let error = ensure_sufficient_items_by_key(&[("bolt", 2), ("nut", 4)], &stock, |item| item.sku).unwrap_err();
+/// assert_eq!(error.to_string(), "need 4 'nut', found 0");
+/// ```
+#[cfg(feature = "std")]
+pub fn ensure_sufficient_items_by_key<T, K: Hash + Eq + Clone, F: Fn(&T) -> K>(req_items: &[(K, usize)], checked: &[T], key_fn: F) -> Result<(), InsufficientItemsError<K>> {
+    check_items_report_by_key(req_items, checked, key_fn).into_result()
+}
+
+/// How [`check_sufficient_items_normalized`] makes strings comparable before counting them.
+#[derive(Debug, Clone, Copy)]
+pub enum Normalizer {
+    /// Compares strings by their lowercase form, e.g. `"APPLE"` and `"Apple"` match `"apple"`.
+    ///
+    /// Lowercasing is Unicode aware but maps characters one by one; it is not full case folding. `"Straße"` and
+    /// `"STRASSE"` stay different, because `ß` has no uppercase form of its own. Use [`Normalizer::Custom`] for
+    /// such rules.
+    CaseInsensitive,
+    /// Ignores leading and trailing whitespace, e.g. `"apple "` matches `"apple"`.
+    TrimWhitespace,
+    /// Combines [`Normalizer::TrimWhitespace`] and [`Normalizer::CaseInsensitive`].
+    CaseInsensitiveTrimmed,
+    /// Applies the given function. Closures that don't capture anything coerce to it.
+    Custom(fn(&str) -> String),
+}
+
+impl Normalizer {
+    /// Returns the normalized form of `s`.
+    /// # Example
+    /// ```
+    /// use rust_helpers::Normalizer;
+    /// assert_eq!(Normalizer::CaseInsensitiveTrimmed.normalize(" Apple\n"), "apple");
+    /// assert_eq!(Normalizer::Custom(|s| s.replace('-', "")).normalize("e-mail"), "email");
+    /// ```
+    pub fn normalize(&self, s: &str) -> String {
+        match self {
+            Normalizer::CaseInsensitive => s.to_lowercase(),
+            Normalizer::TrimWhitespace => String::from(s.trim()),
+            Normalizer::CaseInsensitiveTrimmed => s.trim().to_lowercase(),
+            Normalizer::Custom(normalize) => normalize(s),
+        }
+    }
+}
+
+/// Checks that a slice of strings contains sufficient items after normalizing every string.
+///
+/// The normalizer is applied to the required keys as well as to the checked strings. Requirements that become
+/// equal after normalization are merged by adding their counts, so `[("Apple", 1), ("apple", 2)]` needs three
+/// apples.
+/// # Arguments
+/// * `req_items` - A slice of tuples where each tuple contains a string and its required count.
+/// * `checked` - The strings that are checked against the requirements.
+/// * `normalizer` - Determines which strings count as equal.
+///
+/// # Returns
+/// `true` if `checked` holds at least the required number of strings for every normalized requirement.
+/// # Example
+/// ```
+/// use rust_helpers::{check_sufficient_items_normalized, Normalizer};
+/// let req_items = [("Apple", 2), ("pear", 1)];
+/// let typed = ["apple ", "APPLE", " Pear"];
+/// assert!(check_sufficient_items_normalized(&req_items, &typed, Normalizer::CaseInsensitiveTrimmed));
+/// assert!(!check_sufficient_items_normalized(&req_items, &typed, Normalizer::CaseInsensitive));
+/// ```
+#[cfg(feature = "std")]
+pub fn check_sufficient_items_normalized(req_items: &[(&str, usize)], checked: &[&str], normalizer: Normalizer) -> bool {
+    let mut required: HashMap<String, usize> = HashMap::with_capacity(req_items.len());
+    for (item, count) in req_items {
+        *required.entry(normalizer.normalize(item)).or_insert(0) += count;
+    }
+    let mut present: HashMap<String, usize> = HashMap::with_capacity(required.len());
+    for item in checked {
+        *present.entry(normalizer.normalize(item)).or_insert(0) += 1;
+    }
+    required.iter().all(|(item, required)| present.get(item).copied().unwrap_or(0) >= *required)
+}
+
+/// Checks that a slice contains exactly the expected items: nothing more, nothing less.
+///
+/// An item expected with a count of 0 must not appear, and so must no item that isn't listed at all. If an item is
+/// listed more than once, its counts add up.
+/// # Arguments
+/// * `expected` - A slice of tuples where each tuple contains an item and its expected count.
+/// * `checked` - The items that are compared to the expectation.
+///
+/// # Returns
+/// `true` if every item occurs in `checked` exactly as often as expected. Use [`diff_items`] to find out what's
+/// wrong otherwise.
+/// # Example
+/// ```
+/// use rust_helpers::check_exact_items;
+/// let kit = [("screw", 4), ("bracket", 2)];
+/// assert!(check_exact_items(&kit, &["screw", "bracket", "screw", "screw", "bracket", "screw"]));
+/// assert!(!check_exact_items(&kit, &["screw", "bracket", "screw", "screw", "bracket"]));
+/// ```
+#[cfg(feature = "std")]
+pub fn check_exact_items<T: Hash + Eq>(expected: &[(T, usize)], checked: &[T]) -> bool {
+    expected_counts(expected) == count_occurrences(checked)
+}
+
+/// The differences between expected and present items, as returned by [`diff_items`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemsDiff<T> {
+    /// The items that are present fewer times than expected, with the number of lacking items, in the order of the
+    /// expectation.
+    pub missing: Vec<(T, usize)>,
+    /// The items that are present more often than expected, with the number of extra items, in the order of their
+    /// first occurrence. Items that aren't expected at all count entirely as extra.
+    pub extra: Vec<(T, usize)>,
+}
+
+impl<T> ItemsDiff<T> {
+    /// Returns `true` if nothing is missing or extra.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// Compares a slice to the exactly expected items and lists what's missing and what's extra.
+///
+/// This is the detailed counterpart of [`check_exact_items`]: the result is empty exactly if that function returns
+/// `true`.
+/// # Example
+/// ```
+/// use rust_helpers::diff_items;
+/// let kit = [("screw", 4), ("bracket", 2), ("manual", 0)];
+/// let packed = ["screw", "bracket", "screw", "manual", "bracket", "bracket"];
+/// let diff = diff_items(&kit, &packed);
+/// assert_eq!(diff.missing, vec![("screw", 2)]);
+/// assert_eq!(diff.extra, vec![("bracket", 1), ("manual", 1)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn diff_items<T: Hash + Eq + Clone>(expected: &[(T, usize)], checked: &[T]) -> ItemsDiff<T> {
+    let expected_counts = expected_counts(expected);
+    let counts = counts_by_first_occurrence(checked);
+    let present: HashMap<&T, usize> = counts.iter().copied().collect();
+    let mut listed = HashSet::with_capacity(expected.len());
+    let missing = expected
+        .iter()
+        .filter(|(item, _)| listed.insert(item))
+        .filter_map(|(item, _)| {
+            let present = present.get(item).copied().unwrap_or(0);
+            let lacking = expected_counts.get(item).copied().unwrap_or(0).saturating_sub(present);
+            (lacking > 0).then(|| (item.clone(), lacking))
+        })
+        .collect();
+    let extra = counts
+        .into_iter()
+        .filter_map(|(item, count)| {
+            let surplus = count.saturating_sub(expected_counts.get(item).copied().unwrap_or(0));
+            (surplus > 0).then(|| (item.clone(), surplus))
+        })
+        .collect();
+    ItemsDiff { missing, extra }
+}
+
+/// Checks that no item occurs more often than its limit.
+///
+/// Items without a limit are unlimited; use [`check_at_most_items_strict`] to disallow them. If an item is limited
+/// more than once, the smallest limit applies.
+/// # Arguments
+/// * `limits` - A slice of tuples where each tuple contains an item and the maximum number of its occurrences.
+/// * `checked` - The items that are checked against the limits.
+///
+/// # Returns
+/// `true` if every limit is respected. Use [`exceeded_limits`] to find out which ones aren't.
+/// # Example
+/// ```
+/// use rust_helpers::check_at_most_items;
+/// let slots = [("Ann", 2), ("Ben", 1)];
+/// assert!(check_at_most_items(&slots, &["Ann", "Ben", "Ann", "Cem", "Cem", "Cem"]));
+/// assert!(!check_at_most_items(&slots, &["Ben", "Ben"]));
+/// ```
+#[cfg(feature = "std")]
+pub fn check_at_most_items<T: Hash + Eq>(limits: &[(T, usize)], checked: &[T]) -> bool {
+    let limits = smallest_limits(limits);
+    count_occurrences(checked).into_iter().all(|(item, count)| limits.get(item).is_none_or(|&limit| count <= limit))
+}
+
+/// Like [`check_at_most_items`], but items without a limit must not occur at all.
+/// # Example
+/// ```
+/// use rust_helpers::check_at_most_items_strict;
+/// let slots = [("Ann", 2), ("Ben", 1)];
+/// assert!(check_at_most_items_strict(&slots, &["Ann", "Ben", "Ann"]));
+/// assert!(!check_at_most_items_strict(&slots, &["Ann", "Cem"]));
+/// ```
+#[cfg(feature = "std")]
+pub fn check_at_most_items_strict<T: Hash + Eq>(limits: &[(T, usize)], checked: &[T]) -> bool {
+    let limits = smallest_limits(limits);
+    count_occurrences(checked).into_iter().all(|(item, count)| limits.get(item).is_some_and(|&limit| count <= limit))
+}
+
+/// Lists the items that occur more often than their limit.
+///
+/// This is the detailed counterpart of [`check_at_most_items`]: the result is empty exactly if that function
+/// returns `true`.
+/// # Returns
+/// One `(item, excess)` pair per exceeded limit, where `excess` is the number of occurrences beyond the limit, in
+/// the order of the first occurrences in `checked`.
+/// # Example
+/// ```
+/// use rust_helpers::exceeded_limits;
+/// let slots = [("Ann", 2), ("Ben", 1)];
+/// assert_eq!(exceeded_limits(&slots, &["Ben", "Ann", "Ben", "Cem", "Ben"]), vec![("Ben", 2)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn exceeded_limits<T: Hash + Eq + Clone>(limits: &[(T, usize)], checked: &[T]) -> Vec<(T, usize)> {
+    let limits = smallest_limits(limits);
+    excess_counts(checked, |item| limits.get(item).copied())
+}
+
+/// Like [`exceeded_limits`], but items without a limit must not occur at all, so all their occurrences are excess.
+/// # Example
+/// ```
+/// use rust_helpers::exceeded_limits_strict;
+/// let slots = [("Ann", 2), ("Ben", 1)];
+/// assert_eq!(exceeded_limits_strict(&slots, &["Ben", "Ann", "Ben", "Cem"]), vec![("Ben", 1), ("Cem", 1)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn exceeded_limits_strict<T: Hash + Eq + Clone>(limits: &[(T, usize)], checked: &[T]) -> Vec<(T, usize)> {
+    let limits = smallest_limits(limits);
+    excess_counts(checked, |item| Some(limits.get(item).copied().unwrap_or(0)))
+}
+
+/// Checks that every item occurs a number of times within its allowed range.
+///
+/// This combines [`crate::check_sufficient_items`] and [`check_at_most_items`], e.g. for "2 to 4 moderators and at
+/// most 1 photographer". Items without a range are ignored, and every range is checked on its own, even if the same
+/// item is listed twice.
+/// # Arguments
+/// * `ranges` - A slice of tuples where each tuple contains an item and its allowed number of occurrences.
+/// * `checked` - The items that are checked against the ranges. They are counted once.
+///
+/// # Returns
+/// `Ok(())` if every count lies within its range, and [`RangeViolations`] with the actual count of every violated
+/// range otherwise.
+/// # Example
+/// ```
+/// use rust_helpers::check_counts_in_range;
+/// let staffing = [("moderator", 2..=4), ("photographer", 0..=1)];
+/// assert!(check_counts_in_range(&staffing, &["moderator", "photographer", "moderator"]).is_ok());
+/// let error = check_counts_in_range(&staffing, &["moderator", "photographer", "photographer"]).unwrap_err();
+/// assert_eq!(error.to_string(), "need 2 to 4 'moderator', found 1; need 0 to 1 'photographer', found 2");
+/// ```
+#[cfg(feature = "std")]
+pub fn check_counts_in_range<T: Hash + Eq + Clone>(ranges: &[(T, RangeInclusive<usize>)], checked: &[T]) -> Result<(), RangeViolations<T>> {
+    let counts = count_occurrences(checked);
+    let violations: Vec<CountViolation<T>> = ranges
+        .iter()
+        .filter_map(|(item, allowed)| {
+            let actual = counts.get(item).copied().unwrap_or(0);
+            (!allowed.contains(&actual)).then(|| CountViolation { item: item.clone(), allowed: allowed.clone(), actual })
+        })
+        .collect();
+    if violations.is_empty() { Ok(()) } else { Err(RangeViolations { violations }) }
+}
+
+/// Checks every requirement against counts made with [`count_occurrences`], treating missing items as 0.
+#[cfg(feature = "std")]
+fn meets_requirements<T: Hash + Eq>(req_items: &[(T, usize)], counts: &HashMap<&T, usize>) -> bool {
+    req_items.iter().all(|(item, required)| counts.get(item).copied().unwrap_or(0) >= *required)
+}
+
+/// Returns the smallest limit of every limited item.
+#[cfg(feature = "std")]
+fn smallest_limits<T: Hash + Eq>(limits: &[(T, usize)]) -> HashMap<&T, usize> {
+    let mut smallest = HashMap::with_capacity(limits.len());
+    for (item, limit) in limits {
+        let entry = smallest.entry(item).or_insert(*limit);
+        *entry = (*entry).min(*limit);
+    }
+    smallest
+}
+
+/// Counts the items of a slice and returns those above their limit with the excess, in the order of their first
+/// occurrence. Items for which `limit` returns `None` are unlimited.
+#[cfg(feature = "std")]
+fn excess_counts<T: Hash + Eq + Clone>(checked: &[T], limit: impl Fn(&T) -> Option<usize>) -> Vec<(T, usize)> {
+    counts_by_first_occurrence(checked)
+        .into_iter()
+        .filter_map(|(item, count)| {
+            let excess = limit(item).map_or(0, |limit| count.saturating_sub(limit));
+            (excess > 0).then(|| (item.clone(), excess))
+        })
+        .collect()
+}
+
+/// Adds up the expected count of every item, leaving out items that are expected 0 times.
+#[cfg(feature = "std")]
+fn expected_counts<T: Hash + Eq>(expected: &[(T, usize)]) -> HashMap<&T, usize> {
+    let mut counts = HashMap::with_capacity(expected.len());
+    for (item, count) in expected {
+        *counts.entry(item).or_insert(0) += count;
+    }
+    counts.retain(|_, count| *count > 0);
+    counts
+}
+
+/// Decrements the count of `item` and returns `true`, or returns `false` if the count is already 0 or missing.
+#[cfg(feature = "std")]
+fn take_one<T: Hash + Eq>(counts: &mut HashMap<&T, usize>, item: &T) -> bool {
+    match counts.get_mut(item) {
+        Some(count) if *count > 0 => {
+            *count -= 1;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Counts the items of a slice, listing them in the order of their first occurrence.
+#[cfg(feature = "std")]
+pub(crate) fn counts_by_first_occurrence<T: Hash + Eq>(items: &[T]) -> Vec<(&T, usize)> {
+    let mut position: HashMap<&T, usize> = HashMap::new();
+    let mut counts: Vec<(&T, usize)> = Vec::new();
+    for item in items {
+        let next = counts.len();
+        let i = *position.entry(item).or_insert(next);
+        if i == next {
+            counts.push((item, 0));
+        }
+        counts[i].1 += 1;
+    }
+    counts
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::check_sufficient_items;
+
+    /// Implements `Hash` and `Eq`, but not `Ord`.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Fruit {
+        name: &'static str,
+    }
+
+    /// Draws `runs` random pairs of an inventory and a requirement list from a seeded RNG. The requirements mention
+    /// items that are missing from the inventory too, and counts of 0.
+    fn random_cases(seed: u64, runs: usize) -> impl Iterator<Item = (Vec<u8>, Vec<(u8, usize)>)> {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..runs).map(move |_| {
+            let inventory = (0..rng.random_range(0..40)).map(|_| rng.random_range(0..8)).collect();
+            let req = (0..rng.random_range(0..6)).map(|_| (rng.random_range(0..10), rng.random_range(0..5))).collect();
+            (inventory, req)
+        })
+    }
+
+    #[test]
+    fn test_count_occurrences() {
+        let counts = count_occurrences(&[3, 1, 3, 3, 2, 1]);
+        assert_eq!(counts, HashMap::from([(&3, 3), (&1, 2), (&2, 1)]));
+        assert!(count_occurrences::<u8>(&[]).is_empty());
+        assert!(count_occurrences_owned::<u8>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_count_occurrences_needs_only_hash_and_eq() {
+        let apple = Fruit { name: "apple" };
+        let pear = Fruit { name: "pear" };
+        let items = vec![apple.clone(), pear.clone(), apple.clone()];
+        let counts = count_occurrences(&items);
+        assert_eq!((counts[&apple], counts[&pear]), (2, 1));
+        let owned = count_occurrences_owned(&items);
+        drop(items);
+        assert_eq!(owned, HashMap::from([(apple, 2), (pear, 1)]));
+    }
+
+    #[test]
+    fn test_tally_breaks_ties_by_first_occurrence() {
+        let items = ["d", "b", "a", "c", "a", "b", "c", "d", "e", "c"];
+        assert_eq!(tally(&items), vec![("c", 3), ("d", 2), ("b", 2), ("a", 2), ("e", 1)]);
+        assert_eq!(tally(&items), tally(&items));
+        assert_eq!(tally_with_min_count(&items, 2), vec![("c", 3), ("d", 2), ("b", 2), ("a", 2)]);
+        assert_eq!(tally_with_min_count(&items, 4), vec![]);
+        assert_eq!(tally::<u8>(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_find_duplicates() {
+        assert!(!has_duplicates(&[1, 2, 3]));
+        assert_eq!(find_duplicates(&[1, 2, 3]), vec![]);
+        assert!(has_duplicates(&[4, 4, 4]));
+        assert_eq!(find_duplicates(&[4, 4, 4]), vec![(4, 3)]);
+        assert_eq!(find_duplicates(&[3, 1, 2, 1, 3, 3]), vec![(3, 3), (1, 2)]);
+        assert!(!has_duplicates::<u8>(&[]));
+        assert_eq!(find_duplicates::<u8>(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_duplicates_by_key() {
+        #[derive(Debug, PartialEq)]
+        struct Student {
+            name: &'static str,
+            email: &'static str,
+        }
+        let students = [
+            Student { name: "Ann", email: "ann@example.com" },
+            Student { name: "Ben", email: "ben@example.com" },
+            Student { name: "Anna", email: "ann@example.com" },
+            Student { name: "Cem", email: "cem@example.com" },
+            Student { name: "Benjamin", email: "ben@example.com" },
+        ];
+        let duplicates = duplicates_by_key(&students, |student| student.email);
+        assert_eq!(
+            duplicates,
+            vec![
+                ("ann@example.com", vec![&students[0], &students[2]]),
+                ("ben@example.com", vec![&students[1], &students[4]]),
+            ]
+        );
+        assert!(duplicates_by_key(&students, |student| student.name).is_empty());
+    }
+
+    #[test]
+    fn test_unique_preserving_order() {
+        assert_eq!(unique_preserving_order(vec![3, 1, 3, 2, 1, 4, 2]), vec![3, 1, 2, 4]);
+        assert_eq!(unique_preserving_order(vec![5, 5, 5]), vec![5]);
+        assert!(unique_preserving_order(Vec::<u8>::new()).is_empty());
+        let names: Vec<String> = ["b", "a", "b", "c", "a"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(unique_preserving_order(names), vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_unique_by_key_keeps_first_item_per_key() {
+        let items = vec![(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (2, 'e')];
+        assert_eq!(unique_by_key_preserving_order(items.clone(), |item| item.0), vec![(1, 'a'), (2, 'b'), (3, 'd')]);
+        assert_eq!(unique_by_key_preserving_order(items.clone(), |item| item.0 % 2), vec![(1, 'a'), (2, 'b')]);
+        assert_eq!(unique_by_key_preserving_order(items.clone(), |item| item.1), items);
+    }
+
+    #[test]
+    fn test_missing_items() {
+        let checked = ["egg", "flour", "flour", "egg", "salt"];
+        assert_eq!(missing_items(&[("egg", 3), ("flour", 2)], &checked), vec![("egg", 1)]);
+        assert_eq!(missing_items(&[("milk", 2), ("egg", 2)], &checked), vec![("milk", 2)]);
+        assert_eq!(missing_items(&[("milk", 0), ("salt", 0)], &checked), vec![]);
+        assert_eq!(missing_items(&[("milk", 1)], &[]), vec![("milk", 1)]);
+        for req in [vec![("egg", 3)], vec![("egg", 2), ("salt", 1)], vec![("milk", 0)], vec![]] {
+            assert_eq!(missing_items(&req, &checked).is_empty(), check_sufficient_items(&req, &checked));
+        }
+    }
+
+    #[test]
+    fn test_check_items_report() {
+        let req = [("a", 2), ("b", 3), ("c", 1), ("d", 0), ("a", 1)];
+        let checked = ["x", "a", "b", "a", "a", "y", "b", "x", "d"];
+        let report = check_items_report(&req, &checked);
+        assert!(!report.is_sufficient());
+        assert_eq!(
+            report.requirements(),
+            [
+                ("a", ItemStatus { required: 2, present: 3 }),
+                ("b", ItemStatus { required: 3, present: 2 }),
+                ("c", ItemStatus { required: 1, present: 0 }),
+                ("d", ItemStatus { required: 0, present: 1 }),
+            ]
+        );
+        assert_eq!(report.missing(), vec![(&"b", 1), (&"c", 1)]);
+        assert_eq!(report.surplus(), vec![(&"a", 1), (&"d", 1)]);
+        assert_eq!(report.unrequested(), [("x", 2), ("y", 1)]);
+        assert_eq!(report.get(&"c"), Some(ItemStatus { required: 1, present: 0 }));
+        assert_eq!(report.get(&"x"), None);
+        assert_eq!(report.get(&"b").map(|status| (status.shortfall(), status.surplus())), Some((1, 0)));
+    }
+
+    #[test]
+    fn test_check_items_report_agrees_with_check_sufficient_items() {
+        let checked = ["egg", "flour", "flour", "egg", "salt"];
+        let requirements = [
+            vec![("egg", 2), ("flour", 2)],
+            vec![("egg", 3)],
+            vec![("egg", 2), ("egg", 1), ("salt", 1)],
+            vec![("milk", 0)],
+            vec![("milk", 1), ("salt", 1)],
+            vec![],
+        ];
+        for req in requirements {
+            let report = check_items_report(&req, &checked);
+            assert_eq!(report.is_sufficient(), check_sufficient_items(&req, &checked), "{:?}", req);
+            assert_eq!(report.missing().is_empty(), report.is_sufficient());
+        }
+    }
+
+    #[test]
+    fn test_ensure_sufficient_items_mentions_each_missing_item_once() {
+        let req = [("apple", 3), ("egg", 2), ("apple", 2), ("salt", 1), ("milk", 1)];
+        let checked = ["apple", "salt", "salt"];
+        let error = ensure_sufficient_items(&req, &checked).unwrap_err();
+        let message = error.to_string();
+        for item in ["'apple'", "'egg'", "'milk'"] {
+            assert_eq!(message.matches(item).count(), 1, "{}", message);
+        }
+        assert!(!message.contains("salt"));
+        assert_eq!(message, "need 3 'apple', found 1; need 2 'egg', found 0; need 1 'milk', found 0");
+        assert_eq!(error.missing.len(), 3);
+        assert_eq!(ensure_sufficient_items(&[("salt", 2)], &checked), Ok(()));
+
+        let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(error);
+        assert!(boxed.to_string().starts_with("need 3"));
+    }
+
+    #[test]
+    fn test_check_exact_items() {
+        let kit = [("screw", 2), ("nut", 1), ("manual", 0)];
+        assert!(check_exact_items(&kit, &["nut", "screw", "screw"]));
+        assert!(!check_exact_items(&kit, &["nut", "screw", "screw", "washer"]));
+        assert!(!check_exact_items(&kit, &["nut", "screw"]));
+        assert!(!check_exact_items(&kit, &["nut", "nut", "screw", "screw"]));
+        assert!(!check_exact_items(&kit, &["nut", "screw", "screw", "manual"]));
+        assert!(check_exact_items(&[("screw", 1), ("screw", 1)], &["screw", "screw"]));
+        assert!(check_exact_items::<&str>(&[], &[]));
+    }
+
+    #[test]
+    fn test_diff_items() {
+        let kit = [("screw", 2), ("nut", 1), ("manual", 0)];
+        let exact = diff_items(&kit, &["nut", "screw", "screw"]);
+        assert!(exact.is_empty());
+
+        let diff = diff_items(&kit, &["washer", "nut", "screw", "screw", "washer"]);
+        assert_eq!(diff, ItemsDiff { missing: vec![], extra: vec![("washer", 2)] });
+        let diff = diff_items(&kit, &["screw"]);
+        assert_eq!(diff, ItemsDiff { missing: vec![("screw", 1), ("nut", 1)], extra: vec![] });
+        let diff = diff_items(&kit, &["manual", "nut", "nut", "nut", "screw", "screw"]);
+        assert_eq!(diff, ItemsDiff { missing: vec![], extra: vec![("manual", 1), ("nut", 2)] });
+        for checked in [vec!["nut"], vec!["nut", "screw", "screw"], vec!["washer"], vec![]] {
+            assert_eq!(diff_items(&kit, &checked).is_empty(), check_exact_items(&kit, &checked));
+        }
+    }
+
+    #[test]
+    fn test_check_at_most_items() {
+        let limits = [("a", 2), ("b", 0), ("a", 3)];
+        assert!(check_at_most_items(&limits, &["a"]));
+        assert!(check_at_most_items(&limits, &["a", "a"]));
+        assert!(!check_at_most_items(&limits, &["a", "a", "a"]));
+        assert!(!check_at_most_items(&limits, &["b"]));
+        assert!(check_at_most_items(&limits, &["c", "c", "c", "a"]));
+        assert!(check_at_most_items(&limits, &[]));
+
+        assert!(check_at_most_items_strict(&limits, &["a", "a"]));
+        assert!(!check_at_most_items_strict(&limits, &["a", "a", "a"]));
+        assert!(!check_at_most_items_strict(&limits, &["c", "a"]));
+        assert!(check_at_most_items_strict(&limits, &[]));
+    }
+
+    #[test]
+    fn test_exceeded_limits() {
+        let limits = [("a", 2), ("b", 0)];
+        let checked = ["c", "a", "b", "a", "a", "c", "a", "b"];
+        assert_eq!(exceeded_limits(&limits, &checked), vec![("a", 2), ("b", 2)]);
+        assert_eq!(exceeded_limits_strict(&limits, &checked), vec![("c", 2), ("a", 2), ("b", 2)]);
+        assert_eq!(exceeded_limits(&limits, &["a", "a", "c"]), vec![]);
+        assert_eq!(exceeded_limits_strict(&limits, &["a", "a", "c"]), vec![("c", 1)]);
+        for checked in [vec!["a"], vec!["a", "a", "a"], vec!["c"], vec![]] {
+            assert_eq!(exceeded_limits(&limits, &checked).is_empty(), check_at_most_items(&limits, &checked));
+            assert_eq!(exceeded_limits_strict(&limits, &checked).is_empty(), check_at_most_items_strict(&limits, &checked));
+        }
+    }
+
+    #[test]
+    fn test_check_counts_in_range() {
+        let ranges = [("m", 2..=4), ("p", 0..=1), ("s", 1..=1)];
+        assert_eq!(check_counts_in_range(&ranges, &["m", "m", "s"]), Ok(()));
+        assert_eq!(check_counts_in_range(&ranges, &["m", "m", "m", "m", "p", "s", "x"]), Ok(()));
+
+        let error = check_counts_in_range(&ranges, &["m", "s", "p", "p"]).unwrap_err();
+        assert_eq!(
+            error.violations,
+            vec![
+                CountViolation { item: "m", allowed: 2..=4, actual: 1 },
+                CountViolation { item: "p", allowed: 0..=1, actual: 2 },
+            ]
+        );
+        let error = check_counts_in_range(&ranges, &["m", "m", "m", "m", "m"]).unwrap_err();
+        assert_eq!(
+            error.violations,
+            vec![
+                CountViolation { item: "m", allowed: 2..=4, actual: 5 },
+                CountViolation { item: "s", allowed: 1..=1, actual: 0 },
+            ]
+        );
+        assert!(error.violations[0].is_too_many() && error.violations[1].is_too_few());
+    }
+
+    /// Counted by `sku`; `shelf` only tells copies apart.
+    #[derive(Debug, Clone, PartialEq)]
+    struct Stock {
+        sku: &'static str,
+        shelf: u8,
+    }
+
+    fn stock() -> Vec<Stock> {
+        vec![
+            Stock { sku: "bolt", shelf: 1 },
+            Stock { sku: "nut", shelf: 1 },
+            Stock { sku: "bolt", shelf: 2 },
+            Stock { sku: "washer", shelf: 3 },
+            Stock { sku: "bolt", shelf: 3 },
+        ]
+    }
+
+    #[test]
+    fn test_check_sufficient_items_by_key_counts_items_with_equal_keys_together() {
+        let stock = stock();
+        assert_ne!(stock[0], stock[2]);
+        assert!(check_sufficient_items_by_key(&[("bolt", 3), ("nut", 1)], &stock, |item| item.sku));
+        assert!(!check_sufficient_items_by_key(&[("bolt", 4)], &stock, |item| item.sku));
+        assert!(!check_sufficient_items_by_key(&[("screw", 1)], &stock, |item| item.sku));
+        assert!(check_sufficient_items_by_key(&[("screw", 0)], &stock, |item| item.sku));
+        assert!(check_sufficient_items_by_key::<Stock, &str, _>(&[], &[], |item| item.sku));
+        assert!(check_sufficient_items_by_key(&[(3, 2)], &stock, |item| item.shelf));
+    }
+
+    #[test]
+    fn test_check_items_report_by_key() {
+        let report = check_items_report_by_key(&[("bolt", 2), ("nut", 2)], &stock(), |item| item.sku);
+        assert_eq!(report.get(&"bolt"), Some(ItemStatus { required: 2, present: 3 }));
+        assert_eq!(report.missing(), vec![(&"nut", 1)]);
+        assert_eq!(report.surplus(), vec![(&"bolt", 1)]);
+        assert_eq!(report.unrequested(), [("washer", 1)]);
+
+        let report = check_items_report_by_key(&[("bolt", 2), ("bolt", 4)], &stock(), |item| item.sku);
+        assert_eq!(report.get(&"bolt"), Some(ItemStatus { required: 4, present: 3 }));
+        assert_eq!(report.missing(), vec![(&"bolt", 1)]);
+        let report = check_items_report_by_key(&[("bolt", 1)], &[], |item: &Stock| item.sku);
+        assert_eq!(report.missing(), vec![(&"bolt", 1)]);
+        assert!(report.unrequested().is_empty());
+    }
+
+    #[test]
+    fn test_ensure_sufficient_items_by_key() {
+        let stock = stock();
+        assert_eq!(ensure_sufficient_items_by_key(&[("bolt", 3)], &stock, |item| item.sku), Ok(()));
+        let error = ensure_sufficient_items_by_key(&[("bolt", 4)], &stock, |item| item.sku).unwrap_err();
+        assert_eq!(error.missing, vec![("bolt", ItemStatus { required: 4, present: 3 })]);
+        let error = ensure_sufficient_items_by_key(&[("nut", 2), ("bolt", 3), ("screw", 1)], &stock, |item| item.sku)
+            .unwrap_err();
+        let missing = vec![
+            ("nut", ItemStatus { required: 2, present: 1 }),
+            ("screw", ItemStatus { required: 1, present: 0 }),
+        ];
+        assert_eq!(error.missing, missing);
+    }
+
+    #[test]
+    fn test_by_key_variants_agree_with_check_sufficient_items_on_keys() {
+        for (keys, req) in random_cases(41, 200) {
+            let checked: Vec<(u8, usize)> = keys.iter().copied().zip(0..).collect();
+            let expected = check_sufficient_items(&req, &keys);
+            assert_eq!(check_sufficient_items_by_key(&req, &checked, |item| item.0), expected);
+            assert_eq!(check_items_report_by_key(&req, &checked, |item| item.0).is_sufficient(), expected);
+            assert_eq!(ensure_sufficient_items_by_key(&req, &checked, |item| item.0).is_ok(), expected);
+        }
+    }
+
+    #[test]
+    fn test_check_sufficient_items_normalized_ignores_case_and_whitespace() {
+        let req = [("Apple", 3)];
+        let typed = ["Apple", "apple ", "APPLE"];
+        assert!(!check_sufficient_items_normalized(&req, &typed, Normalizer::CaseInsensitive));
+        assert!(!check_sufficient_items_normalized(&req, &typed, Normalizer::TrimWhitespace));
+        assert!(check_sufficient_items_normalized(&req, &typed, Normalizer::CaseInsensitiveTrimmed));
+        assert!(check_sufficient_items_normalized(&[("apple", 2)], &typed, Normalizer::CaseInsensitive));
+        assert!(check_sufficient_items_normalized(&[(" Apple\t", 1)], &typed, Normalizer::TrimWhitespace));
+        assert!(!check_sufficient_items_normalized(&[("pear", 1)], &typed, Normalizer::CaseInsensitiveTrimmed));
+        assert!(check_sufficient_items_normalized(&[], &[], Normalizer::CaseInsensitive));
+    }
+
+    #[test]
+    fn test_check_sufficient_items_normalized_merges_colliding_requirements() {
+        let req = [("Apple", 1), ("apple", 2)];
+        let normalizer = Normalizer::CaseInsensitive;
+        assert!(!check_sufficient_items_normalized(&req, &["apple", "APPLE"], normalizer));
+        assert!(check_sufficient_items_normalized(&req, &["apple", "APPLE", "aPPle"], normalizer));
+        assert!(check_sufficient_items_normalized(&req, &["Apple", "apple", "apple"], Normalizer::TrimWhitespace));
+    }
+
+    #[test]
+    fn test_check_sufficient_items_normalized_unicode() {
+        let normalizer = Normalizer::CaseInsensitive;
+        assert!(check_sufficient_items_normalized(&[("ÄPFEL", 2)], &["äpfel", "Äpfel"], normalizer));
+        // Lowercasing is no full case folding, so the sharp s doesn't match a double s.
+        assert!(!check_sufficient_items_normalized(&[("Straße", 1)], &["STRASSE"], normalizer));
+        let folding = Normalizer::Custom(|s| s.to_lowercase().replace('ß', "ss"));
+        assert!(check_sufficient_items_normalized(&[("Straße", 2)], &["STRASSE", "strasse"], folding));
+        let typed = ["\u{3000}apple\u{a0}"];
+        assert!(check_sufficient_items_normalized(&[("apple", 1)], &typed, Normalizer::TrimWhitespace));
+    }
+
+    #[test]
+    fn test_how_many_complete_sets() {
+        let req = [("egg", 2), ("flour", 1)];
+        assert_eq!(how_many_complete_sets(&req, &["flour", "egg", "egg"]), 1);
+        assert_eq!(how_many_complete_sets(&req, &["egg", "egg", "egg", "flour", "flour", "flour"]), 1);
+        let pantry = ["egg"; 7].iter().chain(&["flour"; 5]).copied().collect::<Vec<_>>();
+        assert_eq!(how_many_complete_sets(&req, &pantry), 3);
+        assert_eq!(how_many_complete_sets(&req, &["egg", "flour"]), 0);
+        assert_eq!(how_many_complete_sets(&req, &[]), 0);
+        assert_eq!(how_many_complete_sets(&[("egg", 2), ("egg", 3)], &pantry), 2);
+    }
+
+    #[test]
+    fn test_how_many_complete_sets_at_the_boundary() {
+        let req = [("egg", 3), ("flour", 2)];
+        let pantry = ["egg"; 9].iter().chain(&["flour"; 6]).copied().collect::<Vec<_>>();
+        assert_eq!(how_many_complete_sets(&req, &pantry), 3);
+        assert_eq!(how_many_complete_sets(&req, &pantry[1..]), 2);
+        assert_eq!(how_many_complete_sets(&req, &pantry[..pantry.len() - 1]), 2);
+    }
+
+    #[test]
+    fn test_how_many_complete_sets_ignores_zero_requirements() {
+        let pantry = ["egg", "egg", "egg", "egg"];
+        assert_eq!(how_many_complete_sets(&[("egg", 2), ("salt", 0)], &pantry), 2);
+        assert_eq!(how_many_complete_sets(&[("salt", 0)], &pantry), usize::MAX);
+        assert_eq!(how_many_complete_sets::<&str>(&[], &pantry), usize::MAX);
+    }
+
+    #[test]
+    fn test_how_many_complete_sets_agrees_with_check_sufficient_items() {
+        for (inventory, mut req) in random_cases(42, 200) {
+            req.retain(|&(_, count)| count > 0);
+            if req.is_empty() {
+                continue;
+            }
+            let sets = how_many_complete_sets(&req, &inventory);
+            let times = |k: usize| req.iter().map(|(item, count)| (*item, count * k)).collect::<Vec<_>>();
+            assert!(check_sufficient_items(&times(sets), &inventory));
+            assert!(!check_sufficient_items(&times(sets + 1), &inventory));
+        }
+    }
+
+    #[test]
+    fn test_consume_items_removes_the_required_counts() {
+        let pantry = vec!["egg", "flour", "egg", "milk", "egg", "flour"];
+        let left = consume_items(&[("egg", 2), ("flour", 1), ("salt", 0)], pantry.clone()).unwrap();
+        assert_eq!(count_occurrences(&left), HashMap::from([(&"egg", 1), (&"flour", 1), (&"milk", 1)]));
+        assert_eq!(left.len(), pantry.len() - 3);
+        assert!(consume_items(&[("egg", 3), ("flour", 2), ("milk", 1)], pantry.clone()).unwrap().is_empty());
+        assert_eq!(consume_items(&[], pantry.clone()), Ok(pantry));
+    }
+
+    #[test]
+    fn test_consume_items_adds_up_repeated_requirements() {
+        let pantry = vec!["egg", "egg", "egg"];
+        assert_eq!(consume_items(&[("egg", 1), ("egg", 1)], pantry.clone()), Ok(vec!["egg"]));
+        let error = consume_items(&[("egg", 2), ("egg", 2)], pantry).unwrap_err();
+        assert_eq!(error.missing, vec![("egg", ItemStatus { required: 4, present: 3 })]);
+
+        // Checking counts every requirement on its own, consuming adds them up.
+        let required = [("egg", 1), ("egg", 1)];
+        assert_eq!(ensure_sufficient_items(&required, &["egg"]), Ok(()));
+        assert!(!satisfiable_together(&[&required], &["egg"]));
+        let error = consume_items(&required, vec!["egg"]).unwrap_err();
+        assert_eq!(error.missing, vec![("egg", ItemStatus { required: 2, present: 1 })]);
+    }
+
+    #[test]
+    fn test_consume_items_in_place_is_atomic() {
+        let mut pantry = vec!["egg", "flour", "egg", "milk"];
+        let error = consume_items_in_place(&[("egg", 1), ("flour", 2), ("salt", 1)], &mut pantry).unwrap_err();
+        let missing = vec![
+            ("flour", ItemStatus { required: 2, present: 1 }),
+            ("salt", ItemStatus { required: 1, present: 0 }),
+        ];
+        assert_eq!(error.missing, missing);
+        assert_eq!(pantry, ["egg", "flour", "egg", "milk"]);
+        assert_eq!(consume_items_in_place(&[("egg", 2), ("milk", 1)], &mut pantry), Ok(()));
+        assert_eq!(pantry, ["flour"]);
+    }
+
+    #[test]
+    fn test_multiset_subtract() {
+        assert_eq!(multiset_subtract(&[1, 2, 1, 3, 1], &[1, 1, 4]), [2, 3, 1]);
+        assert_eq!(multiset_subtract(&[1, 2, 1], &[1, 1, 1, 2]), Vec::<i32>::new());
+        assert_eq!(multiset_subtract(&[2, 1, 2], &[3]), [2, 1, 2]);
+        assert_eq!(multiset_subtract(&[2, 1, 2], &[]), [2, 1, 2]);
+        assert!(multiset_subtract::<u8>(&[], &[1, 2]).is_empty());
+        assert_eq!(multiset_subtract(&[1, 2, 1], &[1, 1]), [2]);
+        assert_eq!(multiset_subtract(&[1, 2, 1], &[1]), [2, 1]);
+    }
+
+    #[test]
+    fn test_multiset_intersection() {
+        assert_eq!(multiset_intersection(&[1, 2, 1, 3, 1], &[1, 4, 1, 3]), [1, 1, 3]);
+        assert_eq!(multiset_intersection(&[1, 2, 1], &[2, 1, 1, 1, 2]), [1, 2, 1]);
+        assert!(multiset_intersection(&[1, 2], &[3, 4]).is_empty());
+        assert!(multiset_intersection::<u8>(&[1, 2], &[]).is_empty());
+        assert!(multiset_intersection::<u8>(&[], &[1, 2]).is_empty());
+        assert_eq!(multiset_intersection(&[1, 2, 1], &[1, 1]), [1, 1]);
+        assert_eq!(multiset_intersection(&[1, 2, 1], &[1, 2, 2]), [1, 2]);
+    }
+
+    #[test]
+    fn test_multiset_subtract_and_intersection_partition_a() {
+        for (a, req) in random_cases(43, 200) {
+            let b: Vec<u8> = req.iter().flat_map(|&(item, count)| core::iter::repeat_n(item, count)).collect();
+            let rest = multiset_subtract(&a, &b);
+            let common = multiset_intersection(&a, &b);
+            let mut both = [rest.clone(), common.clone()].concat();
+            let mut sorted_a = a.clone();
+            both.sort();
+            sorted_a.sort();
+            assert_eq!(both, sorted_a);
+            for value in 0..10 {
+                let count = |items: &[u8]| items.iter().filter(|&&item| item == value).count();
+                assert_eq!(count(&common), count(&a).min(count(&b)));
+                assert_eq!(count(&rest), count(&a).saturating_sub(count(&b)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_many_requirements() {
+        let volunteers = ["medic", "driver", "cook", "driver"];
+        let rally: &[(&str, usize)] = &[("medic", 1), ("driver", 2)];
+        let picnic: &[(&str, usize)] = &[("cook", 2)];
+        let nothing: &[(&str, usize)] = &[];
+        let flags = check_many_requirements(&[rally, picnic, nothing, rally], &volunteers);
+        assert_eq!(flags, vec![true, false, true, true]);
+        assert!(check_many_requirements::<&str>(&[], &volunteers).is_empty());
+        assert_eq!(check_many_requirements(&[picnic, nothing], &[]), vec![false, true]);
+    }
+
+    #[test]
+    fn test_satisfiable_together_needs_enough_for_all_sets() {
+        let volunteers = ["medic", "driver", "cook", "driver"];
+        let rally: &[(&str, usize)] = &[("medic", 1), ("driver", 2)];
+        let market: &[(&str, usize)] = &[("driver", 1), ("cook", 1)];
+        assert_eq!(check_many_requirements(&[rally, market], &volunteers), vec![true, true]);
+        assert!(!satisfiable_together(&[rally, market], &volunteers));
+        assert!(satisfiable_together(&[rally], &volunteers));
+        assert!(satisfiable_together(&[&[("cook", 1), ("medic", 1)], &[("driver", 2)]], &volunteers));
+        assert!(!satisfiable_together(&[&[("driver", 1), ("driver", 2)]], &volunteers));
+        assert!(satisfiable_together::<&str>(&[], &[]));
+    }
+
+    #[test]
+    fn test_satisfiable_together_at_the_boundary() {
+        let volunteers = ["driver", "driver", "driver", "cook"];
+        let rally: &[(&str, usize)] = &[("driver", 2)];
+        let market: &[(&str, usize)] = &[("driver", 1), ("cook", 1)];
+        let parade: &[(&str, usize)] = &[("driver", 1)];
+        assert!(satisfiable_together(&[rally, market], &volunteers));
+        assert!(!satisfiable_together(&[rally, market, parade], &volunteers));
+        // A requirement listed twice in one set counts twice together, but only once on its own.
+        let twice: &[(&str, usize)] = &[("driver", 2), ("driver", 2)];
+        assert_eq!(check_many_requirements(&[twice], &volunteers), vec![true]);
+        assert!(!satisfiable_together(&[twice], &volunteers));
+        let nothing: &[(&str, usize)] = &[];
+        assert!(satisfiable_together(&[nothing, nothing], &[]));
+        assert!(!satisfiable_together(&[nothing, parade], &[]));
+    }
+
+    #[test]
+    fn test_check_many_requirements_agrees_with_check_sufficient_items() {
+        for (checked, req) in random_cases(44, 200) {
+            let sets: Vec<&[(u8, usize)]> = req.chunks(2).collect();
+            let expected: Vec<bool> = sets.iter().map(|req| check_sufficient_items(req, &checked)).collect();
+            assert_eq!(check_many_requirements(&sets, &checked), expected);
+            let together = consume_items(&req, checked.clone()).is_ok();
+            assert_eq!(satisfiable_together(&sets, &checked), together);
+            if together {
+                assert!(expected.iter().all(|&ok| ok));
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_sufficient_items_fast() {
+        let pantry = ["egg", "flour", "egg"];
+        assert!(check_sufficient_items_fast(&[("egg", 2), ("flour", 1)], &pantry));
+        assert!(!check_sufficient_items_fast(&[("egg", 3), ("flour", 1)], &pantry));
+        // Every requirement is checked on its own, so the largest count of a repeated item decides.
+        assert!(check_sufficient_items_fast(&[("egg", 2), ("egg", 1)], &pantry));
+        assert!(!check_sufficient_items_fast(&[("egg", 1), ("egg", 3)], &pantry));
+        assert!(check_sufficient_items_fast(&[("milk", 0)], &[]));
+        assert!(!check_sufficient_items_fast(&[("milk", 1)], &[]));
+        assert!(check_sufficient_items_fast::<&str>(&[], &[]));
+    }
+
+    #[test]
+    fn test_check_sufficient_items_fast_agrees_with_check_sufficient_items() {
+        for (checked, req) in random_cases(40, 300) {
+            let expected = check_sufficient_items(&req, &checked);
+            assert_eq!(check_sufficient_items_fast(&req, &checked), expected, "{:?} {:?}", req, checked);
+        }
+    }
+
+    #[test]
+    fn test_most_and_least_common() {
+        let items = [5, 1, 2, 1, 3, 3, 3, 2, 4, 3];
+        assert_eq!(most_common(&items, 2), vec![(3, 4), (1, 2)]);
+        assert_eq!(least_common(&items, 2), vec![(5, 1), (4, 1)]);
+        assert_eq!(most_common(&items, 0), vec![]);
+        assert_eq!(least_common(&items, 0), vec![]);
+        assert_eq!(most_common(&items, 10), tally(&items));
+        assert_eq!(least_common(&items, 10), vec![(5, 1), (4, 1), (1, 2), (2, 2), (3, 4)]);
+        assert_eq!(most_common::<u8>(&[], 3), vec![]);
+    }
+
+    #[test]
+    fn test_most_common_tie_at_cutoff() {
+        // 'b', 'c' and 'd' all occur twice; 'b' occurs first and 'd' second, so they make the cut.
+        let items = ['a', 'b', 'd', 'a', 'c', 'a', 'd', 'c', 'b'];
+        assert_eq!(most_common(&items, 3), vec![('a', 3), ('b', 2), ('d', 2)]);
+        assert_eq!(least_common(&items, 2), vec![('b', 2), ('d', 2)]);
+        for n in 0..=4 {
+            assert_eq!(most_common(&items, n), tally(&items)[..n]);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! JSON import and export of split results.
+//!
+//! Parts are stored in the following schema, where `metadata` is optional and currently only records the seed
+//! used for the split:
+//!
+//! ```json
+//! {
+//!   "parts": [["Ann", "Ben"], ["Cem"]],
+//!   "metadata": { "seed": 42 }
+//! }
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// An error while converting parts to or from JSON.
+#[derive(Debug)]
+pub struct JsonError {
+    part: Option<usize>,
+    source: serde_json::Error,
+}
+
+impl JsonError {
+    /// Returns the index of the part that failed to convert, or `None` if the document as a whole is invalid.
+    pub fn part(&self) -> Option<usize> {
+        self.part
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.part {
+            Some(part) => write!(f, "part {}: {}", part, self.source),
+            None => write!(f, "invalid parts document: {}", self.source),
+        }
+    }
+}
+
+impl std::error::Error for JsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Document<P> {
+    parts: Vec<P>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    metadata: Option<Metadata>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Metadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+}
+
+/// Converts parts to a JSON document of the form `{"parts": [[...], [...]]}`.
+/// # Arguments
+/// * `parts` - The parts to convert.
+///
+/// # Returns
+/// The JSON document, or a [`JsonError`] naming the part that could not be serialized.
+/// # Example
+/// ```
+/// use rust_helpers::parts_to_json;
+/// assert_eq!(parts_to_json(&[vec![1, 2], vec![3]]).unwrap(), r#"{"parts":[[1,2],[3]]}"#);
+/// ```
+pub fn parts_to_json<T: Serialize>(parts: &[Vec<T>]) -> Result<String, JsonError> {
+    to_document(parts, None)
+}
+
+/// Like [`parts_to_json`], but records the seed used for the split in the `metadata` field.
+/// # Example
+/// ```
+/// use rust_helpers::{parts_from_json_with_seed, parts_to_json_with_seed, split_rand_vec_eq_seeded};
+/// let parts = split_rand_vec_eq_seeded(vec![1, 2, 3], 2, 42);
+/// let json = parts_to_json_with_seed(&parts, 42).unwrap();
+/// assert!(json.ends_with(r#""metadata":{"seed":42}}"#));
+/// assert_eq!(parts_from_json_with_seed::<i32>(&json).unwrap(), (parts, Some(42)));
+/// ```
+pub fn parts_to_json_with_seed<T: Serialize>(parts: &[Vec<T>], seed: u64) -> Result<String, JsonError> {
+    to_document(parts, Some(Metadata { seed: Some(seed) }))
+}
+
+/// Reads parts from a JSON document written by [`parts_to_json`] or [`parts_to_json_with_seed`].
+/// # Arguments
+/// * `s` - The JSON document.
+///
+/// # Returns
+/// The parts, or a [`JsonError`] naming the part that could not be deserialized.
+/// # Example
+/// ```
+/// use rust_helpers::parts_from_json;
+/// let parts: Vec<Vec<String>> = parts_from_json(r#"{"parts":[["Ann"],["Ben","Cem"]]}"#).unwrap();
+/// assert_eq!(parts[1], vec!["Ben", "Cem"]);
+/// ```
+pub fn parts_from_json<T: DeserializeOwned>(s: &str) -> Result<Vec<Vec<T>>, JsonError> {
+    parts_from_json_with_seed(s).map(|(parts, _)| parts)
+}
+
+/// Like [`parts_from_json`], but also returns the seed recorded in the `metadata` field, if any.
+/// # Example
+/// ```
+/// use rust_helpers::parts_from_json_with_seed;
+/// let (parts, seed) = parts_from_json_with_seed::<i32>(r#"{"parts":[[1]],"metadata":{"seed":7}}"#).unwrap();
+/// assert_eq!((parts, seed), (vec![vec![1]], Some(7)));
+/// ```
+pub fn parts_from_json_with_seed<T: DeserializeOwned>(s: &str) -> Result<(Vec<Vec<T>>, Option<u64>), JsonError> {
+    let document: Document<Value> = serde_json::from_str(s).map_err(|source| JsonError { part: None, source })?;
+    let parts = document
+        .parts
+        .into_iter()
+        .enumerate()
+        .map(|(part, value)| serde_json::from_value(value).map_err(|source| JsonError { part: Some(part), source }))
+        .collect::<Result<_, _>>()?;
+    Ok((parts, document.metadata.and_then(|metadata| metadata.seed)))
+}
+
+fn to_document<T: Serialize>(parts: &[Vec<T>], metadata: Option<Metadata>) -> Result<String, JsonError> {
+    let parts = parts
+        .iter()
+        .enumerate()
+        .map(|(part, elements)| serde_json::to_value(elements).map_err(|source| JsonError { part: Some(part), source }))
+        .collect::<Result<_, _>>()?;
+    serde_json::to_string(&Document { parts, metadata }).map_err(|source| JsonError { part: None, source })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parts_json_round_trip() {
+        let parts = vec![vec!["Zoë".to_string(), "Jürgen".to_string()], vec!["山田".to_string()], vec![]];
+        let json = parts_to_json(&parts).unwrap();
+        assert_eq!(json, r#"{"parts":[["Zoë","Jürgen"],["山田"],[]]}"#);
+        assert_eq!(parts_from_json::<String>(&json).unwrap(), parts);
+
+        let json = parts_to_json_with_seed(&parts, 9).unwrap();
+        assert_eq!(parts_from_json_with_seed::<String>(&json).unwrap(), (parts, Some(9)));
+    }
+
+    #[test]
+    fn test_parts_from_json_errors() {
+        let error = parts_from_json::<u32>(r#"{"parts":[[1, 2], [3, "x"]]}"#).unwrap_err();
+        assert_eq!(error.part(), Some(1));
+        assert!(error.to_string().starts_with("part 1: "));
+
+        let error = parts_from_json::<u32>(r#"{"groups": [[1, 2]]}"#).unwrap_err();
+        assert_eq!(error.part(), None);
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn test_parts_to_json_errors() {
+        let bad: HashMap<(u8, u8), u8> = [((1, 2), 3)].into_iter().collect();
+        let error = parts_to_json(&[vec![], vec![bad]]).unwrap_err();
+        assert_eq!(error.part(), Some(1));
+    }
+}
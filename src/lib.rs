@@ -1,21 +1,204 @@
 //! Rust Helpers Library
+//!
+//! # Features
+//! * `std` - Enables the functions that use the thread-local RNG or hash-based collections. On by default. Without
+//!   it the crate is `no_std` and needs only `alloc`; use the `_with_rng` and `_seeded` variants there.
+//! * `serde` - Derives `Serialize` and `Deserialize` for the public option and result types, and `Serialize` for
+//!   [`SplitError`]. Off by default.
+//! * `json` - Adds `parts_to_json` and `parts_from_json` for a stable JSON schema. Implies `serde`.
+//! * `csv` - Adds `parts_to_csv` and `parts_to_csv_wide` for exporting parts to spreadsheets.
+//! * `rayon` - Adds `par_split_rand_vec` and `par_split_rand_vec_eq`, which shuffle very large vectors in parallel.
+//! * `wasm` - Lets the thread-local RNG draw entropy from the browser on `wasm32-unknown-unknown`, through
+//!   getrandom's `wasm_js` backend. The application also has to build with `--cfg getrandom_backend="wasm_js"`.
+//!   Alternatively, stick to the `_with_rng` and `_seeded` variants, which never touch OS entropy.
+//! * `tracing` - Emits `tracing` spans and events from [`check_sufficient_items`] and the split functions: input
+//!   sizes, counts and the chosen part sizes. Off by default; without it nothing is logged.
+//!
+//! # `no_std`
+//! With `default-features = false` the crate only needs `alloc`. The `_with_rng` and `_seeded` helpers, the
+//! [`RandomSplitter`] builder and [`check_sufficient_items`] stay available:
+//! ```
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//! use rust_helpers::{check_sufficient_items, split_rand_vec_eq_with_rng, split_rand_vec_with_rng};
+//!
+//! let mut rng = StdRng::seed_from_u64(3);
+//! let parts = split_rand_vec_eq_with_rng((0..10).collect::<Vec<u32>>(), 3, &mut rng);
+//! assert_eq!(parts.iter().map(Vec::len).sum::<usize>(), 10);
+//! let chunks = split_rand_vec_with_rng((0..10).collect::<Vec<u32>>(), 4, &mut rng);
+//! assert_eq!(chunks.len(), 3);
+//! assert!(check_sufficient_items(&[(1, 2)], &[1, 2, 1]));
+//! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
 use rand::prelude::*;
-use std::collections::HashSet;
+use rand::rngs::StdRng;
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+mod builder;
+mod constraints;
+#[cfg(feature = "csv")]
+mod csv;
+mod error;
+#[cfg(feature = "std")]
+mod ext;
+mod format;
+mod items;
+#[cfg(feature = "json")]
+mod json;
+mod merge;
+mod options;
+#[cfg(feature = "rayon")]
+mod par;
+mod parts;
+mod permutation;
+#[cfg(feature = "std")]
+pub mod prelude;
+mod sample;
+#[cfg(feature = "std")]
+mod schedule;
+mod shuffle;
+#[cfg(feature = "std")]
+mod verify;
+
+pub use builder::{RandomSplitter, Strategy};
+pub use constraints::{
+    split_rand_vec_eq_apart_with_rng, split_rand_vec_eq_pinned_with_rng, split_rand_vec_eq_together_with_rng,
+};
+#[cfg(feature = "std")]
+pub use constraints::{split_rand_vec_eq_apart, split_rand_vec_eq_pinned, split_rand_vec_eq_together};
+#[cfg(feature = "csv")]
+pub use csv::{parts_to_csv, parts_to_csv_wide};
+pub use error::{
+    CountViolation, DerangeError, InsufficientItemsError, NoValidArrangement, RangeError, RangeViolations, SampleError,
+    SplitError,
+};
+#[cfg(feature = "std")]
+pub use ext::{SetRandExt, VecRandExt};
+pub use format::{format_parts, format_parts_with_labels, PartsFormat};
+pub use items::{missing_items, ItemStatus, ItemsDiff, Normalizer, SufficiencyReport};
+#[cfg(feature = "std")]
+pub use items::{
+    check_at_most_items, check_at_most_items_strict, check_counts_in_range, check_exact_items, check_items_report,
+    check_items_report_by_key, check_many_requirements, check_sufficient_items_by_key, check_sufficient_items_fast,
+    check_sufficient_items_normalized, consume_items, consume_items_in_place, count_occurrences,
+    count_occurrences_owned, diff_items, duplicates_by_key, ensure_sufficient_items, ensure_sufficient_items_by_key,
+    exceeded_limits, exceeded_limits_strict, find_duplicates, has_duplicates, how_many_complete_sets, least_common,
+    most_common, multiset_intersection, multiset_subtract, satisfiable_together, tally, tally_with_min_count,
+    unique_by_key_preserving_order, unique_preserving_order,
+};
+#[cfg(feature = "json")]
+pub use json::{parts_from_json, parts_from_json_with_seed, parts_to_json, parts_to_json_with_seed, JsonError};
+#[cfg(feature = "std")]
+pub use merge::flatten_parts_shuffled;
+pub use merge::{
+    flatten_parts, flatten_parts_indexed, flatten_parts_shuffled_with_rng, interleave_parts, transpose_parts,
+    transpose_parts_padded,
+};
+#[cfg(feature = "std")]
+pub use options::split;
+pub use options::{SplitMode, SplitOptions};
+#[cfg(feature = "rayon")]
+pub use par::{
+    par_split_rand_vec, par_split_rand_vec_eq, par_split_rand_vec_eq_with_rng, par_split_rand_vec_with_rng,
+    try_par_split_rand_vec, try_par_split_rand_vec_eq,
+};
+pub use parts::Parts;
+#[cfg(feature = "std")]
+pub use permutation::{derange, derange_map, random_permutation, try_derange_map};
+pub use permutation::{
+    derange_map_with_rng, derange_with_rng, random_permutation_seeded, random_permutation_with_rng, shuffle_tracked,
+    try_derange_map_with_rng, Permutation,
+};
+#[cfg(feature = "std")]
+pub use sample::{
+    choose_random, choose_random_index, choose_random_mut, drain_random_n, drain_random_n_stable, pop_random,
+    pop_random_stable, sample_n, sample_n_owned, sample_n_with_replacement, sample_subset, sample_weighted,
+    sample_weighted_with_rng, take_subset, take_subset_with_rng,
+};
+pub use sample::{
+    choose_random_index_with_rng, choose_random_mut_with_rng, choose_random_with_rng, drain_random_n_stable_with_rng,
+    drain_random_n_with_rng, pop_random_stable_with_rng, pop_random_with_rng, random_mask, random_mask_p,
+    reservoir_sample, sample_n_owned_with_rng, sample_n_with_replacement_seeded, sample_n_with_replacement_with_rng,
+    sample_n_with_rng,
+};
+pub use shuffle::{
+    partial_shuffle_k, partial_shuffle_k_seeded, rotate_random, rotated_random_with_rng, shuffle_range, shuffle_tail,
+    swap_between_parts, swap_random_pairs,
+};
+#[cfg(feature = "std")]
+pub use shuffle::{
+    rotated_random, shuffle_no_adjacent, shuffle_no_adjacent_with_rng, shuffled_entries, shuffled_entries_seeded,
+    shuffled_keys, shuffled_keys_seeded, shuffled_values, shuffled_values_seeded, weighted_shuffle,
+};
+#[cfg(feature = "std")]
+pub use schedule::{
+    assign_leaders, assign_leaders_seeded, assign_leaders_with_rng, pair_coverage, pair_rand, pair_rand_with_rng,
+    pair_rand_with_triple, pair_rand_with_triple_with_rng, round_robin_schedule, round_robin_schedule_with_rng,
+    schedule_rounds, schedule_rounds_with_rng, split_rand_vec_eq_avoiding, split_rand_vec_eq_avoiding_with_rng,
+    try_schedule_rounds, try_split_rand_vec_eq_avoiding, Pairings,
+};
+#[cfg(feature = "std")]
+pub use verify::{verify_balanced, verify_split, SplitMismatch};
+
+/// Determines what happens when more parts are requested than there are elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmptyPartsPolicy {
+    /// Empty parts are allowed. This is the behavior of [`split_rand_vec_eq`].
+    #[default]
+    Allow,
+    /// Requesting more parts than elements returns [`SplitError::TooManyParts`].
+    Error,
+    /// Only as many parts as there are elements are produced.
+    ShrinkToLen,
+}
+
+/// Determines how [`split_rand_vec_with_strategy`] handles elements that do not fill a whole chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RemainderStrategy {
+    /// The leftover elements form a smaller trailing chunk. This is the behavior of [`split_rand_vec`].
+    #[default]
+    LastSmaller,
+    /// The leftover elements are dropped, so every chunk has exactly `chunk_size` elements.
+    DropRemainder,
+    /// The leftover elements are spread one by one across the chunks, starting with the first.
+    /// If there are fewer elements than `chunk_size`, they form a single smaller chunk.
+    Distribute,
+    /// The number of elements must be divisible by `chunk_size`, otherwise [`SplitError::Indivisible`] is returned.
+    Strict,
+}
 
 /// Sends greetings from the Rust Helpers library.
 pub fn greet_rust_helpers(name: &str) -> String {
     format!("Hello, {}! Welcome to Rust Helpers.", name)
 }
+
 /// Randomizes the order of a vec and splits it into smaller vectors of a specified size.
 ///
+/// The elements are moved into the chunks without being cloned, so `T` doesn't need to implement `Clone`.
 /// # Arguments
-/// * `vec` - The vector to be split and randomized. It must implement the `Clone` trait.
+/// * `vec` - The vector to be split and randomized.
 /// * `chunk_size` - The size of each chunk in the resulting vector. The last chunk may be smaller if the total number of elements is not divisible by `chunk_size`.
 ///
 /// # Returns
 /// A vector of vectors, where each inner vector is a chunk of the original vector, randomized in order.
 ///
+/// # Panics
+/// Panics if `chunk_size` is 0. Use [`try_split_rand_vec`] to get an error instead.
+///
 /// # Example
 /// ```
 /// use rust_helpers::split_rand_vec;
@@ -25,131 +208,3183 @@ pub fn greet_rust_helpers(name: &str) -> String {
 /// assert!(chunked.iter().all(|chunk| chunk.len() <= 3));
 /// /// The elements in the chunks are randomized, so the order may vary.
 /// ```
-pub fn split_rand_vec<T: Clone>(vec: Vec<T>, chunk_size: usize) -> Vec<Vec<T>> {
-    let mut rng = rand::rng();
-    let mut vec: Vec<T> = vec;
-    vec.shuffle(&mut rng);
-    vec.chunks(chunk_size).map(|chunk| chunk.to_vec()).collect()
+#[cfg(feature = "std")]
+pub fn split_rand_vec<T>(vec: Vec<T>, chunk_size: usize) -> Vec<Vec<T>> {
+    split_rand_vec_with_rng(vec, chunk_size, &mut rand::rng())
 }
 
 /// Randomizes the order of a vec and splits it into smaller vectors of equal size.
+///
+/// Every part is allocated with its final size up front, and the elements are moved into it rather than cloned.
 /// # Arguments
-/// * `vec` - The vector to be split and randomized. It must implement the `Clone` trait.
-/// * `parts` - The number of parts to split the vector into. A number of parts may contain fewer elements than others if the total number of elements is not divisible by `parts`.
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into. If the total number of elements is not divisible by `parts`,
+///   the part sizes differ by one, and which parts receive the extra elements is chosen at random.
 ///
 /// # Returns
 /// A vector of vectors, where each inner vector is a part of the original vector, randomized in order.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_vec_eq`] to get an error instead.
 /// # Example
 /// ```
 /// use rust_helpers::split_rand_vec_eq;
 /// let vec = vec![1, 2, 3, 4, 5, 6, 7];
 /// let chunked = split_rand_vec_eq(vec, 3);
 /// assert_eq!(chunked.len(), 3);
-/// assert_eq!(chunked[0].len(), 3);
-/// assert_eq!(chunked[1].len(), 2);
-/// assert_eq!(chunked[2].len(), 2);
+/// let mut sizes: Vec<usize> = chunked.iter().map(|part| part.len()).collect();
+/// sizes.sort();
+/// assert_eq!(sizes, vec![2, 2, 3]);
 /// /// The elements in the chunks are randomized, so the order may vary.
 /// ```
-pub fn split_rand_vec_eq<T: Clone>(vec: Vec<T>, parts: usize) -> Vec<Vec<T>> {
-    let mut rng = rand::rng();
-    let mut vec: Vec<T> = vec;
-    vec.shuffle(&mut rng);
-    let mut result = vec![Vec::new(); parts];
-    for (i, e) in vec.iter().enumerate() {
-        result[i % parts].push(e.clone());
-    }
-    return result;
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq<T>(vec: Vec<T>, parts: usize) -> Vec<Vec<T>> {
+    split_rand_vec_eq_with_rng(vec, parts, &mut rand::rng())
 }
 
 /// Randomizes the order of a HashSet and splits it into smaller vectors of equal size.
 /// # Arguments
-/// * `vec` - The vector to be split and randomized. It must implement the `Clone` trait.
-/// * `parts` - The number of parts to split the vector into. A number of parts may contain fewer elements than others if the total number of elements is not divisible by `parts`.
+/// * `set` - The HashSet to be split and randomized.
+/// * `parts` - The number of parts to split the set into. The part sizes follow the same rule as [`split_rand_vec_eq`].
 ///
 /// # Returns
-/// A vector of vectors, where each inner vector is a part of the original vector, randomized in order.
+/// A vector of vectors, where each inner vector is a part of the original set, randomized in order.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_hashset_eq`] to get an error instead.
 /// # Example
 /// ```
-/// use rust_helpers::split_rand_vec_eq;
-/// let vec = vec![1, 2, 3, 4, 5, 6, 7];
-/// let chunked = split_rand_vec_eq(vec, 3);
+/// use rust_helpers::split_rand_hashset_eq;
+/// use std::collections::HashSet;
+/// let set: HashSet<i32> = (1..=7).collect();
+/// let chunked = split_rand_hashset_eq(set, 3);
 /// assert_eq!(chunked.len(), 3);
-/// assert_eq!(chunked[0].len(), 3);
-/// assert_eq!(chunked[1].len(), 2);
-/// assert_eq!(chunked[2].len(), 2);
+/// let mut sizes: Vec<usize> = chunked.iter().map(|part| part.len()).collect();
+/// sizes.sort();
+/// assert_eq!(sizes, vec![2, 2, 3]);
 /// /// The elements in the chunks are randomized, so the order may vary.
 /// ```
-pub fn split_rand_hashset_eq<T: Clone>(vec: HashSet<T>, parts: usize) -> Vec<Vec<T>> {
-    let mut rng = rand::rng();
-    let mut vec: Vec<T> = vec.into_iter().collect();
-    vec.shuffle(&mut rng);
-    let mut result = vec![Vec::new(); parts];
-    for (i, e) in vec.iter().enumerate() {
-        result[i % parts].push(e.clone());
+#[cfg(feature = "std")]
+pub fn split_rand_hashset_eq<T>(set: HashSet<T>, parts: usize) -> Vec<Vec<T>> {
+    split_rand_hashset_eq_with_rng(set, parts, &mut rand::rng())
+}
+
+/// Randomizes the order of a vec with the given RNG and splits it into smaller vectors of a specified size.
+///
+/// This allows passing a seeded RNG for deterministic results or reusing one RNG across many calls.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `chunk_size` - The size of each chunk in the resulting vector. The last chunk may be smaller if the total number of elements is not divisible by `chunk_size`.
+/// * `rng` - The random number generator used for shuffling.
+///
+/// # Returns
+/// A vector of vectors, where each inner vector is a chunk of the original vector, randomized in order.
+///
+/// # Panics
+/// Panics if `chunk_size` is 0. Use [`try_split_rand_vec_with_rng`] to get an error instead.
+///
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::split_rand_vec_with_rng;
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let chunked = split_rand_vec_with_rng(vec![1, 2, 3, 4, 5, 6, 7, 8], 3, &mut rng);
+/// assert_eq!(chunked.len(), 3);
+/// ```
+pub fn split_rand_vec_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, chunk_size: usize, rng: &mut R) -> Vec<Vec<T>> {
+    assert!(chunk_size > 0, "chunk size must be greater than zero");
+    let sizes = chunk_sizes(vec.len(), chunk_size, RemainderStrategy::LastSmaller).unwrap_or_default();
+    shuffle_and_carve(vec, &sizes, rng)
+}
+
+/// Like [`split_rand_vec_with_rng`], but returns [`SplitError::ZeroChunkSize`] instead of panicking if `chunk_size` is 0.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::{try_split_rand_vec_with_rng, SplitError};
+/// let mut rng = StdRng::seed_from_u64(1);
+/// assert_eq!(try_split_rand_vec_with_rng(vec![1, 2, 3], 0, &mut rng), Err(SplitError::ZeroChunkSize));
+/// assert_eq!(try_split_rand_vec_with_rng(vec![1, 2, 3], 2, &mut rng).unwrap().len(), 2);
+/// ```
+pub fn try_split_rand_vec_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, chunk_size: usize, rng: &mut R) -> Result<Vec<Vec<T>>, SplitError> {
+    if chunk_size == 0 {
+        return Err(SplitError::ZeroChunkSize);
     }
-    return result;
+    Ok(split_rand_vec_with_rng(vec, chunk_size, rng))
 }
 
-/// Checks if a slice of the type 'T' contains sufficient items as specified.
+/// Randomizes the order of a vec with the given RNG and splits it into smaller vectors of equal size.
 /// # Arguments
-/// * `req_items` - A slice of tuples where each tuple contains an item of type `T` and a required count of that item.
-/// * `checked_vector` - A slice of items of type `T` that has been checked against the requirements.
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into. A number of parts may contain fewer elements than others if the total number of elements is not divisible by `parts`.
+/// * `rng` - The random number generator used for shuffling.
 ///
 /// # Returns
-/// A boolean indicating whether the checked vector contains sufficient items as specified in `req_items`.
+/// A vector of vectors, where each inner vector is a part of the original vector, randomized in order.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_vec_eq_with_rng`] to get an error instead.
 /// # Example
 /// ```
-/// use rust_helpers::check_sufficient_items;
-/// let req_items = [("apple", 2), ("banana", 1), ("orange", 3)];
-/// let checked_vector = vec!["apple", "apple", "apple", "banana", "orange", "orange", "orange", "orange"];
-/// assert!(check_sufficient_items(&req_items, &checked_vector));
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::split_rand_vec_eq_with_rng;
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let chunked = split_rand_vec_eq_with_rng(vec![1, 2, 3, 4, 5, 6, 7], 3, &mut rng);
+/// assert_eq!(chunked.iter().map(|part| part.len()).sum::<usize>(), 7);
+/// assert_eq!(chunked.iter().map(|part| part.len()).max(), Some(3));
 /// ```
-pub fn check_sufficient_items<T: PartialEq>(req_items: &[(T, usize)], checked_vector: &[T]) -> bool {
-    for (item, req_number_item) in req_items {
-        let cnt_check = dbg!(checked_vector.iter().filter(|x| *x == item).count());
-        if cnt_check < *req_number_item {
-            return false;
-        }
+pub fn split_rand_vec_eq_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, parts: usize, rng: &mut R) -> Vec<Vec<T>> {
+    deal_with_random_extras(vec, parts, rng)
+}
+
+/// Like [`split_rand_vec_eq_with_rng`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::{try_split_rand_vec_eq_with_rng, SplitError};
+/// let mut rng = StdRng::seed_from_u64(1);
+/// assert_eq!(try_split_rand_vec_eq_with_rng(vec![1, 2, 3], 0, &mut rng), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_vec_eq_with_rng(vec![1, 2, 3], 2, &mut rng).unwrap().len(), 2);
+/// ```
+pub fn try_split_rand_vec_eq_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, parts: usize, rng: &mut R) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
     }
-    true
+    Ok(split_rand_vec_eq_with_rng(vec, parts, rng))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Randomizes the order of a HashSet with the given RNG and splits it into smaller vectors of equal size.
+///
+/// Note that the iteration order of a `HashSet` is not stable, so a seeded RNG alone does not make the
+/// result reproducible. Use [`split_rand_hashset_eq_seeded`] for that.
+/// # Arguments
+/// * `set` - The HashSet to be split and randomized.
+/// * `parts` - The number of parts to split the set into. The part sizes follow the same rule as [`split_rand_vec_eq`].
+/// * `rng` - The random number generator used for shuffling.
+///
+/// # Returns
+/// A vector of vectors, where each inner vector is a part of the original set, randomized in order.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_hashset_eq_with_rng`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_hashset_eq_with_rng;
+/// use std::collections::HashSet;
+/// let set: HashSet<i32> = (1..=7).collect();
+/// let chunked = split_rand_hashset_eq_with_rng(set, 3, &mut rand::rng());
+/// assert_eq!(chunked.iter().map(|part| part.len()).max(), Some(3));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_hashset_eq_with_rng<T, R: Rng + ?Sized>(set: HashSet<T>, parts: usize, rng: &mut R) -> Vec<Vec<T>> {
+    deal_with_random_extras(set.into_iter().collect(), parts, rng)
+}
 
-    #[test]
-    fn test_greet_rust_helpers() {
-        let greeting = greet_rust_helpers("Alice");
-        assert_eq!(greeting, "Hello, Alice! Welcome to Rust Helpers.");
+/// Like [`split_rand_hashset_eq_with_rng`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_hashset_eq_with_rng, SplitError};
+/// use std::collections::HashSet;
+/// let set: HashSet<i32> = (1..=7).collect();
+/// assert_eq!(try_split_rand_hashset_eq_with_rng(set.clone(), 0, &mut rand::rng()), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_hashset_eq_with_rng(set, 3, &mut rand::rng()).unwrap().len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_hashset_eq_with_rng<T, R: Rng + ?Sized>(set: HashSet<T>, parts: usize, rng: &mut R) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
     }
+    Ok(split_rand_hashset_eq_with_rng(set, parts, rng))
+}
 
-    #[test]
-    fn test_split_rand_vec() {
-        let vec = vec![1, 2, 3, 4, 5, 6, 7, 8];
-        let chunked = split_rand_vec(vec, 3);
-        assert_eq!(chunked.len(), 3);
-        assert!(chunked.iter().all(|chunk| chunk.len() <= 3));
+/// Randomizes the order of a vec with a seeded RNG and splits it into smaller vectors of a specified size.
+///
+/// Calling this function twice with the same input and seed produces identical output.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `chunk_size` - The size of each chunk in the resulting vector. The last chunk may be smaller if the total number of elements is not divisible by `chunk_size`.
+/// * `seed` - The seed used to initialize the random number generator.
+///
+/// # Returns
+/// A vector of vectors, where each inner vector is a chunk of the original vector, randomized in order.
+///
+/// # Panics
+/// Panics if `chunk_size` is 0. Use [`split`] with a seed in [`SplitOptions`] to get an error instead.
+///
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_seeded;
+/// let vec = vec![1, 2, 3, 4, 5, 6, 7, 8];
+/// let first = split_rand_vec_seeded(vec.clone(), 3, 42);
+/// let second = split_rand_vec_seeded(vec, 3, 42);
+/// assert_eq!(first, second);
+/// ```
+pub fn split_rand_vec_seeded<T>(vec: Vec<T>, chunk_size: usize, seed: u64) -> Vec<Vec<T>> {
+    split_rand_vec_with_rng(vec, chunk_size, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Randomizes the order of a vec with a seeded RNG and splits it into smaller vectors of equal size.
+///
+/// Calling this function twice with the same input and seed produces identical output.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into. A number of parts may contain fewer elements than others if the total number of elements is not divisible by `parts`.
+/// * `seed` - The seed used to initialize the random number generator.
+///
+/// # Returns
+/// A vector of vectors, where each inner vector is a part of the original vector, randomized in order.
+/// # Panics
+/// Panics if `parts` is 0. Use [`split`] with a seed in [`SplitOptions`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_eq_seeded;
+/// let vec = vec!["Ann", "Ben", "Cem", "Dana", "Eli", "Finn", "Gus"];
+/// let groups = split_rand_vec_eq_seeded(vec.clone(), 3, 2024);
+/// assert_eq!(groups, split_rand_vec_eq_seeded(vec, 3, 2024));
+/// ```
+pub fn split_rand_vec_eq_seeded<T>(vec: Vec<T>, parts: usize, seed: u64) -> Vec<Vec<T>> {
+    split_rand_vec_eq_with_rng(vec, parts, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Randomizes the order of a HashSet with a seeded RNG and splits it into smaller vectors of equal size.
+///
+/// The elements are sorted before shuffling, because the iteration order of a `HashSet` differs between
+/// instances. This way the same set and seed always produce identical output.
+/// # Arguments
+/// * `set` - The HashSet to be split and randomized. Its elements must implement the `Ord` trait.
+/// * `parts` - The number of parts to split the set into. The part sizes follow the same rule as [`split_rand_vec_eq`].
+/// * `seed` - The seed used to initialize the random number generator.
+///
+/// # Returns
+/// A vector of vectors, where each inner vector is a part of the original set, randomized in order.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_hashset_eq_seeded`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_hashset_eq_seeded;
+/// use std::collections::HashSet;
+/// let set: HashSet<i32> = (1..=7).collect();
+/// let chunked = split_rand_hashset_eq_seeded(set.clone(), 3, 7);
+/// assert_eq!(chunked, split_rand_hashset_eq_seeded(set, 3, 7));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_hashset_eq_seeded<T: Ord>(set: HashSet<T>, parts: usize, seed: u64) -> Vec<Vec<T>> {
+    let mut vec: Vec<T> = set.into_iter().collect();
+    vec.sort();
+    split_rand_vec_eq_with_rng(vec, parts, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Like [`split_rand_hashset_eq_seeded`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_hashset_eq_seeded, SplitError};
+/// use std::collections::HashSet;
+/// let set: HashSet<i32> = (1..=7).collect();
+/// assert_eq!(try_split_rand_hashset_eq_seeded(set.clone(), 0, 7), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_hashset_eq_seeded(set.clone(), 3, 7), try_split_rand_hashset_eq_seeded(set, 3, 7));
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_hashset_eq_seeded<T: Ord>(set: HashSet<T>, parts: usize, seed: u64) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
     }
+    Ok(split_rand_hashset_eq_seeded(set, parts, seed))
+}
 
-    #[test]
-    fn test_split_rand_vec_eq() {
-        let vec = vec![1, 2, 3, 4, 5, 6, 7];
-        let chunked = split_rand_vec_eq(vec, 3);
-        assert_eq!(chunked.len(), 3);
-        assert_eq!(chunked[0].len(), 3);
-        assert_eq!(chunked[1].len(), 2);
-        assert_eq!(chunked[2].len(), 2);
+/// Like [`split_rand_vec`], but returns the chunks as [`Parts`].
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_parts;
+/// let chunks = split_rand_vec_parts(vec![1, 2, 3, 4, 5], 2);
+/// assert_eq!(chunks.sizes(), vec![2, 2, 1]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_parts<T>(vec: Vec<T>, chunk_size: usize) -> Parts<T> {
+    split_rand_vec(vec, chunk_size).into()
+}
+
+/// Like [`split_rand_vec_eq`], but returns the parts as [`Parts`].
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_eq_parts;
+/// let parts = split_rand_vec_eq_parts(vec![1, 2, 3, 4, 5], 2);
+/// assert!(parts.is_balanced());
+/// assert_eq!(parts.total_len(), 5);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq_parts<T>(vec: Vec<T>, parts: usize) -> Parts<T> {
+    split_rand_vec_eq(vec, parts).into()
+}
+
+/// Randomizes the order of a vec and splits it into smaller vectors of a specified size, returning an error on invalid arguments.
+///
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `chunk_size` - The size of each chunk in the resulting vector. The last chunk may be smaller if the total number of elements is not divisible by `chunk_size`.
+///
+/// # Returns
+/// The chunks as in [`split_rand_vec`], or [`SplitError::ZeroChunkSize`] if `chunk_size` is 0.
+///
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_vec, SplitError};
+/// assert_eq!(try_split_rand_vec(vec![1, 2, 3], 0), Err(SplitError::ZeroChunkSize));
+/// let chunked = try_split_rand_vec(vec![1, 2, 3], 2).unwrap();
+/// assert_eq!(chunked.len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_vec<T>(vec: Vec<T>, chunk_size: usize) -> Result<Vec<Vec<T>>, SplitError> {
+    if chunk_size == 0 {
+        return Err(SplitError::ZeroChunkSize);
     }
+    Ok(split_rand_vec(vec, chunk_size))
+}
 
-    #[test]
-    fn test_split_hashset_eq() {
-        let vec: HashSet<i32> = [1, 2, 3, 4, 5, 6, 7].iter().cloned().collect();
-        let chunked = split_rand_hashset_eq(vec, 3);
-        assert_eq!(chunked.len(), 3);
-        assert_eq!(chunked[0].len(), 3);
-        assert_eq!(chunked[1].len(), 2);
-        assert_eq!(chunked[2].len(), 2);
+/// Randomizes the order of a vec and splits it into smaller vectors of equal size, returning an error on invalid arguments.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into.
+///
+/// # Returns
+/// The parts as in [`split_rand_vec_eq`], or [`SplitError::ZeroParts`] if `parts` is 0.
+/// An empty vector is split into `parts` empty vectors.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_vec_eq, SplitError};
+/// assert_eq!(try_split_rand_vec_eq(vec![1, 2, 3], 0), Err(SplitError::ZeroParts));
+/// let chunked = try_split_rand_vec_eq(Vec::<i32>::new(), 2).unwrap();
+/// assert_eq!(chunked, vec![Vec::<i32>::new(), Vec::new()]);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_vec_eq<T>(vec: Vec<T>, parts: usize) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_vec_eq(vec, parts))
+}
+
+/// Randomizes the order of a HashSet and splits it into smaller vectors of equal size, returning an error on invalid arguments.
+/// # Arguments
+/// * `set` - The HashSet to be split and randomized.
+/// * `parts` - The number of parts to split the set into.
+///
+/// # Returns
+/// The parts as in [`split_rand_hashset_eq`], or [`SplitError::ZeroParts`] if `parts` is 0.
+/// An empty set is split into `parts` empty vectors.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_hashset_eq, SplitError};
+/// use std::collections::HashSet;
+/// let set: HashSet<i32> = (1..=7).collect();
+/// assert_eq!(try_split_rand_hashset_eq(set.clone(), 0), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_hashset_eq(set, 3).unwrap().len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_hashset_eq<T>(set: HashSet<T>, parts: usize) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_hashset_eq(set, parts))
+}
+
+/// Randomizes the order of a vec and splits it into smaller vectors of equal size, applying a policy for empty parts.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into.
+/// * `policy` - What to do if `parts` is greater than the number of elements. See [`EmptyPartsPolicy`].
+///
+/// # Returns
+/// The parts as in [`split_rand_vec_eq`]. Returns [`SplitError::ZeroParts`] if `parts` is 0, and
+/// [`SplitError::TooManyParts`] if `parts` exceeds the number of elements under [`EmptyPartsPolicy::Error`].
+/// With [`EmptyPartsPolicy::ShrinkToLen`] an empty vector produces no parts at all.
+/// # Example
+/// ```
+/// use rust_helpers::{split_rand_vec_eq_with_policy, EmptyPartsPolicy, SplitError};
+/// let shrunk = split_rand_vec_eq_with_policy(vec![1, 2], 5, EmptyPartsPolicy::ShrinkToLen).unwrap();
+/// assert_eq!(shrunk.len(), 2);
+/// let err = split_rand_vec_eq_with_policy(vec![1, 2], 5, EmptyPartsPolicy::Error);
+/// assert_eq!(err, Err(SplitError::TooManyParts { parts: 5, len: 2 }));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq_with_policy<T>(vec: Vec<T>, parts: usize, policy: EmptyPartsPolicy) -> Result<Vec<Vec<T>>, SplitError> {
+    split_rand_vec_eq_with_policy_with_rng(vec, parts, policy, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_eq_with_policy`], but shuffles with the given RNG.
+pub fn split_rand_vec_eq_with_policy_with_rng<T, R: Rng + ?Sized>(
+    vec: Vec<T>,
+    parts: usize,
+    policy: EmptyPartsPolicy,
+    rng: &mut R,
+) -> Result<Vec<Vec<T>>, SplitError> {
+    match resolve_parts(vec.len(), parts, policy)? {
+        0 => Ok(Vec::new()),
+        parts => Ok(split_rand_vec_eq_with_rng(vec, parts, rng)),
+    }
+}
+
+/// Randomizes the order of a vec and splits it into chunks of a specified size, handling the remainder as specified.
+///
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `chunk_size` - The size of each chunk in the resulting vector.
+/// * `strategy` - How to handle elements that do not fill a whole chunk. See [`RemainderStrategy`].
+///
+/// # Returns
+/// The chunks, or [`SplitError::ZeroChunkSize`] if `chunk_size` is 0 and [`SplitError::Indivisible`] if the
+/// length is not divisible by `chunk_size` under [`RemainderStrategy::Strict`].
+///
+/// # Example
+/// ```
+/// use rust_helpers::{split_rand_vec_with_strategy, RemainderStrategy};
+/// let chunked = split_rand_vec_with_strategy(vec![1, 2, 3, 4, 5, 6, 7], 3, RemainderStrategy::Distribute).unwrap();
+/// assert_eq!(chunked.iter().map(|c| c.len()).collect::<Vec<_>>(), vec![4, 3]);
+/// let dropped = split_rand_vec_with_strategy(vec![1, 2, 3, 4, 5, 6, 7], 3, RemainderStrategy::DropRemainder).unwrap();
+/// assert_eq!(dropped.iter().map(|c| c.len()).collect::<Vec<_>>(), vec![3, 3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_with_strategy<T>(vec: Vec<T>, chunk_size: usize, strategy: RemainderStrategy) -> Result<Vec<Vec<T>>, SplitError> {
+    split_rand_vec_with_strategy_with_rng(vec, chunk_size, strategy, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_with_strategy`], but shuffles with the given RNG.
+pub fn split_rand_vec_with_strategy_with_rng<T, R: Rng + ?Sized>(
+    vec: Vec<T>,
+    chunk_size: usize,
+    strategy: RemainderStrategy,
+    rng: &mut R,
+) -> Result<Vec<Vec<T>>, SplitError> {
+    let sizes = chunk_sizes(vec.len(), chunk_size, strategy)?;
+    Ok(shuffle_and_carve(vec, &sizes, rng))
+}
+
+/// Randomizes the order of a vec and slices it into contiguous parts of equal size.
+///
+/// Unlike [`split_rand_vec_eq`], the elements are not dealt out one by one. The part sizes are computed
+/// up front and the shuffled vector is cut into blocks, moving the elements instead of cloning them.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into. The first `len % parts` parts contain one element more than the others.
+///
+/// # Returns
+/// A vector of `parts` vectors whose sizes differ by at most one.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_vec_eq_contiguous`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_eq_contiguous;
+/// let chunked = split_rand_vec_eq_contiguous(vec![1, 2, 3, 4, 5, 6, 7], 3);
+/// assert_eq!(chunked.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![3, 2, 2]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq_contiguous<T>(vec: Vec<T>, parts: usize) -> Vec<Vec<T>> {
+    split_rand_vec_eq_contiguous_with_rng(vec, parts, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_eq_contiguous`], but shuffles with the given RNG.
+pub fn split_rand_vec_eq_contiguous_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, parts: usize, rng: &mut R) -> Vec<Vec<T>> {
+    let sizes = eq_part_sizes(vec.len(), parts);
+    shuffle_and_carve(vec, &sizes, rng)
+}
+
+/// Like [`split_rand_vec_eq_contiguous`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_vec_eq_contiguous, SplitError};
+/// assert_eq!(try_split_rand_vec_eq_contiguous(vec![1, 2, 3], 0), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_vec_eq_contiguous(vec![1, 2, 3], 2).unwrap()[0].len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_vec_eq_contiguous<T>(vec: Vec<T>, parts: usize) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_vec_eq_contiguous(vec, parts))
+}
+
+/// Randomizes the order of a vec and splits it into parts of equal size, keeping the original index of each element.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into. The first `len % parts` parts contain one element more than the others.
+///
+/// # Returns
+/// A vector of `parts` vectors of `(index, element)` pairs, where `index` is the position of the element in `vec`.
+/// The indices form a permutation of `0..len`.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_eq`] with `vec.into_iter().enumerate()` to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_eq_indexed;
+/// let vec = vec!['a', 'b', 'c', 'd'];
+/// let parts = split_rand_vec_eq_indexed(vec.clone(), 2);
+/// assert!(parts.iter().flatten().all(|&(i, e)| vec[i] == e));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq_indexed<T>(vec: Vec<T>, parts: usize) -> Vec<Vec<(usize, T)>> {
+    split_rand_vec_eq_indexed_with_rng(vec, parts, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_eq_indexed`], but shuffles with the given RNG.
+pub fn split_rand_vec_eq_indexed_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, parts: usize, rng: &mut R) -> Vec<Vec<(usize, T)>> {
+    shuffle_and_deal(vec.into_iter().enumerate().collect(), parts, rng)
+}
+
+/// Like [`split_rand_vec_eq`], but also returns the permutation that was applied.
+/// # Returns
+/// The parts and a [`Permutation`] `p` of `0..len` such that the `i`-th element of the concatenated parts is
+/// `vec[p[i]]`.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_vec_eq_tracked`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_eq_tracked;
+/// let vec = vec!['a', 'b', 'c', 'd', 'e'];
+/// let (parts, permutation) = split_rand_vec_eq_tracked(vec.clone(), 2);
+/// let flat: Vec<char> = parts.concat();
+/// assert_eq!(permutation.apply(&vec), flat);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq_tracked<T>(vec: Vec<T>, parts: usize) -> (Vec<Vec<T>>, Permutation) {
+    split_rand_vec_eq_tracked_with_rng(vec, parts, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_eq_tracked`], but shuffles with the given RNG.
+pub fn split_rand_vec_eq_tracked_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, parts: usize, rng: &mut R) -> (Vec<Vec<T>>, Permutation) {
+    let indexed = split_rand_vec_eq_indexed_with_rng(vec, parts, rng);
+    let permutation = Permutation::from_indices_unchecked(indexed.iter().flatten().map(|&(i, _)| i).collect());
+    let parts = indexed.into_iter().map(|part| part.into_iter().map(|(_, e)| e).collect()).collect();
+    (parts, permutation)
+}
+
+/// Like [`split_rand_vec_eq_tracked`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_vec_eq_tracked, SplitError};
+/// assert_eq!(try_split_rand_vec_eq_tracked(vec![1, 2, 3], 0), Err(SplitError::ZeroParts));
+/// let (parts, permutation) = try_split_rand_vec_eq_tracked(vec![1, 2, 3], 2).unwrap();
+/// assert_eq!((parts.len(), permutation.len()), (2, 3));
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_vec_eq_tracked<T>(vec: Vec<T>, parts: usize) -> Result<(Vec<Vec<T>>, Permutation), SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_vec_eq_tracked(vec, parts))
+}
+
+/// The `(xs, ys)` parts produced by [`split_rand_zip_eq`].
+pub type ZipParts<X, Y> = Vec<(Vec<X>, Vec<Y>)>;
+
+/// Randomizes the order of two parallel vecs and splits them into the same parts of equal size.
+///
+/// Both vecs are reordered by one shared permutation, so `xs[i]` and `ys[i]` always end up at the same position of
+/// the same part.
+/// # Arguments
+/// * `xs` - The first vector, e.g. the features of a dataset.
+/// * `ys` - The second vector, e.g. the labels. Must be as long as `xs`.
+/// * `parts` - The number of parts. The first `len % parts` parts contain one element more than the others.
+///
+/// # Returns
+/// One `(xs, ys)` pair per part. Returns [`SplitError::ZeroParts`] if `parts` is 0 and
+/// [`SplitError::LengthMismatch`] if the vecs differ in length.
+/// # Example
+/// ```
+/// use rust_helpers::{split_rand_zip_eq, SplitError};
+/// let features = vec![[0.1, 0.2], [0.3, 0.4], [0.5, 0.6], [0.7, 0.8]];
+/// let labels = vec![0, 1, 2, 3];
+/// let parts = split_rand_zip_eq(features.clone(), labels.clone(), 2).unwrap();
+/// for (xs, ys) in &parts {
+///     assert!(xs.iter().zip(ys).all(|(x, &y)| *x == features[y]));
+/// }
+/// assert_eq!(split_rand_zip_eq(features, vec![0], 2), Err(SplitError::LengthMismatch { left: 4, right: 1 }));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_zip_eq<X, Y>(xs: Vec<X>, ys: Vec<Y>, parts: usize) -> Result<ZipParts<X, Y>, SplitError> {
+    split_rand_zip_eq_with_rng(xs, ys, parts, &mut rand::rng())
+}
+
+/// Like [`split_rand_zip_eq`], but shuffles with the given RNG.
+pub fn split_rand_zip_eq_with_rng<X, Y, R: Rng + ?Sized>(xs: Vec<X>, ys: Vec<Y>, parts: usize, rng: &mut R) -> Result<ZipParts<X, Y>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    if xs.len() != ys.len() {
+        return Err(SplitError::LengthMismatch { left: xs.len(), right: ys.len() });
+    }
+    let zipped: Vec<(X, Y)> = xs.into_iter().zip(ys).collect();
+    Ok(split_rand_vec_eq_with_rng(zipped, parts, rng).into_iter().map(|part| part.into_iter().unzip()).collect())
+}
+
+/// Randomizes the order of a vec and splits it into named parts of equal size.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `names` - The names of the parts, in the order of the returned parts. The first `len % names.len()` parts
+///   contain one element more than the others.
+///
+/// # Returns
+/// A vector of `(name, part)` pairs in the order of `names`, or an error if `names` is empty
+/// ([`SplitError::ZeroParts`]) or contains a name twice ([`SplitError::DuplicateName`]).
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_named;
+/// let roster = vec!["Ann", "Ben", "Cem", "Dana", "Eli", "Finn", "Gus"];
+/// let teams = split_rand_named(roster, &["Team Red", "Team Blue", "Team Green"]).unwrap();
+/// assert_eq!(teams[0].0, "Team Red");
+/// assert_eq!(teams.iter().map(|(_, team)| team.len()).collect::<Vec<_>>(), vec![3, 2, 2]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_named<T>(vec: Vec<T>, names: &[&str]) -> Result<Vec<(String, Vec<T>)>, SplitError> {
+    split_rand_named_with_rng(vec, names, &mut rand::rng())
+}
+
+/// Like [`split_rand_named`], but shuffles with the given RNG.
+#[cfg(feature = "std")]
+pub fn split_rand_named_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, names: &[&str], rng: &mut R) -> Result<Vec<(String, Vec<T>)>, SplitError> {
+    if names.is_empty() {
+        return Err(SplitError::ZeroParts);
+    }
+    let mut seen = HashSet::with_capacity(names.len());
+    if let Some(name) = names.iter().find(|name| !seen.insert(**name)) {
+        return Err(SplitError::DuplicateName(name.to_string()));
+    }
+    let parts = shuffle_and_deal(vec, names.len(), rng);
+    Ok(names.iter().map(|name| name.to_string()).zip(parts).collect())
+}
+
+/// How many random splits [`split_rand_vec_eq_multi`] tries per requested grouping before giving up.
+#[cfg(feature = "std")]
+const MULTI_ATTEMPTS_PER_GROUPING: usize = 50;
+
+/// Produces several pairwise distinct random splits of the same vec into parts of equal size.
+///
+/// Two groupings count as equal if they contain the same parts, regardless of the order of the parts or of the
+/// elements within them. Random splits are drawn until `n` distinct ones are found or a bounded number of attempts
+/// is used up, so small inputs that admit fewer than `n` distinct groupings return fewer.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts per grouping. The first `len % parts` parts contain one element more than the others.
+/// * `n` - The number of distinct groupings to produce.
+///
+/// # Returns
+/// A [`DistinctGroupings`] holding up to `n` groupings; [`DistinctGroupings::is_complete`] tells whether all `n`
+/// were found.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_vec_eq_multi`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_eq_multi;
+/// let result = split_rand_vec_eq_multi(vec!["Ann", "Ben", "Cem", "Dana"], 2, 3);
+/// assert!(result.is_complete());
+/// assert_eq!(result.groupings.len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq_multi<T: Hash + Eq + Clone>(vec: Vec<T>, parts: usize, n: usize) -> DistinctGroupings<T> {
+    split_rand_vec_eq_multi_with_rng(vec, parts, n, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_eq_multi`], but shuffles with the given RNG.
+#[cfg(feature = "std")]
+pub fn split_rand_vec_eq_multi_with_rng<T: Hash + Eq + Clone, R: Rng + ?Sized>(vec: Vec<T>, parts: usize, n: usize, rng: &mut R) -> DistinctGroupings<T> {
+    let mut ids: HashMap<&T, usize> = HashMap::with_capacity(vec.len());
+    for e in &vec {
+        let next = ids.len();
+        ids.entry(e).or_insert(next);
+    }
+    let mut seen: HashSet<Vec<Vec<usize>>> = HashSet::with_capacity(n);
+    let mut groupings = Vec::with_capacity(n);
+    for _ in 0..n.saturating_mul(MULTI_ATTEMPTS_PER_GROUPING) {
+        if groupings.len() == n {
+            break;
+        }
+        let grouping = shuffle_and_deal((0..vec.len()).collect(), parts, rng);
+        let mut canonical: Vec<Vec<usize>> = grouping
+            .iter()
+            .map(|part| {
+                let mut part: Vec<usize> = part.iter().map(|&i| ids[&vec[i]]).collect();
+                part.sort_unstable();
+                part
+            })
+            .collect();
+        canonical.sort_unstable();
+        if seen.insert(canonical) {
+            groupings.push(grouping.into_iter().map(|part| part.into_iter().map(|i| vec[i].clone()).collect()).collect());
+        }
+    }
+    DistinctGroupings { groupings, requested: n }
+}
+
+/// Like [`split_rand_vec_eq_multi`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_vec_eq_multi, SplitError};
+/// assert_eq!(try_split_rand_vec_eq_multi(vec![1, 2, 3], 0, 2), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_vec_eq_multi(vec![1, 2, 3], 3, 1).unwrap().groupings.len(), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_vec_eq_multi<T: Hash + Eq + Clone>(vec: Vec<T>, parts: usize, n: usize) -> Result<DistinctGroupings<T>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_vec_eq_multi(vec, parts, n))
+}
+
+/// Distinct groupings of the same input, returned by [`split_rand_vec_eq_multi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistinctGroupings<T> {
+    /// The distinct groupings found, each a vector of parts.
+    pub groupings: Vec<Vec<Vec<T>>>,
+    /// The number of groupings that was requested.
+    pub requested: usize,
+}
+
+impl<T> DistinctGroupings<T> {
+    /// Returns `true` if as many distinct groupings were found as were requested.
+    pub fn is_complete(&self) -> bool {
+        self.groupings.len() == self.requested
+    }
+}
+
+/// Randomizes the order of a vec and splits it into a fixed number of parts known at compile time.
+///
+/// The first `len % N` parts contain one element more than the others. Requesting zero parts is a compile-time error.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+///
+/// # Returns
+/// An array of `N` vectors, which can be destructured directly.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_array;
+/// let players = vec!["Ann", "Ben", "Cem", "Dana", "Eli", "Finn"];
+/// let [red, blue, green] = split_rand_array(players);
+/// assert_eq!((red.len(), blue.len(), green.len()), (2, 2, 2));
+/// ```
+///
+/// ```compile_fail
+/// let [] = rust_helpers::split_rand_array::<i32, 0>(vec![1, 2, 3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_array<T, const N: usize>(vec: Vec<T>) -> [Vec<T>; N] {
+    split_rand_array_with_rng(vec, &mut rand::rng())
+}
+
+/// Like [`split_rand_array`], but shuffles with the given RNG.
+pub fn split_rand_array_with_rng<T, R: Rng + ?Sized, const N: usize>(vec: Vec<T>, rng: &mut R) -> [Vec<T>; N] {
+    const { assert!(N > 0, "number of parts must be greater than zero") };
+    match shuffle_and_deal(vec, N, rng).try_into() {
+        Ok(parts) => parts,
+        Err(_) => unreachable!("shuffle_and_deal returns exactly N parts"),
+    }
+}
+
+/// Randomizes the order of a vec and splits it into fixed-size arrays plus a remainder.
+///
+/// The chunk length is part of the type, so the batches can be indexed without bounds checks.
+/// A chunk length of zero is a compile-time error.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+///
+/// # Returns
+/// A tuple with the full chunks of `N` elements and a vector of the `len % N` leftover elements.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_exact_chunks;
+/// let (batches, rest) = split_rand_exact_chunks::<_, 3>((0..8).collect::<Vec<i32>>());
+/// assert_eq!(batches.len(), 2);
+/// assert_eq!(rest.len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_exact_chunks<T, const N: usize>(vec: Vec<T>) -> (Vec<[T; N]>, Vec<T>) {
+    split_rand_exact_chunks_with_rng(vec, &mut rand::rng())
+}
+
+/// Like [`split_rand_exact_chunks`], but shuffles with the given RNG.
+pub fn split_rand_exact_chunks_with_rng<T, R: Rng + ?Sized, const N: usize>(vec: Vec<T>, rng: &mut R) -> (Vec<[T; N]>, Vec<T>) {
+    const { assert!(N > 0, "chunk size must be greater than zero") };
+    let mut vec: Vec<T> = vec;
+    vec.shuffle(rng);
+    let remainder = vec.split_off(vec.len() / N * N);
+    let mut elements = vec.into_iter();
+    let chunks = (0..elements.len() / N)
+        .map(|_| core::array::from_fn(|_| elements.next().expect("length is a multiple of N")))
+        .collect();
+    (chunks, remainder)
+}
+
+/// Randomizes the order of a vec and splits it into parts according to the given ratios.
+///
+/// The ratios are normalized, so `&[0.7, 0.2, 0.1]` and `&[7.0, 2.0, 1.0]` are equivalent. Part sizes are
+/// rounded with the largest remainder method, so every element ends up in exactly one part.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `ratios` - The relative size of each part. Ratios must be finite and non-negative.
+///
+/// # Returns
+/// One part per ratio, or an error if `ratios` is empty, contains an invalid value or sums to zero.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_by_ratios;
+/// let parts = split_rand_by_ratios((0..10).collect::<Vec<_>>(), &[0.7, 0.2, 0.1]).unwrap();
+/// assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![7, 2, 1]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_by_ratios<T>(vec: Vec<T>, ratios: &[f64]) -> Result<Vec<Vec<T>>, SplitError> {
+    split_rand_by_ratios_with_rng(vec, ratios, &mut rand::rng())
+}
+
+/// Like [`split_rand_by_ratios`], but shuffles with the given RNG.
+pub fn split_rand_by_ratios_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, ratios: &[f64], rng: &mut R) -> Result<Vec<Vec<T>>, SplitError> {
+    let sizes = ratio_sizes(vec.len(), ratios)?;
+    Ok(shuffle_and_carve(vec, &sizes, rng))
+}
+
+/// Like [`split_rand_by_ratios`], but shuffles with an RNG seeded from `seed`, so the result is reproducible.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_by_ratios_seeded;
+/// let vec: Vec<i32> = (0..20).collect();
+/// let first = split_rand_by_ratios_seeded(vec.clone(), &[0.8, 0.2], 3).unwrap();
+/// assert_eq!(first, split_rand_by_ratios_seeded(vec, &[0.8, 0.2], 3).unwrap());
+/// ```
+pub fn split_rand_by_ratios_seeded<T>(vec: Vec<T>, ratios: &[f64], seed: u64) -> Result<Vec<Vec<T>>, SplitError> {
+    split_rand_by_ratios_with_rng(vec, ratios, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Randomizes the order of a vec and splits it into parts of exactly the requested sizes.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `sizes` - The size of each part. A size of 0 produces an empty part.
+///
+/// # Returns
+/// One part per entry in `sizes`, or [`SplitError::SizesMismatch`] if the sizes don't add up to the length of `vec`.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_sizes;
+/// let parts = split_rand_sizes((0..10).collect::<Vec<_>>(), &[4, 3, 3]).unwrap();
+/// assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![4, 3, 3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_sizes<T>(vec: Vec<T>, sizes: &[usize]) -> Result<Vec<Vec<T>>, SplitError> {
+    split_rand_sizes_with_rng(vec, sizes, &mut rand::rng())
+}
+
+/// Like [`split_rand_sizes`], but shuffles with the given RNG.
+pub fn split_rand_sizes_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, sizes: &[usize], rng: &mut R) -> Result<Vec<Vec<T>>, SplitError> {
+    let total = total_size(sizes);
+    if total != vec.len() {
+        return Err(SplitError::SizesMismatch { expected: vec.len(), got: total });
+    }
+    Ok(shuffle_and_carve(vec, sizes, rng))
+}
+
+/// Randomizes the order of a vec and splits it into parts of the requested sizes, returning the leftovers as an extra part.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `sizes` - The size of each part. The sizes may add up to less than the length of `vec`.
+///
+/// # Returns
+/// One part per entry in `sizes` followed by a part with the remaining elements, which may be empty.
+/// Returns [`SplitError::SizesMismatch`] if the sizes add up to more than the length of `vec`.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_sizes_lenient;
+/// let parts = split_rand_sizes_lenient((0..10).collect::<Vec<_>>(), &[4, 3]).unwrap();
+/// assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![4, 3, 3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_sizes_lenient<T>(vec: Vec<T>, sizes: &[usize]) -> Result<Vec<Vec<T>>, SplitError> {
+    split_rand_sizes_lenient_with_rng(vec, sizes, &mut rand::rng())
+}
+
+/// Like [`split_rand_sizes_lenient`], but shuffles with the given RNG.
+pub fn split_rand_sizes_lenient_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, sizes: &[usize], rng: &mut R) -> Result<Vec<Vec<T>>, SplitError> {
+    let total = total_size(sizes);
+    if total > vec.len() {
+        return Err(SplitError::SizesMismatch { expected: vec.len(), got: total });
+    }
+    let mut sizes = sizes.to_vec();
+    sizes.push(vec.len() - total);
+    Ok(shuffle_and_carve(vec, &sizes, rng))
+}
+
+/// The `(train, test)` portions returned by [`train_test_split`] and [`k_fold`].
+pub type TrainTest<T> = (Vec<T>, Vec<T>);
+
+/// Randomizes the order of a vec and splits it into a train and a test portion.
+///
+/// The test portion receives `round(len * test_fraction)` elements and the train portion the rest.
+/// The test size is clamped so that neither portion is empty.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized. It must contain at least two elements.
+/// * `test_fraction` - The fraction of elements in the test portion, strictly between 0.0 and 1.0.
+///
+/// # Returns
+/// A tuple `(train, test)`. Returns [`SplitError::InvalidFraction`] for a fraction outside of (0.0, 1.0) and
+/// [`SplitError::TooFewElements`] if `vec` has fewer than two elements.
+/// # Example
+/// ```
+/// use rust_helpers::train_test_split;
+/// let (train, test) = train_test_split((0..10).collect::<Vec<_>>(), 0.2).unwrap();
+/// assert_eq!(train.len(), 8);
+/// assert_eq!(test.len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn train_test_split<T>(vec: Vec<T>, test_fraction: f64) -> Result<TrainTest<T>, SplitError> {
+    train_test_split_with_rng(vec, test_fraction, &mut rand::rng())
+}
+
+/// Like [`train_test_split`], but shuffles with the given RNG.
+pub fn train_test_split_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, test_fraction: f64, rng: &mut R) -> Result<TrainTest<T>, SplitError> {
+    if !(test_fraction > 0.0 && test_fraction < 1.0) {
+        return Err(SplitError::InvalidFraction);
+    }
+    let len = vec.len();
+    if len < 2 {
+        return Err(SplitError::TooFewElements { required: 2, len });
+    }
+    let test_len = round_to_usize(len as f64 * test_fraction).clamp(1, len - 1);
+    let mut parts = shuffle_and_carve(vec, &[len - test_len, test_len], rng).into_iter();
+    match (parts.next(), parts.next()) {
+        (Some(train), Some(test)) => Ok((train, test)),
+        _ => unreachable!("two sizes produce two parts"),
+    }
+}
+
+/// Like [`train_test_split`], but shuffles with an RNG seeded from `seed`, so the result is reproducible.
+/// # Example
+/// ```
+/// use rust_helpers::train_test_split_seeded;
+/// let data: Vec<i32> = (0..100).collect();
+/// assert_eq!(train_test_split_seeded(data.clone(), 0.25, 1), train_test_split_seeded(data, 0.25, 1));
+/// ```
+pub fn train_test_split_seeded<T>(vec: Vec<T>, test_fraction: f64, seed: u64) -> Result<TrainTest<T>, SplitError> {
+    train_test_split_with_rng(vec, test_fraction, &mut StdRng::seed_from_u64(seed))
+}
+
+/// The `(train, val, test)` portions returned by [`train_val_test_split`].
+pub type TrainValTest<T> = (Vec<T>, Vec<T>, Vec<T>);
+
+/// Randomizes the order of a vec and splits it into a train, a validation and a test portion.
+///
+/// The validation and test portions receive `round(len * fraction)` elements each, but at least one. If that leaves
+/// no element for the train portion, the excess is taken from the larger of the two, so their sizes stay close to
+/// their fractions. The train portion receives the rest.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized. It must contain at least three elements.
+/// * `val_fraction` - The fraction of elements in the validation portion.
+/// * `test_fraction` - The fraction of elements in the test portion.
+///
+/// # Returns
+/// A tuple `(train, val, test)`. Returns [`SplitError::InvalidFraction`] unless both fractions are greater than
+/// 0.0 and add up to less than 1.0, and [`SplitError::TooFewElements`] if `vec` has fewer than three elements.
+/// # Example
+/// ```
+/// use rust_helpers::train_val_test_split;
+/// # fn main() -> Result<(), rust_helpers::SplitError> {
+/// let data: Vec<i32> = (0..100).collect();
+/// let (train, val, test) = train_val_test_split(data, 0.15, 0.15)?;
+/// assert_eq!((train.len(), val.len(), test.len()), (70, 15, 15));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn train_val_test_split<T>(vec: Vec<T>, val_fraction: f64, test_fraction: f64) -> Result<TrainValTest<T>, SplitError> {
+    train_val_test_split_with_rng(vec, val_fraction, test_fraction, &mut rand::rng())
+}
+
+/// Like [`train_val_test_split`], but shuffles with the given RNG.
+pub fn train_val_test_split_with_rng<T, R: Rng + ?Sized>(
+    vec: Vec<T>,
+    val_fraction: f64,
+    test_fraction: f64,
+    rng: &mut R,
+) -> Result<TrainValTest<T>, SplitError> {
+    if !(val_fraction > 0.0 && test_fraction > 0.0 && val_fraction + test_fraction < 1.0) {
+        return Err(SplitError::InvalidFraction);
+    }
+    let len = vec.len();
+    if len < 3 {
+        return Err(SplitError::TooFewElements { required: 3, len });
+    }
+    let exact_val = len as f64 * val_fraction;
+    let exact_test = len as f64 * test_fraction;
+    let mut val_len = round_to_usize(exact_val).max(1);
+    let mut test_len = round_to_usize(exact_test).max(1);
+    while val_len + test_len > len - 1 {
+        // On a tie, shrink the portion that was rounded up more.
+        if val_len > test_len || (val_len == test_len && exact_val < exact_test) {
+            val_len -= 1;
+        } else {
+            test_len -= 1;
+        }
+    }
+    let mut parts = shuffle_and_carve(vec, &[len - val_len - test_len, val_len, test_len], rng).into_iter();
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(train), Some(val), Some(test)) => Ok((train, val, test)),
+        _ => unreachable!("three sizes produce three parts"),
+    }
+}
+
+/// Like [`train_val_test_split`], but shuffles with an RNG seeded from `seed`, so the result is reproducible.
+/// # Example
+/// ```
+/// use rust_helpers::train_val_test_split_seeded;
+/// let data: Vec<i32> = (0..100).collect();
+/// assert_eq!(
+///     train_val_test_split_seeded(data.clone(), 0.1, 0.2, 5),
+///     train_val_test_split_seeded(data, 0.1, 0.2, 5)
+/// );
+/// ```
+pub fn train_val_test_split_seeded<T>(vec: Vec<T>, val_fraction: f64, test_fraction: f64, seed: u64) -> Result<TrainValTest<T>, SplitError> {
+    train_val_test_split_with_rng(vec, val_fraction, test_fraction, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Randomizes the order of a vec once and produces `k` folds for cross-validation.
+///
+/// The shuffled vector is cut into `k` slices whose sizes differ by at most one. Fold `i` uses the `i`-th slice
+/// as test data and all other slices as train data, so every element is test data in exactly one fold.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized. It must implement the `Clone` trait, because each element appears in several folds.
+/// * `k` - The number of folds.
+///
+/// # Returns
+/// A vector of `k` `(train, test)` pairs. Returns [`SplitError::ZeroParts`] if `k` is 0 and
+/// [`SplitError::TooManyParts`] if `k` is greater than the number of elements.
+/// # Example
+/// ```
+/// use rust_helpers::k_fold;
+/// let folds = k_fold((0..10).collect::<Vec<_>>(), 3).unwrap();
+/// assert_eq!(folds.len(), 3);
+/// for (train, test) in &folds {
+///     assert_eq!(train.len() + test.len(), 10);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn k_fold<T: Clone>(vec: Vec<T>, k: usize) -> Result<Vec<TrainTest<T>>, SplitError> {
+    k_fold_with_rng(vec, k, &mut rand::rng())
+}
+
+/// Like [`k_fold`], but shuffles with the given RNG.
+pub fn k_fold_with_rng<T: Clone, R: Rng + ?Sized>(vec: Vec<T>, k: usize, rng: &mut R) -> Result<Vec<TrainTest<T>>, SplitError> {
+    let k = resolve_parts(vec.len(), k, EmptyPartsPolicy::Error)?;
+    let sizes = eq_part_sizes(vec.len(), k);
+    let slices = shuffle_and_carve(vec, &sizes, rng);
+    let folds = (0..k)
+        .map(|i| {
+            let train = slices.iter().enumerate().filter(|(j, _)| *j != i).flat_map(|(_, slice)| slice.iter().cloned()).collect();
+            (train, slices[i].clone())
+        })
+        .collect();
+    Ok(folds)
+}
+
+/// Randomizes the order of a vec and returns an iterator of leave-one-out splits.
+///
+/// The vector is shuffled once up front. Each call to `next` clones the elements of one pair, so the
+/// pairs are produced lazily instead of cloning the whole vector `len` times at once.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized. It must implement the `Clone` trait.
+///
+/// # Returns
+/// An iterator yielding one `(rest, single_element)` pair per element, where `rest` contains all other elements
+/// in randomized order. An empty vector yields no pairs.
+/// # Example
+/// ```
+/// use rust_helpers::leave_one_out;
+/// let pairs: Vec<(Vec<i32>, i32)> = leave_one_out(vec![1, 2, 3]).collect();
+/// assert_eq!(pairs.len(), 3);
+/// assert!(pairs.iter().all(|(rest, single)| rest.len() == 2 && !rest.contains(single)));
+/// ```
+#[cfg(feature = "std")]
+pub fn leave_one_out<T: Clone>(vec: Vec<T>) -> LeaveOneOut<T> {
+    leave_one_out_with_rng(vec, &mut rand::rng())
+}
+
+/// Like [`leave_one_out`], but shuffles with the given RNG.
+pub fn leave_one_out_with_rng<T: Clone, R: Rng + ?Sized>(vec: Vec<T>, rng: &mut R) -> LeaveOneOut<T> {
+    let mut items: Vec<T> = vec;
+    items.shuffle(rng);
+    LeaveOneOut { items, next: 0 }
+}
+
+/// Iterator over leave-one-out splits, returned by [`leave_one_out`].
+#[derive(Debug, Clone)]
+pub struct LeaveOneOut<T> {
+    items: Vec<T>,
+    next: usize,
+}
+
+impl<T: Clone> Iterator for LeaveOneOut<T> {
+    type Item = (Vec<T>, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let single = self.items.get(self.next)?.clone();
+        let mut rest = Vec::with_capacity(self.items.len() - 1);
+        rest.extend_from_slice(&self.items[..self.next]);
+        rest.extend_from_slice(&self.items[self.next + 1..]);
+        self.next += 1;
+        Some((rest, single))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.items.len() - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for LeaveOneOut<T> {}
+
+/// Randomizes the order of a vec and returns an iterator yielding chunks of a specified size lazily.
+///
+/// The vector is shuffled once up front. The chunks are only built when requested, moving the elements out,
+/// so the chunks need not be held in memory all at once.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `chunk_size` - The size of each chunk. The last chunk may be smaller, as in [`split_rand_vec`].
+///
+/// # Returns
+/// An [`ExactSizeIterator`] over the chunks.
+/// # Panics
+/// Panics if `chunk_size` is 0. Use [`try_split_rand_vec`] to get an error instead, which collects all chunks at once.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_iter;
+/// let mut chunks = split_rand_vec_iter((0..8).collect::<Vec<i32>>(), 3);
+/// assert_eq!(chunks.len(), 3);
+/// assert_eq!(chunks.next().map(|c| c.len()), Some(3));
+/// assert_eq!(chunks.map(|c| c.len()).collect::<Vec<_>>(), vec![3, 2]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_vec_iter<T>(vec: Vec<T>, chunk_size: usize) -> RandChunks<T> {
+    split_rand_vec_iter_with_rng(vec, chunk_size, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_iter`], but shuffles with the given RNG.
+pub fn split_rand_vec_iter_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, chunk_size: usize, rng: &mut R) -> RandChunks<T> {
+    assert!(chunk_size > 0, "chunk size must be greater than zero");
+    let mut vec: Vec<T> = vec;
+    vec.shuffle(rng);
+    RandChunks { elements: vec.into_iter(), chunk_size }
+}
+
+/// Iterator over randomized chunks, returned by [`split_rand_vec_iter`].
+#[derive(Debug, Clone)]
+pub struct RandChunks<T> {
+    elements: alloc::vec::IntoIter<T>,
+    chunk_size: usize,
+}
+
+impl<T> Iterator for RandChunks<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.len() == 0 {
+            return None;
+        }
+        Some(self.elements.by_ref().take(self.chunk_size).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.elements.len().div_ceil(self.chunk_size);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for RandChunks<T> {}
+
+/// Creates bootstrap samples by drawing elements with replacement into parts of a fixed size.
+///
+/// The same element can appear in several parts or several times within one part.
+/// # Arguments
+/// * `items` - The elements to draw from. They must implement the `Clone` trait.
+/// * `parts` - The number of parts to create.
+/// * `part_size` - The number of elements in each part.
+///
+/// # Returns
+/// `parts` vectors of exactly `part_size` elements. Returns [`SplitError::ZeroParts`] if `parts` is 0 and
+/// [`SplitError::EmptyInput`] if `items` is empty but `part_size` is greater than 0.
+/// # Example
+/// ```
+/// use rust_helpers::bootstrap_parts;
+/// let samples = bootstrap_parts(&[1, 2, 3], 4, 5).unwrap();
+/// assert_eq!(samples.len(), 4);
+/// assert!(samples.iter().all(|s| s.len() == 5));
+/// ```
+#[cfg(feature = "std")]
+pub fn bootstrap_parts<T: Clone>(items: &[T], parts: usize, part_size: usize) -> Result<Vec<Vec<T>>, SplitError> {
+    bootstrap_parts_with_rng(items, parts, part_size, &mut rand::rng())
+}
+
+/// Like [`bootstrap_parts`], but draws with the given RNG.
+pub fn bootstrap_parts_with_rng<T: Clone, R: Rng + ?Sized>(items: &[T], parts: usize, part_size: usize, rng: &mut R) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    if items.is_empty() && part_size > 0 {
+        return Err(SplitError::EmptyInput);
+    }
+    let samples = (0..parts)
+        .map(|_| (0..part_size).map(|_| items[rng.random_range(0..items.len())].clone()).collect())
+        .collect();
+    Ok(samples)
+}
+
+/// Like [`bootstrap_parts`], but draws with an RNG seeded from `seed`, so the result is reproducible.
+/// # Example
+/// ```
+/// use rust_helpers::bootstrap_parts_seeded;
+/// assert_eq!(bootstrap_parts_seeded(&[1, 2, 3], 2, 3, 8), bootstrap_parts_seeded(&[1, 2, 3], 2, 3, 8));
+/// ```
+pub fn bootstrap_parts_seeded<T: Clone>(items: &[T], parts: usize, part_size: usize, seed: u64) -> Result<Vec<Vec<T>>, SplitError> {
+    bootstrap_parts_with_rng(items, parts, part_size, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Splits a vec into parts of equal size while preserving the proportion of each key in every part.
+///
+/// The elements are grouped by `key_fn` and shuffled within each group. The groups are then dealt out
+/// round-robin one after another, so the elements of each key are spread over as many parts as possible.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into. The first `len % parts` parts contain one element more than the others.
+/// * `key_fn` - A function extracting the key to stratify by.
+///
+/// # Returns
+/// A vector of `parts` vectors. The number of elements with the same key differs by at most one between parts.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_stratified`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_stratified;
+/// let rows = vec![("a", 1), ("a", 2), ("a", 3), ("b", 4), ("b", 5), ("b", 6)];
+/// let parts = split_rand_stratified(rows, 3, |row| row.0);
+/// assert!(parts.iter().all(|p| p.iter().filter(|row| row.0 == "a").count() == 1));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_stratified<T, K, F>(vec: Vec<T>, parts: usize, key_fn: F) -> Vec<Vec<T>>
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    split_rand_stratified_with_rng(vec, parts, key_fn, &mut rand::rng())
+}
+
+/// Like [`split_rand_stratified`], but shuffles with the given RNG.
+#[cfg(feature = "std")]
+pub fn split_rand_stratified_with_rng<T, K, F, R>(vec: Vec<T>, parts: usize, key_fn: F, rng: &mut R) -> Vec<Vec<T>>
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+    R: Rng + ?Sized,
+{
+    let sizes = eq_part_sizes(vec.len(), parts);
+    let (_, mut groups) = group_by_key(vec, key_fn);
+    groups.shuffle(rng);
+    let mut result: Vec<Vec<T>> = sizes.into_iter().map(Vec::with_capacity).collect();
+    let mut i = 0;
+    for mut group in groups {
+        group.shuffle(rng);
+        for e in group {
+            result[i % parts].push(e);
+            i += 1;
+        }
+    }
+    result
+}
+
+/// Like [`split_rand_stratified`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_stratified, SplitError};
+/// let rows = vec![("a", 1), ("b", 2)];
+/// assert_eq!(try_split_rand_stratified(rows.clone(), 0, |row| row.0), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_stratified(rows, 2, |row| row.0).unwrap().len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_stratified<T, K, F>(vec: Vec<T>, parts: usize, key_fn: F) -> Result<Vec<Vec<T>>, SplitError>
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_stratified(vec, parts, key_fn))
+}
+
+/// Splits a vec into parts of equal size with every key spread evenly over the parts, and counts the keys per part.
+///
+/// The split is the same as [`split_rand_stratified`]: the number of elements with any given key differs by at most
+/// one between parts, even for keys with fewer elements than there are parts. The returned tallies make it easy to
+/// verify or display that balance.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into. The first `len % parts` parts contain one element more than the others.
+/// * `key_fn` - A function extracting the category to balance.
+///
+/// # Returns
+/// A tuple with the `parts` vectors and, for every part, a map from each key to its number of elements in that part.
+/// Keys that do not occur in a part are missing from its map.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_balanced_by`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_balanced_by;
+/// let people = vec![("Ann", "beginner"), ("Ben", "expert"), ("Cem", "beginner"), ("Dana", "expert")];
+/// let (groups, tallies) = split_rand_balanced_by(people, 2, |person| person.1);
+/// assert_eq!(groups.len(), 2);
+/// assert!(tallies.iter().all(|tally| tally["beginner"] == 1 && tally["expert"] == 1));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_balanced_by<T, K, F>(vec: Vec<T>, parts: usize, key_fn: F) -> (Vec<Vec<T>>, Vec<HashMap<K, usize>>)
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    split_rand_balanced_by_with_rng(vec, parts, key_fn, &mut rand::rng())
+}
+
+/// Like [`split_rand_balanced_by`], but shuffles with the given RNG.
+#[cfg(feature = "std")]
+pub fn split_rand_balanced_by_with_rng<T, K, F, R>(vec: Vec<T>, parts: usize, key_fn: F, rng: &mut R) -> (Vec<Vec<T>>, Vec<HashMap<K, usize>>)
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+    R: Rng + ?Sized,
+{
+    let result = split_rand_stratified_with_rng(vec, parts, &key_fn, rng);
+    let tallies = result
+        .iter()
+        .map(|part| {
+            let mut tally = HashMap::new();
+            for e in part {
+                *tally.entry(key_fn(e)).or_insert(0) += 1;
+            }
+            tally
+        })
+        .collect();
+    (result, tallies)
+}
+
+/// The parts and per-part key counts produced by [`split_rand_balanced_by`].
+#[cfg(feature = "std")]
+pub type BalancedParts<T, K> = (Vec<Vec<T>>, Vec<HashMap<K, usize>>);
+
+/// Like [`split_rand_balanced_by`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_balanced_by, SplitError};
+/// let rows = vec![("a", 1), ("b", 2)];
+/// assert_eq!(try_split_rand_balanced_by(rows.clone(), 0, |row| row.0), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_balanced_by(rows, 2, |row| row.0).unwrap().1.len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_balanced_by<T, K, F>(vec: Vec<T>, parts: usize, key_fn: F) -> Result<BalancedParts<T, K>, SplitError>
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_balanced_by(vec, parts, key_fn))
+}
+
+/// Splits a vec into parts of equal size whose total weights are as even as possible.
+///
+/// The elements are shuffled and sorted by descending weight, then assigned in rounds of `parts` elements: in each
+/// round the heaviest element goes to the currently lightest part, the next one to the second lightest and so on.
+/// Ties between equally heavy parts are broken randomly, so repeated calls give different but equally balanced
+/// groupings. Every part gets one element per round, so the part sizes differ by at most one.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into.
+/// * `weight_fn` - A function returning the weight of an element. Weights are compared with [`f64::total_cmp`].
+///
+/// # Returns
+/// A tuple with the `parts` vectors and the sum of the weights in each part.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_weight_balanced`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_weight_balanced;
+/// let players = vec![("Ann", 9.0), ("Ben", 7.0), ("Cem", 5.0), ("Dana", 4.0), ("Eli", 3.0), ("Finn", 2.0)];
+/// let (teams, totals) = split_rand_weight_balanced(players, 2, |player| player.1);
+/// assert_eq!(teams[0].len(), 3);
+/// assert_eq!(totals.iter().sum::<f64>(), 30.0);
+/// assert!((totals[0] - totals[1]).abs() <= 4.0);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_weight_balanced<T, F>(vec: Vec<T>, parts: usize, weight_fn: F) -> (Vec<Vec<T>>, Vec<f64>)
+where
+    F: Fn(&T) -> f64,
+{
+    split_rand_weight_balanced_with_rng(vec, parts, weight_fn, &mut rand::rng())
+}
+
+/// Like [`split_rand_weight_balanced`], but shuffles with the given RNG.
+pub fn split_rand_weight_balanced_with_rng<T, F, R>(vec: Vec<T>, parts: usize, weight_fn: F, rng: &mut R) -> (Vec<Vec<T>>, Vec<f64>)
+where
+    F: Fn(&T) -> f64,
+    R: Rng + ?Sized,
+{
+    let sizes = eq_part_sizes(vec.len(), parts);
+    let mut weighted: Vec<(f64, T)> = vec.into_iter().map(|e| (weight_fn(&e), e)).collect();
+    weighted.shuffle(rng);
+    weighted.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut result: Vec<Vec<T>> = sizes.into_iter().map(Vec::with_capacity).collect();
+    let mut totals = vec![0.0_f64; parts];
+    let mut order: Vec<usize> = (0..parts).collect();
+    let mut elements = weighted.into_iter().peekable();
+    while elements.peek().is_some() {
+        order.shuffle(rng);
+        order.sort_by(|&a, &b| totals[a].total_cmp(&totals[b]));
+        for (&part, (weight, e)) in order.iter().zip(elements.by_ref()) {
+            totals[part] += weight;
+            result[part].push(e);
+        }
+    }
+    (result, totals)
+}
+
+/// Like [`split_rand_weight_balanced`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_weight_balanced, SplitError};
+/// assert_eq!(try_split_rand_weight_balanced(vec![1.0, 2.0], 0, |w| *w), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_weight_balanced(vec![1.0, 2.0], 2, |w| *w).unwrap().1.iter().sum::<f64>(), 3.0);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_weight_balanced<T, F>(vec: Vec<T>, parts: usize, weight_fn: F) -> Result<(Vec<Vec<T>>, Vec<f64>), SplitError>
+where
+    F: Fn(&T) -> f64,
+{
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_weight_balanced(vec, parts, weight_fn))
+}
+
+/// Splits a vec into parts while keeping all elements with the same key in the same part.
+///
+/// The elements are grouped by `key_fn`, the groups are shuffled and then assigned whole, largest first, to the
+/// currently smallest part. Ties between equally small parts are broken randomly. Exact balance is impossible
+/// when the group sizes are skewed: a single group larger than `len / parts` always makes its part bigger than the rest.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into.
+/// * `key_fn` - A function extracting the key whose elements must stay together.
+///
+/// # Returns
+/// A tuple with the `parts` vectors and a map from each key to the index of the part it was assigned to.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_grouped`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_grouped;
+/// let rows = vec![("alice", 1), ("alice", 2), ("bob", 3), ("carol", 4)];
+/// let (parts, assignment) = split_rand_grouped(rows, 2, |row| row.0);
+/// let alice = &parts[assignment["alice"]];
+/// assert!(alice.contains(&("alice", 1)) && alice.contains(&("alice", 2)));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_grouped<T, K, F>(vec: Vec<T>, parts: usize, key_fn: F) -> (Vec<Vec<T>>, HashMap<K, usize>)
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    split_rand_grouped_with_rng(vec, parts, key_fn, &mut rand::rng())
+}
+
+/// Like [`split_rand_grouped`], but shuffles with the given RNG.
+#[cfg(feature = "std")]
+pub fn split_rand_grouped_with_rng<T, K, F, R>(vec: Vec<T>, parts: usize, key_fn: F, rng: &mut R) -> (Vec<Vec<T>>, HashMap<K, usize>)
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+    R: Rng + ?Sized,
+{
+    assert!(parts > 0, "number of parts must be greater than zero");
+    let (index, groups) = group_by_key(vec, key_fn);
+    let (result, group_part) = deal_groups(groups, parts, rng);
+    let assignment = index.into_iter().map(|(key, g)| (key, group_part[g])).collect();
+    (result, assignment)
+}
+
+/// The parts and key assignment produced by [`split_rand_grouped`].
+#[cfg(feature = "std")]
+pub type GroupedParts<T, K> = (Vec<Vec<T>>, HashMap<K, usize>);
+
+/// Like [`split_rand_grouped`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_grouped, SplitError};
+/// let rows = vec![("alice", 1), ("bob", 2)];
+/// assert_eq!(try_split_rand_grouped(rows.clone(), 0, |row| row.0), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_grouped(rows, 2, |row| row.0).unwrap().0.len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_grouped<T, K, F>(vec: Vec<T>, parts: usize, key_fn: F) -> Result<GroupedParts<T, K>, SplitError>
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_grouped(vec, parts, key_fn))
+}
+
+/// Randomizes the order of the entries of a HashMap and splits them into smaller vectors of equal size.
+///
+/// The map is consumed, so neither keys nor values need to implement `Clone`.
+/// # Arguments
+/// * `map` - The map whose entries are split and randomized.
+/// * `parts` - The number of parts to split the entries into. The first `len % parts` parts contain one entry more than the others.
+///
+/// # Returns
+/// A vector of `parts` vectors of `(key, value)` entries.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_eq`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_map_eq;
+/// use std::collections::HashMap;
+/// let map: HashMap<u32, &str> = [(1, "Ann"), (2, "Ben"), (3, "Cem")].into_iter().collect();
+/// let parts = split_rand_map_eq(map, 2);
+/// assert_eq!(parts[0].len(), 2);
+/// assert_eq!(parts[1].len(), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_map_eq<K, V>(map: HashMap<K, V>, parts: usize) -> Vec<Vec<(K, V)>> {
+    split_rand_eq(map, parts)
+}
+
+/// Randomizes the order of the entries of a HashMap and splits them into smaller maps of equal size.
+/// # Arguments
+/// * `map` - The map whose entries are split and randomized.
+/// * `parts` - The number of maps to split the entries into. The first `len % parts` maps contain one entry more than the others.
+///
+/// # Returns
+/// A vector of `parts` maps.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_eq`] and collect every part into a map to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_map_eq_into_maps;
+/// use std::collections::HashMap;
+/// let map: HashMap<u32, &str> = [(1, "Ann"), (2, "Ben"), (3, "Cem")].into_iter().collect();
+/// let maps = split_rand_map_eq_into_maps(map, 3);
+/// assert!(maps.iter().all(|m| m.len() == 1));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_map_eq_into_maps<K: Hash + Eq, V>(map: HashMap<K, V>, parts: usize) -> Vec<HashMap<K, V>> {
+    split_rand_map_eq(map, parts).into_iter().map(|part| part.into_iter().collect()).collect()
+}
+
+/// Collects the items of any iterable, randomizes their order and splits them into smaller vectors of equal size.
+///
+/// This is the shared implementation behind the container-specific functions like [`split_rand_vec_eq`],
+/// [`split_rand_hashset_eq`], [`split_rand_btreeset_eq`] and [`split_rand_map_eq`]. The items are moved, so they
+/// don't need to implement `Clone`.
+/// # Arguments
+/// * `items` - The items to be split and randomized.
+/// * `parts` - The number of parts to split the items into. The first `len % parts` parts contain one element more than the others.
+///
+/// # Returns
+/// A vector of `parts` vectors.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_eq`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_eq;
+/// use std::collections::BinaryHeap;
+/// let parts = split_rand_eq(1..=7, 3);
+/// assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![3, 2, 2]);
+/// let mut heap: BinaryHeap<i32> = (1..=4).collect();
+/// let parts = split_rand_eq(heap.drain(), 2);
+/// assert_eq!(parts[0].len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_eq<I: IntoIterator>(items: I, parts: usize) -> Vec<Vec<I::Item>> {
+    split_rand_eq_with_rng(items, parts, &mut rand::rng())
+}
+
+/// Like [`split_rand_eq`], but shuffles with the given RNG.
+pub fn split_rand_eq_with_rng<I: IntoIterator, R: Rng + ?Sized>(items: I, parts: usize, rng: &mut R) -> Vec<Vec<I::Item>> {
+    shuffle_and_deal(items.into_iter().collect(), parts, rng)
+}
+
+/// Like [`split_rand_eq`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_eq, SplitError};
+/// assert_eq!(try_split_rand_eq(1..=3, 0), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_eq(1..=3, 3).unwrap().len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_split_rand_eq<I: IntoIterator>(items: I, parts: usize) -> Result<Vec<Vec<I::Item>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_eq(items, parts))
+}
+
+/// Randomizes the order of a BTreeSet and splits it into smaller vectors of equal size.
+/// # Arguments
+/// * `set` - The set to be split and randomized.
+/// * `parts` - The number of parts to split the set into. The first `len % parts` parts contain one element more than the others.
+///
+/// # Returns
+/// A vector of `parts` vectors.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_eq`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_btreeset_eq;
+/// use std::collections::BTreeSet;
+/// let set: BTreeSet<i32> = (1..=7).collect();
+/// let parts = split_rand_btreeset_eq(set, 3);
+/// assert_eq!(parts[0].len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_btreeset_eq<T>(set: BTreeSet<T>, parts: usize) -> Vec<Vec<T>> {
+    split_rand_eq(set, parts)
+}
+
+/// Randomizes the order of the entries of a BTreeMap and splits them into smaller vectors of equal size.
+/// # Arguments
+/// * `map` - The map whose entries are split and randomized.
+/// * `parts` - The number of parts to split the entries into. The first `len % parts` parts contain one entry more than the others.
+///
+/// # Returns
+/// A vector of `parts` vectors of `(key, value)` entries.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_eq`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_btreemap_eq;
+/// use std::collections::BTreeMap;
+/// let map: BTreeMap<u32, &str> = [(1, "Ann"), (2, "Ben"), (3, "Cem")].into_iter().collect();
+/// let parts = split_rand_btreemap_eq(map, 2);
+/// assert_eq!(parts[1].len(), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_btreemap_eq<K, V>(map: BTreeMap<K, V>, parts: usize) -> Vec<Vec<(K, V)>> {
+    split_rand_eq(map, parts)
+}
+
+/// Splits a slice into randomized parts of equal size, returning references instead of clones.
+///
+/// Only references are shuffled, so this is cheap even for large elements and doesn't require `T: Clone`.
+/// # Arguments
+/// * `slice` - The elements to be split and randomized.
+/// * `parts` - The number of parts to split the slice into. The first `len % parts` parts contain one element more than the others.
+///
+/// # Returns
+/// A vector of `parts` vectors of references into `slice`.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_eq`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_slice_eq;
+/// let names = vec![String::from("Ann"), String::from("Ben"), String::from("Cem")];
+/// let parts = split_rand_slice_eq(&names, 2);
+/// assert_eq!(parts[0].len(), 2);
+/// assert!(parts.iter().flatten().all(|name| names.contains(name)));
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_slice_eq<T>(slice: &[T], parts: usize) -> Vec<Vec<&T>> {
+    split_rand_eq(slice, parts)
+}
+
+/// Randomly partitions the indices `0..len` into groups of equal size.
+///
+/// This is useful to split data that can't or shouldn't be moved, like parallel arrays or database results.
+/// # Arguments
+/// * `len` - The number of indices to partition.
+/// * `parts` - The number of groups. The first `len % parts` groups contain one index more than the others.
+///
+/// # Returns
+/// A vector of `parts` vectors of indices. Every index in `0..len` appears in exactly one group.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_eq`] with `0..len` to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_indices;
+/// let names = ["Ann", "Ben", "Cem", "Dana"];
+/// let ages = [31, 27, 45, 38];
+/// for group in split_rand_indices(names.len(), 2) {
+///     let members: Vec<(&str, u32)> = group.iter().map(|&i| (names[i], ages[i])).collect();
+///     assert_eq!(members.len(), 2);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_indices(len: usize, parts: usize) -> Vec<Vec<usize>> {
+    split_rand_indices_with_rng(len, parts, &mut rand::rng())
+}
+
+/// Like [`split_rand_indices`], but shuffles with the given RNG.
+pub fn split_rand_indices_with_rng<R: Rng + ?Sized>(len: usize, parts: usize, rng: &mut R) -> Vec<Vec<usize>> {
+    split_rand_eq_with_rng(0..len, parts, rng)
+}
+
+/// Randomly partitions the indices `0..len` into chunks of a specified size.
+/// # Arguments
+/// * `len` - The number of indices to partition.
+/// * `chunk_size` - The size of each chunk. The last chunk may be smaller, as in [`split_rand_vec`].
+///
+/// # Returns
+/// A vector of chunks of indices. Every index in `0..len` appears in exactly one chunk.
+/// # Panics
+/// Panics if `chunk_size` is 0. Use [`try_split_rand_vec`] with `(0..len).collect()` to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_indices_chunks;
+/// let chunks = split_rand_indices_chunks(10, 4);
+/// assert_eq!(chunks.iter().map(|c| c.len()).collect::<Vec<_>>(), vec![4, 4, 2]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_rand_indices_chunks(len: usize, chunk_size: usize) -> Vec<Vec<usize>> {
+    split_rand_indices_chunks_with_rng(len, chunk_size, &mut rand::rng())
+}
+
+/// Like [`split_rand_indices_chunks`], but shuffles with the given RNG.
+pub fn split_rand_indices_chunks_with_rng<R: Rng + ?Sized>(len: usize, chunk_size: usize, rng: &mut R) -> Vec<Vec<usize>> {
+    split_rand_vec_with_rng((0..len).collect(), chunk_size, rng)
+}
+
+/// Splits the items of an iterator of unknown length into randomized parts of equal size, one item at a time.
+///
+/// The items are never collected into an intermediate vector. The parts are filled with an inside-out
+/// Fisher-Yates shuffle: every incoming item is appended to the next part in round-robin order and then swapped
+/// with a uniformly chosen earlier position. After every item the parts hold a uniformly random permutation of the
+/// items seen so far, dealt round-robin, so the result has the same distribution as [`split_rand_vec_eq`] on the
+/// collected items. In particular, every item is equally likely to end up in any part, and the part sizes differ
+/// by at most one at all times.
+/// # Arguments
+/// * `iter` - The items to be split, consumed lazily.
+/// * `parts` - The number of parts to split the items into.
+/// * `rng` - The random number generator used for shuffling.
+///
+/// # Returns
+/// A vector of `parts` vectors, containing every item exactly once.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_stream_eq`] to get an error instead.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::split_rand_stream_eq;
+/// let lines = "Ann\nBen\nCem\nDana\nEli".lines();
+/// let parts = split_rand_stream_eq(lines, 2, &mut StdRng::seed_from_u64(1));
+/// let mut sizes: Vec<usize> = parts.iter().map(|part| part.len()).collect();
+/// sizes.sort();
+/// assert_eq!(sizes, vec![2, 3]);
+/// ```
+pub fn split_rand_stream_eq<I: Iterator, R: Rng + ?Sized>(iter: I, parts: usize, rng: &mut R) -> Vec<Vec<I::Item>> {
+    assert!(parts > 0, "number of parts must be greater than zero");
+    let mut result: Vec<Vec<I::Item>> = (0..parts).map(|_| Vec::new()).collect();
+    for (i, item) in iter.enumerate() {
+        result[i % parts].push(item);
+        let j = rng.random_range(0..=i);
+        if j != i {
+            let (a, b) = (i % parts, j % parts);
+            if a == b {
+                result[a].swap(i / parts, j / parts);
+            } else {
+                let (low, high) = result.split_at_mut(a.max(b));
+                let (new, old) = if a > b { (&mut high[0], &mut low[b]) } else { (&mut low[a], &mut high[0]) };
+                core::mem::swap(&mut new[i / parts], &mut old[j / parts]);
+            }
+        }
+    }
+    result.shuffle(rng);
+    result
+}
+
+/// Like [`split_rand_stream_eq`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::{try_split_rand_stream_eq, SplitError};
+/// let mut rng = StdRng::seed_from_u64(1);
+/// assert_eq!(try_split_rand_stream_eq("Ann\nBen".lines(), 0, &mut rng), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_stream_eq("Ann\nBen".lines(), 2, &mut rng).unwrap().len(), 2);
+/// ```
+pub fn try_split_rand_stream_eq<I: Iterator, R: Rng + ?Sized>(iter: I, parts: usize, rng: &mut R) -> Result<Vec<Vec<I::Item>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_stream_eq(iter, parts, rng))
+}
+
+/// Shuffles a slice in place and partitions it into contiguous ranges of equal size.
+///
+/// Nothing is allocated except the ranges, so each part can be borrowed as `&slice[range.clone()]`.
+/// # Arguments
+/// * `slice` - The elements to shuffle. A `&mut Vec<T>` works as well.
+/// * `parts` - The number of ranges. The first `len % parts` ranges are one longer than the others.
+///
+/// # Returns
+/// `parts` non-overlapping ranges in ascending order that together cover `0..slice.len()`.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_shuffle_partition_in_place`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::shuffle_partition_in_place;
+/// let mut vec: Vec<i32> = (0..7).collect();
+/// let ranges = shuffle_partition_in_place(&mut vec, 3);
+/// assert_eq!(ranges.len(), 3);
+/// assert_eq!(ranges[0].start, 0);
+/// assert_eq!(ranges[2].end, 7);
+/// let parts: Vec<&[i32]> = ranges.iter().map(|range| &vec[range.clone()]).collect();
+/// assert_eq!(parts.iter().map(|part| part.len()).sum::<usize>(), 7);
+/// ```
+#[cfg(feature = "std")]
+pub fn shuffle_partition_in_place<T>(slice: &mut [T], parts: usize) -> Vec<Range<usize>> {
+    shuffle_partition_in_place_with_rng(slice, parts, &mut rand::rng())
+}
+
+/// Like [`shuffle_partition_in_place`], but shuffles with the given RNG.
+pub fn shuffle_partition_in_place_with_rng<T, R: Rng + ?Sized>(slice: &mut [T], parts: usize, rng: &mut R) -> Vec<Range<usize>> {
+    let sizes = eq_part_sizes(slice.len(), parts);
+    slice.shuffle(rng);
+    ranges_of(&sizes)
+}
+
+/// Like [`shuffle_partition_in_place`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_shuffle_partition_in_place, SplitError};
+/// let mut data = [1, 2, 3];
+/// assert_eq!(try_shuffle_partition_in_place(&mut data, 0), Err(SplitError::ZeroParts));
+/// assert_eq!(try_shuffle_partition_in_place(&mut data, 2).unwrap().len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_shuffle_partition_in_place<T>(slice: &mut [T], parts: usize) -> Result<Vec<Range<usize>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(shuffle_partition_in_place(slice, parts))
+}
+
+/// Shuffles a slice in place and partitions it into contiguous ranges of a specified length.
+/// # Arguments
+/// * `slice` - The elements to shuffle.
+/// * `chunk_size` - The length of each range. The last range is shorter if the length is not divisible by `chunk_size`.
+///
+/// # Returns
+/// Non-overlapping ranges in ascending order that together cover `0..slice.len()`.
+/// # Panics
+/// Panics if `chunk_size` is 0. Use [`try_shuffle_chunks_in_place`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::shuffle_chunks_in_place;
+/// let mut vec: Vec<i32> = (0..8).collect();
+/// assert_eq!(shuffle_chunks_in_place(&mut vec, 3), vec![0..3, 3..6, 6..8]);
+/// ```
+#[cfg(feature = "std")]
+pub fn shuffle_chunks_in_place<T>(slice: &mut [T], chunk_size: usize) -> Vec<Range<usize>> {
+    shuffle_chunks_in_place_with_rng(slice, chunk_size, &mut rand::rng())
+}
+
+/// Like [`shuffle_chunks_in_place`], but shuffles with the given RNG.
+pub fn shuffle_chunks_in_place_with_rng<T, R: Rng + ?Sized>(slice: &mut [T], chunk_size: usize, rng: &mut R) -> Vec<Range<usize>> {
+    assert!(chunk_size > 0, "chunk size must be greater than zero");
+    let sizes = chunk_sizes(slice.len(), chunk_size, RemainderStrategy::LastSmaller).unwrap_or_default();
+    slice.shuffle(rng);
+    ranges_of(&sizes)
+}
+
+/// Like [`shuffle_chunks_in_place`], but returns [`SplitError::ZeroChunkSize`] instead of panicking if `chunk_size` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_shuffle_chunks_in_place, SplitError};
+/// let mut data = [1, 2, 3];
+/// assert_eq!(try_shuffle_chunks_in_place(&mut data, 0), Err(SplitError::ZeroChunkSize));
+/// assert_eq!(try_shuffle_chunks_in_place(&mut data, 2).unwrap(), vec![0..2, 2..3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn try_shuffle_chunks_in_place<T>(slice: &mut [T], chunk_size: usize) -> Result<Vec<Range<usize>>, SplitError> {
+    if chunk_size == 0 {
+        return Err(SplitError::ZeroChunkSize);
+    }
+    Ok(shuffle_chunks_in_place(slice, chunk_size))
+}
+
+/// Validates `parts` against `len` and returns the number of parts to produce under `policy`.
+fn resolve_parts(len: usize, parts: usize, policy: EmptyPartsPolicy) -> Result<usize, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    match policy {
+        EmptyPartsPolicy::Allow => Ok(parts),
+        EmptyPartsPolicy::Error if parts > len => Err(SplitError::TooManyParts { parts, len }),
+        EmptyPartsPolicy::Error => Ok(parts),
+        EmptyPartsPolicy::ShrinkToLen => Ok(parts.min(len)),
+    }
+}
+
+/// Computes the chunk sizes for splitting `len` elements into chunks of `chunk_size` under `strategy`.
+fn chunk_sizes(len: usize, chunk_size: usize, strategy: RemainderStrategy) -> Result<Vec<usize>, SplitError> {
+    if chunk_size == 0 {
+        return Err(SplitError::ZeroChunkSize);
+    }
+    let full = len / chunk_size;
+    let remainder = len % chunk_size;
+    let mut sizes = vec![chunk_size; full];
+    match strategy {
+        RemainderStrategy::LastSmaller if remainder > 0 => sizes.push(remainder),
+        RemainderStrategy::LastSmaller | RemainderStrategy::DropRemainder => {}
+        RemainderStrategy::Distribute if full == 0 => {
+            if remainder > 0 {
+                sizes.push(remainder);
+            }
+        }
+        RemainderStrategy::Distribute => {
+            for (i, size) in sizes.iter_mut().enumerate() {
+                *size += remainder / full + usize::from(i < remainder % full);
+            }
+        }
+        RemainderStrategy::Strict if remainder > 0 => return Err(SplitError::Indivisible { len, chunk_size }),
+        RemainderStrategy::Strict => {}
+    }
+    Ok(sizes)
+}
+
+/// Computes the sizes of `parts` equal parts of `len` elements, giving the first `len % parts` parts one extra element.
+fn eq_part_sizes(len: usize, parts: usize) -> Vec<usize> {
+    assert!(parts > 0, "number of parts must be greater than zero");
+    (0..parts).map(|i| len / parts + usize::from(i < len % parts)).collect()
+}
+
+/// Rounds a non-negative `x` to the nearest integer, rounding halves up like [`f64::round`], which needs `std`.
+fn round_to_usize(x: f64) -> usize {
+    let floor = x as usize;
+    floor + usize::from(x - floor as f64 >= 0.5)
+}
+
+/// Computes part sizes for `len` elements proportional to `ratios` using the largest remainder method.
+fn ratio_sizes(len: usize, ratios: &[f64]) -> Result<Vec<usize>, SplitError> {
+    if ratios.is_empty() {
+        return Err(SplitError::EmptyRatios);
+    }
+    if let Some(index) = ratios.iter().position(|r| !r.is_finite() || *r < 0.0) {
+        return Err(SplitError::InvalidRatio { index });
+    }
+    // Dividing by the largest ratio first keeps the sum finite for huge ratios.
+    let max = ratios.iter().copied().fold(0.0, f64::max);
+    if max == 0.0 {
+        return Err(SplitError::ZeroRatioSum);
+    }
+    let sum: f64 = ratios.iter().map(|r| r / max).sum();
+    let exact: Vec<f64> = ratios.iter().map(|r| r / max / sum * len as f64).collect();
+    let mut sizes: Vec<usize> = exact.iter().map(|&e| e as usize).collect();
+    let assigned: usize = sizes.iter().sum();
+    let mut by_remainder: Vec<usize> = (0..ratios.len()).collect();
+    by_remainder.sort_by(|&a, &b| (exact[b] % 1.0).total_cmp(&(exact[a] % 1.0)));
+    for &i in by_remainder.iter().take(len.saturating_sub(assigned)) {
+        sizes[i] += 1;
+    }
+    debug_assert_eq!(sizes.iter().sum::<usize>(), len);
+    Ok(sizes)
+}
+
+/// Adds up part sizes, saturating at `usize::MAX` so that an overflowing sum never matches a length.
+fn total_size(sizes: &[usize]) -> usize {
+    sizes.iter().try_fold(0usize, |total, &size| total.checked_add(size)).unwrap_or(usize::MAX)
+}
+
+/// Turns part sizes into the consecutive ranges they occupy.
+fn ranges_of(sizes: &[usize]) -> Vec<Range<usize>> {
+    let mut start = 0;
+    sizes
+        .iter()
+        .map(|&size| {
+            start += size;
+            start - size..start
+        })
+        .collect()
+}
+
+/// Shuffles `vec` and cuts it into consecutive parts of the given sizes, moving the elements.
+/// Elements beyond the sum of `sizes` are dropped.
+fn shuffle_and_carve<T, R: Rng + ?Sized>(mut vec: Vec<T>, sizes: &[usize], rng: &mut R) -> Vec<Vec<T>> {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(len = vec.len(), parts = sizes.len(), ?sizes, "splitting into parts");
+    vec.shuffle(rng);
+    let mut elements = vec.into_iter();
+    sizes.iter().map(|&size| elements.by_ref().take(size).collect()).collect()
+}
+
+/// Shuffles `vec` and deals its elements round-robin into `parts` parts, moving the elements.
+/// The first `len % parts` parts get one extra element.
+fn shuffle_and_deal<T, R: Rng + ?Sized>(vec: Vec<T>, parts: usize, rng: &mut R) -> Vec<Vec<T>> {
+    deal(vec, parts, false, rng)
+}
+
+/// Like [`shuffle_and_deal`], but gives the extra elements to `len % parts` randomly chosen parts.
+fn deal_with_random_extras<T, R: Rng + ?Sized>(vec: Vec<T>, parts: usize, rng: &mut R) -> Vec<Vec<T>> {
+    deal(vec, parts, true, rng)
+}
+
+/// Like [`eq_part_sizes`], but gives the extra elements to `len % parts` randomly chosen parts.
+#[cfg(feature = "rayon")]
+fn random_eq_part_sizes<R: Rng + ?Sized>(len: usize, parts: usize, rng: &mut R) -> Vec<usize> {
+    let mut sizes = eq_part_sizes(len, parts);
+    sizes.shuffle(rng);
+    sizes
+}
+
+/// Shuffles `vec`, deals its elements round-robin into `parts` parts and, if `random_extras` is set, shuffles the
+/// parts so that the extra elements end up in random parts.
+fn deal<T, R: Rng + ?Sized>(mut vec: Vec<T>, parts: usize, random_extras: bool, rng: &mut R) -> Vec<Vec<T>> {
+    let sizes = eq_part_sizes(vec.len(), parts);
+    vec.shuffle(rng);
+    let mut result: Vec<Vec<T>> = sizes.into_iter().map(Vec::with_capacity).collect();
+    for (i, e) in vec.into_iter().enumerate() {
+        result[i % parts].push(e);
+    }
+    if random_extras {
+        result.shuffle(rng);
+    }
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        len = result.iter().map(Vec::len).sum::<usize>(),
+        parts,
+        sizes = ?result.iter().map(Vec::len).collect::<Vec<_>>(),
+        "dealt into parts"
+    );
+    result
+}
+
+/// Assigns whole groups to parts, largest group first, each to a random one of the currently smallest parts.
+/// Returns the parts and the part of every group.
+#[cfg(feature = "std")]
+fn deal_groups<T, R: Rng + ?Sized>(groups: Vec<Vec<T>>, parts: usize, rng: &mut R) -> (Vec<Vec<T>>, Vec<usize>) {
+    let mut group_part = vec![0; groups.len()];
+    let mut groups: Vec<(usize, Vec<T>)> = groups.into_iter().enumerate().collect();
+    groups.shuffle(rng);
+    groups.sort_by_key(|(_, group)| core::cmp::Reverse(group.len()));
+
+    let mut result: Vec<Vec<T>> = (0..parts).map(|_| Vec::new()).collect();
+    for (g, mut group) in groups {
+        let smallest = result.iter().map(Vec::len).min().unwrap_or(0);
+        let candidates: Vec<usize> = (0..parts).filter(|&p| result[p].len() == smallest).collect();
+        let part = candidates[rng.random_range(0..candidates.len())];
+        group.shuffle(rng);
+        result[part].extend(group);
+        group_part[g] = part;
+    }
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        groups = group_part.len(),
+        parts,
+        sizes = ?result.iter().map(Vec::len).collect::<Vec<_>>(),
+        "dealt groups"
+    );
+    (result, group_part)
+}
+
+/// Groups the elements of `vec` by key, keeping the groups in order of first occurrence.
+/// Returns the index of each key's group along with the groups.
+#[cfg(feature = "std")]
+fn group_by_key<T, K, F>(vec: Vec<T>, key_fn: F) -> (HashMap<K, usize>, Vec<Vec<T>>)
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    let mut index: HashMap<K, usize> = HashMap::new();
+    let mut groups: Vec<Vec<T>> = Vec::new();
+    for e in vec {
+        let next = groups.len();
+        let i = *index.entry(key_fn(&e)).or_insert(next);
+        if i == next {
+            groups.push(Vec::new());
+        }
+        groups[i].push(e);
+    }
+    (index, groups)
+}
+
+/// Checks if a slice of the type 'T' contains sufficient items as specified.
+///
+/// Every requirement scans `checked_vector` again. If `T` implements `Hash` and `Eq`, `check_sufficient_items_fast`
+/// counts the slice once instead.
+/// # Arguments
+/// * `req_items` - A slice of tuples where each tuple contains an item of type `T` and a required count of that item.
+/// * `checked_vector` - A slice of items of type `T` that has been checked against the requirements.
+///
+/// # Returns
+/// A boolean indicating whether the checked vector contains sufficient items as specified in `req_items`.
+/// # Example
+/// ```
+/// use rust_helpers::check_sufficient_items;
+/// let req_items = [("apple", 2), ("banana", 1), ("orange", 3)];
+/// let checked_vector = vec!["apple", "apple", "apple", "banana", "orange", "orange", "orange", "orange"];
+/// assert!(check_sufficient_items(&req_items, &checked_vector));
+/// ```
+pub fn check_sufficient_items<T: PartialEq>(req_items: &[(T, usize)], checked_vector: &[T]) -> bool {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!(
+        "check_sufficient_items",
+        requirements = req_items.len(),
+        checked = checked_vector.len()
+    )
+    .entered();
+    for (item, req_number_item) in req_items {
+        let cnt_check = checked_vector.iter().filter(|x| *x == item).count();
+        #[cfg(feature = "tracing")]
+        tracing::trace!(required = *req_number_item, found = cnt_check, "counted requirement");
+        if cnt_check < *req_number_item {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(required = *req_number_item, found = cnt_check, "requirement not met");
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn sorted_sizes<T>(parts: &[Vec<T>]) -> Vec<usize> {
+        let mut sizes: Vec<usize> = parts.iter().map(Vec::len).collect();
+        sizes.sort();
+        sizes
+    }
+
+    /// Counts how often any of its copies has been cloned.
+    struct CloneCounter(std::rc::Rc<std::cell::Cell<usize>>);
+
+    impl Clone for CloneCounter {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            CloneCounter(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn test_greet_rust_helpers() {
+        let greeting = greet_rust_helpers("Alice");
+        assert_eq!(greeting, "Hello, Alice! Welcome to Rust Helpers.");
+    }
+
+    #[test]
+    fn test_split_rand_vec() {
+        let vec = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let chunked = split_rand_vec(vec, 3);
+        assert_eq!(chunked.len(), 3);
+        assert!(chunked.iter().all(|chunk| chunk.len() <= 3));
+    }
+
+    #[test]
+    fn test_split_rand_vec_does_not_clone() {
+        let clones = std::rc::Rc::new(std::cell::Cell::new(0));
+        let vec: Vec<CloneCounter> = (0..1000).map(|_| CloneCounter(clones.clone())).collect();
+        let chunked = split_rand_vec(vec, 7);
+        assert_eq!(chunked.iter().map(Vec::len).sum::<usize>(), 1000);
+        assert_eq!(chunked.len(), 143);
+        assert_eq!(clones.get(), 0);
+    }
+
+    #[test]
+    fn test_split_rand_eq_allocates_exact_capacity() {
+        let parts = split_rand_vec_eq((0..1003).collect::<Vec<u32>>(), 10);
+        assert!(parts.iter().all(|part| part.capacity() == part.len()));
+        let mut all: Vec<u32> = parts.concat();
+        all.sort();
+        assert_eq!(all, (0..1003).collect::<Vec<_>>());
+
+        let parts = split_rand_hashset_eq((0..1003).collect::<HashSet<u32>>(), 10);
+        assert!(parts.iter().all(|part| part.capacity() == part.len()));
+        assert_eq!(sorted_sizes(&parts), vec![100, 100, 100, 100, 100, 100, 100, 101, 101, 101]);
+        assert_eq!(parts.concat().into_iter().collect::<HashSet<_>>(), (0..1003).collect());
+    }
+
+    #[test]
+    fn test_split_rand_stream_eq() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let parts = split_rand_stream_eq(0..10_001_u32, 4, &mut rng);
+        assert_eq!(sorted_sizes(&parts), vec![2500, 2500, 2500, 2501]);
+        let mut all: Vec<u32> = parts.concat();
+        all.sort();
+        assert_eq!(all, (0..10_001).collect::<Vec<_>>());
+        assert!(split_rand_stream_eq(std::iter::empty::<u32>(), 3, &mut rng).iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn test_split_rand_stream_eq_is_uniform() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut counts = [[0_u32; 3]; 6];
+        let mut together = 0;
+        for _ in 0..3000 {
+            for (p, part) in split_rand_stream_eq(0..6_usize, 3, &mut rng).into_iter().enumerate() {
+                together += u32::from(part.contains(&0) && part.contains(&1));
+                for item in part {
+                    counts[item][p] += 1;
+                }
+            }
+        }
+        assert!(counts.iter().flatten().all(|&count| (850..1150).contains(&count)), "{:?}", counts);
+        // In a uniformly random split of 6 items into pairs, two given items share a pair with probability 1/5.
+        assert!((480..720).contains(&together), "{}", together);
+    }
+
+    #[test]
+    fn test_shuffle_partition_in_place() {
+        fn assert_covering(ranges: &[Range<usize>], len: usize) {
+            assert_eq!(ranges.first().map_or(0, |range| range.start), 0);
+            assert_eq!(ranges.last().map_or(0, |range| range.end), len);
+            assert!(ranges.windows(2).all(|w| w[0].end == w[1].start));
+        }
+
+        let mut vec: Vec<u32> = (0..10).collect();
+        let ranges = shuffle_partition_in_place(&mut vec, 3);
+        assert_covering(&ranges, 10);
+        let mut sizes: Vec<usize> = ranges.iter().map(|range| range.len()).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![3, 3, 4]);
+        vec.sort();
+        assert_eq!(vec, (0..10).collect::<Vec<_>>());
+
+        let mut vec: Vec<u32> = (0..2).collect();
+        let ranges = shuffle_partition_in_place(&mut vec, 4);
+        assert_covering(&ranges, 2);
+        assert_eq!(ranges.iter().filter(|range| range.is_empty()).count(), 2);
+    }
+
+    #[test]
+    fn test_shuffle_chunks_in_place() {
+        let mut vec: Vec<u32> = (0..10).collect();
+        let mut rng = StdRng::seed_from_u64(2);
+        assert_eq!(shuffle_chunks_in_place_with_rng(&mut vec, 4, &mut rng), vec![0..4, 4..8, 8..10]);
+        assert_ne!(vec, (0..10).collect::<Vec<_>>());
+        vec.sort();
+        assert_eq!(vec, (0..10).collect::<Vec<_>>());
+        assert!(shuffle_chunks_in_place::<u32>(&mut [], 3).is_empty());
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_tracked_reconstructs_original() {
+        let vec: Vec<String> = (0..11).map(|i| format!("item {}", i)).collect();
+        let (parts, permutation) = split_rand_vec_eq_tracked(vec.clone(), 3);
+        assert_eq!(sorted_sizes(&parts), vec![3, 4, 4]);
+        let mut restored: Vec<Option<String>> = vec![None; vec.len()];
+        for (e, &p) in parts.into_iter().flatten().zip(permutation.as_slice()) {
+            restored[p] = Some(e);
+        }
+        assert_eq!(restored.into_iter().map(Option::unwrap).collect::<Vec<_>>(), vec);
+        let (parts, permutation) = split_rand_vec_eq_tracked(vec.clone(), 3);
+        assert_eq!(permutation.invert().apply(&parts.concat()), vec);
+    }
+
+    #[test]
+    fn test_split_rand_zip_eq_matches_zipped_split() {
+        let xs: Vec<String> = (0..13).map(|i| format!("x{}", i)).collect();
+        let ys: Vec<u32> = (0..13).collect();
+        let parts = split_rand_zip_eq_with_rng(xs.clone(), ys.clone(), 4, &mut StdRng::seed_from_u64(5)).unwrap();
+        let zipped: Vec<(String, u32)> = xs.into_iter().zip(ys).collect();
+        let expected: Vec<(Vec<String>, Vec<u32>)> = split_rand_vec_eq_with_rng(zipped, 4, &mut StdRng::seed_from_u64(5))
+            .into_iter()
+            .map(|part| part.into_iter().unzip())
+            .collect();
+        assert_eq!(parts, expected);
+        assert_eq!(sorted_sizes(&parts.iter().map(|(xs, _)| xs.clone()).collect::<Vec<_>>()), vec![3, 3, 3, 4]);
+        for (xs, ys) in split_rand_zip_eq(vec![10, 20, 30, 40, 50], vec![1, 2, 3, 4, 5], 2).unwrap() {
+            assert!(xs.iter().zip(&ys).all(|(x, y)| *x == y * 10));
+        }
+    }
+
+    #[test]
+    fn test_split_rand_zip_eq_rejects_invalid_input() {
+        assert_eq!(split_rand_zip_eq(vec![1, 2, 3], vec!['a', 'b'], 2), Err(SplitError::LengthMismatch { left: 3, right: 2 }));
+        assert_eq!(split_rand_zip_eq(vec![1], vec!['a'], 0), Err(SplitError::ZeroParts));
+        assert_eq!(split_rand_zip_eq(Vec::<u8>::new(), Vec::<u8>::new(), 2), Ok(vec![(vec![], vec![]), (vec![], vec![])]));
+    }
+
+    #[test]
+    fn test_split_rand_parts() {
+        let chunks = split_rand_vec_parts((0..7).collect::<Vec<i32>>(), 3);
+        assert_eq!(chunks.sizes(), vec![3, 3, 1]);
+        let parts = split_rand_vec_eq_parts((0..7).collect::<Vec<i32>>(), 3);
+        assert_eq!(sorted_sizes(&parts.clone().into_inner()), vec![2, 2, 3]);
+        let mut all = parts.flatten();
+        all.sort();
+        assert_eq!(all, (0..7).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_try_split_rand_vec() {
+        assert_eq!(try_split_rand_vec(vec![1, 2, 3], 0), Err(SplitError::ZeroChunkSize));
+
+        let chunked = try_split_rand_vec(vec![1, 2, 3], 10).unwrap();
+        assert_eq!(chunked.len(), 1);
+        assert_eq!(chunked[0].len(), 3);
+
+        let empty: Vec<Vec<i32>> = try_split_rand_vec(Vec::new(), 3).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(try_split_rand_vec(Vec::<i32>::new(), 0), Err(SplitError::ZeroChunkSize));
+    }
+
+    #[test]
+    fn test_split_rand_vec_with_strategy() {
+        fn sizes(len: usize, strategy: RemainderStrategy) -> Result<Vec<usize>, SplitError> {
+            let vec: Vec<usize> = (0..len).collect();
+            let chunked = split_rand_vec_with_strategy(vec, 3, strategy)?;
+            let mut all: Vec<usize> = chunked.concat();
+            all.sort();
+            assert!(all.windows(2).all(|w| w[0] < w[1]));
+            Ok(chunked.iter().map(|c| c.len()).collect())
+        }
+
+        use RemainderStrategy::*;
+        for strategy in [LastSmaller, DropRemainder, Distribute, Strict] {
+            assert_eq!(sizes(6, strategy), Ok(vec![3, 3]));
+        }
+
+        assert_eq!(sizes(7, LastSmaller), Ok(vec![3, 3, 1]));
+        assert_eq!(sizes(7, DropRemainder), Ok(vec![3, 3]));
+        assert_eq!(sizes(7, Distribute), Ok(vec![4, 3]));
+        assert_eq!(sizes(7, Strict), Err(SplitError::Indivisible { len: 7, chunk_size: 3 }));
+        assert_eq!(sizes(5, Distribute), Ok(vec![5]));
+
+        assert_eq!(sizes(2, LastSmaller), Ok(vec![2]));
+        assert_eq!(sizes(2, DropRemainder), Ok(vec![]));
+        assert_eq!(sizes(2, Distribute), Ok(vec![2]));
+        assert_eq!(sizes(2, Strict), Err(SplitError::Indivisible { len: 2, chunk_size: 3 }));
+
+        assert_eq!(split_rand_vec_with_strategy(vec![1], 0, LastSmaller), Err(SplitError::ZeroChunkSize));
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq() {
+        let vec = vec![1, 2, 3, 4, 5, 6, 7];
+        let chunked = split_rand_vec_eq(vec, 3);
+        assert_eq!(chunked.len(), 3);
+        assert_eq!(sorted_sizes(&chunked), vec![2, 2, 3]);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_extras_are_random() {
+        let runs = 3000;
+        let mut extras = [0; 4];
+        for _ in 0..runs {
+            for (i, part) in split_rand_vec_eq((0..9).collect::<Vec<i32>>(), 4).iter().enumerate() {
+                if part.len() == 3 {
+                    extras[i] += 1;
+                }
+            }
+        }
+        assert!(extras.iter().all(|&count| (600..900).contains(&count)), "extra element counts {:?}", extras);
+
+        let mut extras = [0; 3];
+        for _ in 0..runs {
+            let set: HashSet<i32> = (0..7).collect();
+            for (i, part) in split_rand_hashset_eq(set, 3).iter().enumerate() {
+                if part.len() == 3 {
+                    extras[i] += 1;
+                }
+            }
+        }
+        assert!(extras.iter().all(|&count| (850..1150).contains(&count)), "extra element counts {:?}", extras);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_contiguous() {
+        let vec: Vec<i32> = vec![1, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let chunked = split_rand_vec_eq_contiguous(vec.clone(), 4);
+        assert_eq!(chunked.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![3, 3, 2, 2]);
+        let mut all = chunked.concat();
+        all.sort();
+        assert_eq!(all, vec);
+
+        let empty = split_rand_vec_eq_contiguous(Vec::<i32>::new(), 2);
+        assert_eq!(empty, vec![Vec::<i32>::new(), Vec::new()]);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_indexed() {
+        let vec: Vec<String> = (0..11).map(|i| format!("item{}", i)).collect();
+        let parts = split_rand_vec_eq_indexed(vec.clone(), 3);
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![4, 4, 3]);
+
+        let mut restored: Vec<Option<String>> = vec![None; vec.len()];
+        for (i, e) in parts.into_iter().flatten() {
+            assert!(restored[i].is_none());
+            restored[i] = Some(e);
+        }
+        let restored: Vec<String> = restored.into_iter().map(Option::unwrap).collect();
+        assert_eq!(restored, vec);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_multi() {
+        let result = split_rand_vec_eq_multi((0..6).collect::<Vec<u32>>(), 3, 5);
+        assert!(result.is_complete());
+        let canonical: HashSet<Vec<Vec<u32>>> = result
+            .groupings
+            .iter()
+            .map(|grouping| {
+                assert_eq!(grouping.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 2, 2]);
+                let mut parts: Vec<Vec<u32>> = grouping
+                    .iter()
+                    .map(|part| {
+                        let mut part = part.clone();
+                        part.sort();
+                        part
+                    })
+                    .collect();
+                parts.sort();
+                parts
+            })
+            .collect();
+        assert_eq!(canonical.len(), 5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_distinct_groupings_serde_round_trip() {
+        let result = split_rand_vec_eq_multi(vec![1, 2, 3, 4], 2, 2);
+        let json = serde_json::to_string(&result).unwrap();
+        assert_eq!(serde_json::from_str::<DistinctGroupings<i32>>(&json).unwrap(), result);
+        let json = serde_json::to_string(&EmptyPartsPolicy::ShrinkToLen).unwrap();
+        assert_eq!(serde_json::from_str::<EmptyPartsPolicy>(&json).unwrap(), EmptyPartsPolicy::ShrinkToLen);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_multi_too_small() {
+        let result = split_rand_vec_eq_multi(vec!['a', 'b'], 2, 3);
+        assert!(!result.is_complete());
+        assert_eq!(result.groupings.len(), 1);
+        assert_eq!(result.requested, 3);
+
+        let result = split_rand_vec_eq_multi(vec!['a', 'a', 'b'], 3, 2);
+        assert_eq!(result.groupings.len(), 1);
+        assert!(split_rand_vec_eq_multi(vec![1, 2, 3], 2, 0).is_complete());
+    }
+
+    #[test]
+    fn test_split_rand_named() {
+        let teams = split_rand_named((0..7).collect(), &["Red", "Blue", "Green"]).unwrap();
+        let names: Vec<&str> = teams.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["Red", "Blue", "Green"]);
+        assert_eq!(teams.iter().map(|(_, team)| team.len()).collect::<Vec<_>>(), vec![3, 2, 2]);
+        let mut all: Vec<i32> = teams.into_iter().flat_map(|(_, team)| team).collect();
+        all.sort();
+        assert_eq!(all, (0..7).collect::<Vec<_>>());
+
+        let seeded = split_rand_named_with_rng((0..7).collect::<Vec<i32>>(), &["a", "b"], &mut StdRng::seed_from_u64(3)).unwrap();
+        let parts: Vec<Vec<i32>> = seeded.into_iter().map(|(_, team)| team).collect();
+        assert_eq!(parts, split_rand_vec_eq_seeded((0..7).collect(), 2, 3));
+    }
+
+    #[test]
+    fn test_split_rand_named_errors() {
+        assert_eq!(split_rand_named(vec![1, 2, 3], &[]), Err(SplitError::ZeroParts));
+        assert_eq!(
+            split_rand_named(vec![1, 2, 3], &["Red", "Blue", "Red"]),
+            Err(SplitError::DuplicateName("Red".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_split_rand_array() {
+        let [a, b] = split_rand_array((0..5).collect::<Vec<i32>>());
+        assert_eq!((a.len(), b.len()), (3, 2));
+
+        let parts: [Vec<i32>; 5] = split_rand_array((0..12).collect());
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![3, 3, 2, 2, 2]);
+        let mut all = parts.concat();
+        all.sort();
+        assert_eq!(all, (0..12).collect::<Vec<_>>());
+
+        struct NoClone;
+        let [x, y] = split_rand_array(vec![NoClone, NoClone, NoClone]);
+        assert_eq!(x.len() + y.len(), 3);
+    }
+
+    #[test]
+    fn test_split_rand_exact_chunks() {
+        let (chunks, rest) = split_rand_exact_chunks::<i32, 4>((0..12).collect());
+        assert_eq!(chunks.len(), 3);
+        assert!(rest.is_empty());
+        let mut all: Vec<i32> = chunks.iter().flatten().copied().collect();
+        all.sort();
+        assert_eq!(all, (0..12).collect::<Vec<_>>());
+
+        let (chunks, rest) = split_rand_exact_chunks::<String, 4>((0..14).map(|i| i.to_string()).collect());
+        assert_eq!((chunks.len(), rest.len()), (3, 2));
+        let mut all: Vec<String> = chunks.into_iter().flatten().chain(rest).collect();
+        all.sort_by_key(|s| s.parse::<i32>().unwrap());
+        assert_eq!(all, (0..14).map(|i| i.to_string()).collect::<Vec<_>>());
+
+        let (chunks, rest) = split_rand_exact_chunks::<i32, 5>(vec![1, 2]);
+        assert!(chunks.is_empty());
+        assert_eq!(rest.len(), 2);
+    }
+
+    #[test]
+    fn test_split_rand_by_ratios() {
+        let parts = split_rand_by_ratios((0..7).collect::<Vec<_>>(), &[0.5, 0.5]).unwrap();
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![4, 3]);
+        let mut all = parts.concat();
+        all.sort();
+        assert_eq!(all, (0..7).collect::<Vec<_>>());
+
+        let parts = split_rand_by_ratios((0..10).collect::<Vec<_>>(), &[7.0, 2.0, 1.0]).unwrap();
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![7, 2, 1]);
+
+        for len in 0..30 {
+            let parts = split_rand_by_ratios((0..len).collect::<Vec<_>>(), &[0.33, 0.33, 0.33, 0.01]).unwrap();
+            assert_eq!(parts.iter().map(|p| p.len()).sum::<usize>(), len);
+        }
+
+        assert_eq!(split_rand_by_ratios(vec![1], &[]), Err(SplitError::EmptyRatios));
+        assert_eq!(split_rand_by_ratios(vec![1], &[0.5, -0.1]), Err(SplitError::InvalidRatio { index: 1 }));
+        assert_eq!(split_rand_by_ratios(vec![1], &[f64::NAN]), Err(SplitError::InvalidRatio { index: 0 }));
+        assert_eq!(split_rand_by_ratios(vec![1], &[0.0, 0.0]), Err(SplitError::ZeroRatioSum));
+    }
+
+    #[test]
+    fn test_split_rand_by_ratios_with_huge_ratios() {
+        let parts = split_rand_by_ratios((0..10).collect::<Vec<_>>(), &[f64::MAX, f64::MAX]).unwrap();
+        assert_eq!(sorted_sizes(&parts), vec![5, 5]);
+        let parts = split_rand_by_ratios((0..10).collect::<Vec<_>>(), &[f64::MAX, f64::MAX / 4.0, 0.0]).unwrap();
+        assert_eq!(parts.iter().map(Vec::len).collect::<Vec<_>>(), vec![8, 2, 0]);
+        let parts = split_rand_by_ratios((0..4).collect::<Vec<_>>(), &[f64::MIN_POSITIVE, 5e-324]).unwrap();
+        assert_eq!(parts.iter().map(Vec::len).sum::<usize>(), 4);
+    }
+
+    #[test]
+    fn test_split_rand_by_ratios_seeded() {
+        let vec: Vec<i32> = (0..50).collect();
+        let first = split_rand_by_ratios_seeded(vec.clone(), &[0.7, 0.2, 0.1], 9).unwrap();
+        assert_eq!(first, split_rand_by_ratios_seeded(vec.clone(), &[0.7, 0.2, 0.1], 9).unwrap());
+        assert_ne!(first, split_rand_by_ratios_seeded(vec, &[0.7, 0.2, 0.1], 10).unwrap());
+    }
+
+    #[test]
+    fn test_split_rand_sizes() {
+        let vec: Vec<i32> = (0..10).collect();
+        let parts = split_rand_sizes(vec.clone(), &[4, 3, 3]).unwrap();
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![4, 3, 3]);
+        let mut all = parts.concat();
+        all.sort();
+        assert_eq!(all, vec);
+
+        assert_eq!(split_rand_sizes(vec.clone(), &[4, 3]), Err(SplitError::SizesMismatch { expected: 10, got: 7 }));
+        assert_eq!(split_rand_sizes(vec.clone(), &[4, 4, 4]), Err(SplitError::SizesMismatch { expected: 10, got: 12 }));
+
+        let parts = split_rand_sizes(vec, &[5, 0, 5]).unwrap();
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![5, 0, 5]);
+    }
+
+    #[test]
+    fn test_split_rand_sizes_lenient() {
+        let vec: Vec<i32> = (0..10).collect();
+        let parts = split_rand_sizes_lenient(vec.clone(), &[4, 3, 3]).unwrap();
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![4, 3, 3, 0]);
+
+        let parts = split_rand_sizes_lenient(vec.clone(), &[4, 0, 3]).unwrap();
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![4, 0, 3, 3]);
+        let mut all = parts.concat();
+        all.sort();
+        assert_eq!(all, vec);
+
+        assert_eq!(split_rand_sizes_lenient(vec, &[11]), Err(SplitError::SizesMismatch { expected: 10, got: 11 }));
+    }
+
+    #[test]
+    fn test_split_rand_sizes_overflowing_sum() {
+        let overflow = Err(SplitError::SizesMismatch { expected: 3, got: usize::MAX });
+        assert_eq!(split_rand_sizes(vec![1, 2, 3], &[usize::MAX, 4]), overflow);
+        assert_eq!(split_rand_sizes_lenient(vec![1, 2, 3], &[usize::MAX, 4]), overflow);
+        assert_eq!(split_rand_sizes_lenient(vec![1, 2, 3], &[usize::MAX]), overflow);
+    }
+
+    #[test]
+    fn test_train_test_split() {
+        let (train, test) = train_test_split((0..10).collect::<Vec<_>>(), 0.25).unwrap();
+        assert_eq!((train.len(), test.len()), (7, 3));
+        let mut all = [train, test].concat();
+        all.sort();
+        assert_eq!(all, (0..10).collect::<Vec<_>>());
+
+        let (train, test) = train_test_split(vec![1, 2], 0.5).unwrap();
+        assert_eq!((train.len(), test.len()), (1, 1));
+        let (train, test) = train_test_split((0..10).collect::<Vec<_>>(), 0.01).unwrap();
+        assert_eq!((train.len(), test.len()), (9, 1));
+        let (train, test) = train_test_split((0..10).collect::<Vec<_>>(), 0.99).unwrap();
+        assert_eq!((train.len(), test.len()), (1, 9));
+
+        // A test size that rounds to 0 is raised to 1, one that rounds to `len` is lowered to `len - 1`.
+        for len in 2..20 {
+            let (train, test) = train_test_split_seeded((0..len).collect::<Vec<_>>(), 0.001, len as u64).unwrap();
+            assert_eq!((train.len(), test.len()), (len - 1, 1));
+            let (train, test) = train_test_split_seeded((0..len).collect::<Vec<_>>(), 0.999, len as u64).unwrap();
+            assert_eq!((train.len(), test.len()), (1, len - 1));
+        }
+
+        assert_eq!(train_test_split(vec![1], 0.5), Err(SplitError::TooFewElements { required: 2, len: 1 }));
+        assert_eq!(train_test_split(Vec::<i32>::new(), 0.5), Err(SplitError::TooFewElements { required: 2, len: 0 }));
+        for fraction in [0.0, 1.0, -0.5, 1.5, f64::NAN] {
+            assert_eq!(train_test_split(vec![1, 2, 3], fraction), Err(SplitError::InvalidFraction));
+        }
+    }
+
+    #[test]
+    fn test_train_test_split_seeded() {
+        let data: Vec<i32> = (0..100).collect();
+        let first = train_test_split_seeded(data.clone(), 0.3, 11).unwrap();
+        assert_eq!(first, train_test_split_seeded(data.clone(), 0.3, 11).unwrap());
+        assert_ne!(first, train_test_split_seeded(data, 0.3, 12).unwrap());
+    }
+
+    #[test]
+    fn test_train_val_test_split() {
+        let (train, val, test) = train_val_test_split((0..100).collect::<Vec<_>>(), 0.15, 0.15).unwrap();
+        assert_eq!((train.len(), val.len(), test.len()), (70, 15, 15));
+        let mut all = [train, val, test].concat();
+        all.sort();
+        assert_eq!(all, (0..100).collect::<Vec<_>>());
+
+        for len in 3..40 {
+            let (train, val, test) = train_val_test_split((0..len).collect::<Vec<_>>(), 0.33, 0.33).unwrap();
+            assert_eq!(train.len() + val.len() + test.len(), len);
+            assert!(!train.is_empty() && !val.is_empty() && !test.is_empty());
+        }
+
+        // Both portions round up to 5, which leaves no element for the train portion.
+        let sizes = |val, test| {
+            let (train, val, test) = train_val_test_split_seeded((0..10).collect::<Vec<_>>(), val, test, 1).unwrap();
+            (train.len(), val.len(), test.len())
+        };
+        assert_eq!(sizes(0.48, 0.49), (1, 4, 5));
+        assert_eq!(sizes(0.49, 0.48), (1, 5, 4));
+        // The validation portion rounds up to the whole input and gives up elements until the others fit.
+        assert_eq!(sizes(0.96, 0.01), (1, 8, 1));
+        assert_eq!(sizes(0.01, 0.96), (1, 1, 8));
+
+        assert_eq!(train_val_test_split(vec![1, 2], 0.3, 0.3), Err(SplitError::TooFewElements { required: 3, len: 2 }));
+        assert_eq!(train_val_test_split(vec![1, 2, 3], 0.5, 0.5), Err(SplitError::InvalidFraction));
+        assert_eq!(train_val_test_split(vec![1, 2, 3], 0.0, 0.5), Err(SplitError::InvalidFraction));
+    }
+
+    #[test]
+    fn test_train_val_test_split_seeded() {
+        let data: Vec<i32> = (0..60).collect();
+        let first = train_val_test_split_seeded(data.clone(), 0.2, 0.1, 3).unwrap();
+        assert_eq!(first, train_val_test_split_seeded(data.clone(), 0.2, 0.1, 3).unwrap());
+        assert_ne!(first, train_val_test_split_seeded(data, 0.2, 0.1, 4).unwrap());
+    }
+
+    #[test]
+    fn test_k_fold() {
+        let vec: Vec<i32> = vec![1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let folds = k_fold(vec.clone(), 4).unwrap();
+        assert_eq!(folds.len(), 4);
+        let mut test_sizes: Vec<usize> = folds.iter().map(|(_, test)| test.len()).collect();
+        test_sizes.sort();
+        assert_eq!(test_sizes, vec![2, 3, 3, 3]);
+
+        let mut tests: Vec<i32> = folds.iter().flat_map(|(_, test)| test.clone()).collect();
+        tests.sort();
+        assert_eq!(tests, vec);
+        for (train, test) in &folds {
+            let mut fold = [train.clone(), test.clone()].concat();
+            fold.sort();
+            assert_eq!(fold, vec);
+        }
+
+        assert_eq!(k_fold(vec![1, 2], 0), Err(SplitError::ZeroParts));
+        assert_eq!(k_fold(vec![1, 2], 3), Err(SplitError::TooManyParts { parts: 3, len: 2 }));
+    }
+
+    #[test]
+    fn test_leave_one_out() {
+        let vec: Vec<i32> = (0..5).collect();
+        let mut iter = leave_one_out(vec.clone());
+        assert_eq!(iter.len(), 5);
+        let (rest, single) = iter.next().unwrap();
+        assert_eq!(rest.len(), 4);
+        assert!(!rest.contains(&single));
+        assert_eq!(iter.len(), 4);
+
+        let mut singles: Vec<i32> = leave_one_out(vec.clone()).map(|(_, single)| single).collect();
+        singles.sort();
+        assert_eq!(singles, vec);
+
+        assert_eq!(leave_one_out(Vec::<i32>::new()).count(), 0);
+        let pairs: Vec<(Vec<i32>, i32)> = leave_one_out(vec![7]).collect();
+        assert_eq!(pairs, vec![(Vec::new(), 7)]);
+    }
+
+    #[test]
+    fn test_split_rand_vec_iter() {
+        let mut chunks = split_rand_vec_iter((0..10).collect::<Vec<i32>>(), 4);
+        assert_eq!(chunks.len(), 3);
+        let first = chunks.next().unwrap();
+        assert_eq!(first.len(), 4);
+        assert_eq!(chunks.len(), 2);
+        let rest: Vec<Vec<i32>> = chunks.collect();
+        assert_eq!(rest.iter().map(|c| c.len()).collect::<Vec<_>>(), vec![4, 2]);
+        let mut all = [vec![first], rest].concat().concat();
+        all.sort();
+        assert_eq!(all, (0..10).collect::<Vec<_>>());
+
+        let sizes: Vec<usize> = split_rand_vec_iter((0..9).collect::<Vec<i32>>(), 3).map(|c| c.len()).collect();
+        assert_eq!(sizes, vec![3, 3, 3]);
+        assert_eq!(split_rand_vec_iter(Vec::<i32>::new(), 3).len(), 0);
+        assert_eq!(split_rand_vec_iter(Vec::<i32>::new(), 3).next(), None);
+    }
+
+    #[test]
+    fn test_bootstrap_parts() {
+        let items = [1, 2, 3];
+        let samples = bootstrap_parts(&items, 3, 10).unwrap();
+        assert_eq!(samples.len(), 3);
+        assert!(samples.iter().all(|s| s.len() == 10 && s.iter().all(|x| items.contains(x))));
+        // 30 draws from 3 values must repeat at least one of them.
+        assert!(samples.iter().any(|s| s.iter().any(|x| s.iter().filter(|y| *y == x).count() > 1)));
+
+        assert_eq!(bootstrap_parts(&[1], 2, 0).unwrap(), vec![Vec::<i32>::new(), Vec::new()]);
+        assert_eq!(bootstrap_parts::<i32>(&[], 2, 0).unwrap().len(), 2);
+        assert_eq!(bootstrap_parts::<i32>(&[], 2, 1), Err(SplitError::EmptyInput));
+        assert_eq!(bootstrap_parts(&[1], 0, 1), Err(SplitError::ZeroParts));
+    }
+
+    #[test]
+    fn test_bootstrap_parts_seeded() {
+        let items: Vec<i32> = (0..20).collect();
+        let first = bootstrap_parts_seeded(&items, 4, 20, 1).unwrap();
+        assert_eq!(first, bootstrap_parts_seeded(&items, 4, 20, 1).unwrap());
+        assert_ne!(first, bootstrap_parts_seeded(&items, 4, 20, 2).unwrap());
+    }
+
+    #[test]
+    fn test_split_rand_stratified() {
+        let mut rows: Vec<(char, i32)> = (0..10).map(|i| ('a', i)).collect();
+        rows.push(('b', 10));
+        rows.push(('b', 11));
+        for _ in 0..50 {
+            let parts = split_rand_stratified(rows.clone(), 3, |row| row.0);
+            assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![4, 4, 4]);
+            assert!(parts.iter().all(|p| p.iter().filter(|row| row.0 == 'b').count() <= 1));
+            let a_counts: Vec<usize> = parts.iter().map(|p| p.iter().filter(|row| row.0 == 'a').count()).collect();
+            assert!(a_counts.iter().max().unwrap() - a_counts.iter().min().unwrap() <= 1);
+            let mut all = parts.concat();
+            all.sort();
+            assert_eq!(all, rows);
+        }
+    }
+
+    #[test]
+    fn test_split_rand_balanced_by() {
+        let mut people: Vec<(u32, &str)> = (0..7).map(|i| (i, "beginner")).collect();
+        people.extend((7..12).map(|i| (i, "expert")));
+        people.push((12, "coach"));
+        for _ in 0..50 {
+            let (groups, tallies) = split_rand_balanced_by(people.clone(), 4, |person| person.1);
+            assert_eq!(groups.len(), 4);
+            for (group, tally) in groups.iter().zip(&tallies) {
+                assert_eq!(tally.values().sum::<usize>(), group.len());
+            }
+            for key in ["beginner", "expert", "coach"] {
+                let counts: Vec<usize> = tallies.iter().map(|tally| tally.get(key).copied().unwrap_or(0)).collect();
+                assert!(counts.iter().max().unwrap() - counts.iter().min().unwrap() <= 1, "{} counts {:?}", key, counts);
+            }
+            assert_eq!(tallies.iter().filter(|tally| tally.contains_key("coach")).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_split_rand_weight_balanced() {
+        let weights: Vec<f64> = vec![50.0, 40.0, 30.0, 20.0, 10.0, 5.0, 4.0, 3.0, 2.0, 1.0, 1.0, 1.0];
+        let spread = |totals: &[f64]| {
+            let max = totals.iter().cloned().fold(f64::MIN, f64::max);
+            let min = totals.iter().cloned().fold(f64::MAX, f64::min);
+            max - min
+        };
+        let naive: Vec<f64> = (0..3).map(|p| weights.iter().skip(p).step_by(3).sum()).collect();
+        for _ in 0..50 {
+            let (parts, totals) = split_rand_weight_balanced(weights.clone(), 3, |w| *w);
+            assert_eq!(parts.iter().map(Vec::len).collect::<Vec<_>>(), vec![4, 4, 4]);
+            for (part, total) in parts.iter().zip(&totals) {
+                assert_eq!(part.iter().sum::<f64>(), *total);
+            }
+            assert!(spread(&totals) <= spread(&naive), "spread {:?} is worse than {:?}", totals, naive);
+        }
+    }
+
+    #[test]
+    fn test_split_rand_weight_balanced_ties_are_random() {
+        let first_parts: HashSet<Vec<u32>> = (0..50)
+            .map(|_| {
+                let (parts, _) = split_rand_weight_balanced((0..6).collect::<Vec<u32>>(), 2, |_| 1.0);
+                let mut first = parts[0].clone();
+                first.sort();
+                first
+            })
+            .collect();
+        assert!(first_parts.len() > 1);
+    }
+
+    #[test]
+    fn test_split_rand_grouped() {
+        let mut rows: Vec<(u32, u32)> = (0..6).map(|i| (0, i)).collect();
+        rows.extend((1..=6).map(|k| (k, 100 + k)));
+        for _ in 0..20 {
+            let (parts, assignment) = split_rand_grouped(rows.clone(), 3, |row| row.0);
+            assert_eq!(assignment.len(), 7);
+            for row in &rows {
+                assert!(parts[assignment[&row.0]].contains(row));
+            }
+            let mut sizes: Vec<usize> = parts.iter().map(|p| p.len()).collect();
+            sizes.sort();
+            assert_eq!(sizes, vec![3, 3, 6]);
+            let mut all = parts.concat();
+            all.sort();
+            assert_eq!(all, rows);
+        }
+    }
+
+    #[test]
+    fn test_split_functions_without_clone() {
+        #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        struct Task(u32);
+
+        let chunked = split_rand_vec((0..8).map(Task).collect(), 3);
+        assert_eq!(chunked.iter().map(|c| c.len()).collect::<Vec<_>>(), vec![3, 3, 2]);
+
+        let parts = split_rand_vec_eq((0..7).map(Task).collect(), 3);
+        assert_eq!(sorted_sizes(&parts), vec![2, 2, 3]);
+        let mut ids: Vec<u32> = parts.into_iter().flatten().map(|t| t.0).collect();
+        ids.sort();
+        assert_eq!(ids, (0..7).collect::<Vec<_>>());
+
+        let set: HashSet<Task> = (0..7).map(Task).collect();
+        let parts = split_rand_hashset_eq(set, 3);
+        assert_eq!(sorted_sizes(&parts), vec![2, 2, 3]);
+
+        let boxed: Vec<Box<dyn Fn() -> u32>> = vec![Box::new(|| 1), Box::new(|| 2), Box::new(|| 3)];
+        let chunked = split_rand_vec(boxed, 2);
+        assert_eq!(chunked.iter().flatten().map(|f| f()).sum::<u32>(), 6);
+    }
+
+    #[test]
+    fn test_split_hashset_eq() {
+        let vec: HashSet<i32> = [1, 2, 3, 4, 5, 6, 7].iter().cloned().collect();
+        let chunked = split_rand_hashset_eq(vec, 3);
+        assert_eq!(chunked.len(), 3);
+        assert_eq!(sorted_sizes(&chunked), vec![2, 2, 3]);
+    }
+
+    #[test]
+    fn test_try_split_rand_vec_eq() {
+        assert_eq!(try_split_rand_vec_eq(vec![1, 2, 3], 0), Err(SplitError::ZeroParts));
+
+        let single = try_split_rand_vec_eq(vec![1, 2, 3], 1).unwrap();
+        assert_eq!(single.len(), 1);
+        let mut all = single[0].clone();
+        all.sort();
+        assert_eq!(all, vec![1, 2, 3]);
+
+        let empty = try_split_rand_vec_eq(Vec::<i32>::new(), 3).unwrap();
+        assert_eq!(empty.len(), 3);
+        assert!(empty.iter().all(|part| part.is_empty()));
+    }
+
+    #[test]
+    fn test_try_split_hashset_eq() {
+        let set: HashSet<i32> = (1..=4).collect();
+        assert_eq!(try_split_rand_hashset_eq(set.clone(), 0), Err(SplitError::ZeroParts));
+        assert_eq!(try_split_rand_hashset_eq(set, 1).unwrap()[0].len(), 4);
+
+        let empty = try_split_rand_hashset_eq(HashSet::<i32>::new(), 2).unwrap();
+        assert_eq!(empty, vec![Vec::<i32>::new(), Vec::new()]);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_with_policy() {
+        let allowed = split_rand_vec_eq_with_policy(vec![1, 2], 5, EmptyPartsPolicy::Allow).unwrap();
+        assert_eq!(allowed.len(), 5);
+        assert_eq!(allowed.iter().filter(|p| p.is_empty()).count(), 3);
+
+        let err = split_rand_vec_eq_with_policy(vec![1, 2], 5, EmptyPartsPolicy::Error);
+        assert_eq!(err, Err(SplitError::TooManyParts { parts: 5, len: 2 }));
+        let ok = split_rand_vec_eq_with_policy(vec![1, 2], 2, EmptyPartsPolicy::Error).unwrap();
+        assert!(ok.iter().all(|p| p.len() == 1));
+
+        let shrunk = split_rand_vec_eq_with_policy(vec![1, 2], 5, EmptyPartsPolicy::ShrinkToLen).unwrap();
+        assert_eq!(shrunk.len(), 2);
+        assert!(shrunk.iter().all(|p| p.len() == 1));
+        let none = split_rand_vec_eq_with_policy(Vec::<i32>::new(), 3, EmptyPartsPolicy::ShrinkToLen).unwrap();
+        assert!(none.is_empty());
+
+        for policy in [EmptyPartsPolicy::Allow, EmptyPartsPolicy::Error, EmptyPartsPolicy::ShrinkToLen] {
+            assert_eq!(split_rand_vec_eq_with_policy(vec![1], 0, policy), Err(SplitError::ZeroParts));
+        }
+        assert_eq!(EmptyPartsPolicy::default(), EmptyPartsPolicy::Allow);
+    }
+
+    #[test]
+    fn test_split_rand_vec_with_rng() {
+        let vec: Vec<i32> = (0..10).collect();
+        let first = split_rand_vec_with_rng(vec.clone(), 4, &mut StdRng::seed_from_u64(5));
+        let second = split_rand_vec_with_rng(vec, 4, &mut StdRng::seed_from_u64(5));
+        assert_eq!(first, second);
+        assert_eq!(first.iter().map(|c| c.len()).collect::<Vec<_>>(), vec![4, 4, 2]);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_with_rng() {
+        let vec: Vec<i32> = (0..10).collect();
+        let mut rng = StdRng::seed_from_u64(5);
+        let first = split_rand_vec_eq_with_rng(vec.clone(), 3, &mut rng);
+        assert_eq!(sorted_sizes(&first), vec![3, 3, 4]);
+        assert_eq!(split_rand_vec_eq_seeded(vec, 3, 5), first);
+    }
+
+    #[test]
+    fn test_split_hashset_eq_with_rng() {
+        let set: HashSet<i32> = (0..10).collect();
+        let chunked = split_rand_hashset_eq_with_rng(set, 3, &mut StdRng::seed_from_u64(5));
+        let mut all: Vec<i32> = chunked.concat();
+        all.sort();
+        assert_eq!(all, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_sized_splits_with_rng_are_deterministic() {
+        let vec: Vec<i32> = (0..10).collect();
+        let run = |seed| {
+            let rng = &mut StdRng::seed_from_u64(seed);
+            (
+                split_rand_vec_eq_with_policy_with_rng(vec.clone(), 3, EmptyPartsPolicy::Error, rng).unwrap(),
+                split_rand_vec_with_strategy_with_rng(vec.clone(), 3, RemainderStrategy::Distribute, rng).unwrap(),
+                split_rand_vec_eq_contiguous_with_rng(vec.clone(), 3, rng),
+                split_rand_sizes_with_rng(vec.clone(), &[5, 5], rng).unwrap(),
+                split_rand_sizes_lenient_with_rng(vec.clone(), &[5], rng).unwrap(),
+            )
+        };
+        assert_eq!(run(8), run(8));
+        let (policy, strategy, contiguous, sizes, lenient) = run(8);
+        assert_eq!(sorted_sizes(&policy), vec![3, 3, 4]);
+        assert_eq!(sorted_sizes(&strategy), vec![3, 3, 4]);
+        assert_eq!(sorted_sizes(&contiguous), vec![3, 3, 4]);
+        assert_eq!(sorted_sizes(&sizes), vec![5, 5]);
+        assert_eq!(sorted_sizes(&lenient), vec![5, 5]);
+    }
+
+    #[test]
+    fn test_split_rand_vec_seeded() {
+        let vec: Vec<i32> = (0..20).collect();
+        let first = split_rand_vec_seeded(vec.clone(), 3, 42);
+        let second = split_rand_vec_seeded(vec.clone(), 3, 42);
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 7);
+        assert_ne!(first, split_rand_vec_seeded(vec, 3, 43));
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_seeded() {
+        let vec: Vec<String> = (0..20).map(|i| format!("student{}", i)).collect();
+        let first = split_rand_vec_eq_seeded(vec.clone(), 3, 42);
+        let second = split_rand_vec_eq_seeded(vec.clone(), 3, 42);
+        assert_eq!(first, second);
+        assert_eq!(sorted_sizes(&first), vec![6, 7, 7]);
+        assert_ne!(first, split_rand_vec_eq_seeded(vec, 3, 43));
+    }
+
+    #[test]
+    fn test_split_hashset_eq_seeded() {
+        let set: HashSet<i32> = (0..20).collect();
+        let other: HashSet<i32> = (0..20).rev().collect();
+        let first = split_rand_hashset_eq_seeded(set.clone(), 3, 42);
+        assert_eq!(first, split_rand_hashset_eq_seeded(other, 3, 42));
+        assert_ne!(first, split_rand_hashset_eq_seeded(set, 3, 43));
+    }
+
+    #[test]
+    fn test_split_rand_map_eq() {
+        struct Participant(u32);
+        let map: HashMap<u32, Participant> = (0..7).map(|i| (i, Participant(i * 10))).collect();
+        let parts = split_rand_map_eq(map, 3);
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![3, 2, 2]);
+        assert!(parts.iter().flatten().all(|(k, v)| v.0 == k * 10));
+        let mut keys: Vec<u32> = parts.iter().flatten().map(|(k, _)| *k).collect();
+        keys.sort();
+        assert_eq!(keys, (0..7).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_split_rand_map_eq_into_maps() {
+        let map: HashMap<u32, String> = (0..10).map(|i| (i, i.to_string())).collect();
+        let maps = split_rand_map_eq_into_maps(map, 4);
+        assert_eq!(maps.iter().map(|m| m.len()).collect::<Vec<_>>(), vec![3, 3, 2, 2]);
+        let merged: HashMap<u32, String> = maps.into_iter().flatten().collect();
+        assert_eq!(merged.len(), 10);
+        assert!(merged.iter().all(|(k, v)| *v == k.to_string()));
+    }
+
+    #[test]
+    fn test_split_rand_eq() {
+        let parts = split_rand_eq((0..10).map(|i| i * 2), 4);
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![3, 3, 2, 2]);
+        let mut all = parts.concat();
+        all.sort();
+        assert_eq!(all, (0..10).map(|i| i * 2).collect::<Vec<_>>());
+        assert_eq!(split_rand_eq(std::iter::empty::<i32>(), 2), vec![Vec::<i32>::new(), Vec::new()]);
+
+        let sizes = |parts: &Vec<Vec<i32>>| parts.iter().map(|p| p.len()).collect::<Vec<_>>();
+        assert_eq!(sizes(&split_rand_eq(vec![1, 2, 3, 4, 5], 2)), vec![3, 2]);
+        let set: HashSet<i32> = (1..=5).collect();
+        assert_eq!(sizes(&split_rand_eq(set, 2)), vec![3, 2]);
+        let map: HashMap<&str, i32> = [("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5)].into_iter().collect();
+        let parts: Vec<Vec<i32>> = split_rand_eq(map.values().copied(), 2);
+        assert_eq!(sizes(&parts), vec![3, 2]);
+        assert_eq!(parts.concat().iter().sum::<i32>(), 15);
+    }
+
+    #[test]
+    fn test_try_split_rand_eq() {
+        assert_eq!(try_split_rand_eq(vec![1, 2], 0), Err(SplitError::ZeroParts));
+        assert_eq!(try_split_rand_eq(HashSet::from([1, 2]), 0), Err(SplitError::ZeroParts));
+        assert_eq!(try_split_rand_eq(HashMap::from([(1, 2)]).into_values(), 0), Err(SplitError::ZeroParts));
+        assert_eq!(try_split_rand_eq(vec![1, 2], 2).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_try_variants_of_rng_splits() {
+        let mut rng = StdRng::seed_from_u64(3);
+        assert_eq!(try_split_rand_vec_with_rng(vec![1, 2], 0, &mut rng), Err(SplitError::ZeroChunkSize));
+        assert_eq!(try_split_rand_vec_eq_with_rng(vec![1, 2], 0, &mut rng), Err(SplitError::ZeroParts));
+        assert_eq!(try_split_rand_stream_eq(0..2, 0, &mut rng), Err(SplitError::ZeroParts));
+        assert_eq!(try_split_rand_vec_eq_contiguous(vec![1, 2], 0), Err(SplitError::ZeroParts));
+        let set: HashSet<i32> = (0..5).collect();
+        assert_eq!(try_split_rand_hashset_eq_with_rng(set.clone(), 0, &mut rng), Err(SplitError::ZeroParts));
+        assert_eq!(try_split_rand_hashset_eq_seeded(set.clone(), 0, 3), Err(SplitError::ZeroParts));
+
+        let vec: Vec<i32> = (0..10).collect();
+        let seeded = |seed| StdRng::seed_from_u64(seed);
+        assert_eq!(try_split_rand_vec_with_rng(vec.clone(), 4, &mut seeded(1)), Ok(split_rand_vec_seeded(vec.clone(), 4, 1)));
+        assert_eq!(try_split_rand_vec_eq_with_rng(vec.clone(), 3, &mut seeded(1)), Ok(split_rand_vec_eq_seeded(vec.clone(), 3, 1)));
+        assert_eq!(try_split_rand_hashset_eq_seeded(set.clone(), 2, 1), Ok(split_rand_hashset_eq_seeded(set, 2, 1)));
+        assert_eq!(try_split_rand_vec_eq_contiguous(vec.clone(), 4).unwrap()[0].len(), 3);
+        assert_eq!(try_split_rand_stream_eq(vec.into_iter(), 3, &mut rng).unwrap().concat().len(), 10);
+    }
+
+    #[test]
+    fn test_try_split_rand_stratified() {
+        let rows = vec![("a", 1), ("a", 2), ("b", 3), ("b", 4)];
+        assert_eq!(try_split_rand_stratified(rows.clone(), 0, |row| row.0), Err(SplitError::ZeroParts));
+        let parts = try_split_rand_stratified(rows, 2, |row| row.0).unwrap();
+        assert!(parts.iter().all(|part| part.iter().filter(|row| row.0 == "a").count() == 1));
+    }
+
+    #[test]
+    fn test_try_split_rand_grouped() {
+        let rows = vec![("a", 1), ("a", 2), ("b", 3), ("b", 4)];
+        assert_eq!(try_split_rand_grouped(rows.clone(), 0, |row| row.0), Err(SplitError::ZeroParts));
+        let (parts, assignment) = try_split_rand_grouped(rows, 2, |row| row.0).unwrap();
+        assert_eq!(sorted_sizes(&parts), vec![2, 2]);
+        assert_ne!(assignment["a"], assignment["b"]);
+    }
+
+    #[test]
+    fn test_try_split_rand_balanced_by() {
+        let rows = vec![("a", 1), ("a", 2), ("b", 3), ("b", 4)];
+        assert_eq!(try_split_rand_balanced_by(rows.clone(), 0, |row| row.0), Err(SplitError::ZeroParts));
+        let (_, tallies) = try_split_rand_balanced_by(rows, 2, |row| row.0).unwrap();
+        assert!(tallies.iter().all(|tally| tally["a"] == 1 && tally["b"] == 1));
+    }
+
+    #[test]
+    fn test_try_split_rand_weight_balanced() {
+        let rows = vec![("a", 1), ("a", 2), ("b", 3), ("b", 4)];
+        assert_eq!(try_split_rand_weight_balanced(rows.clone(), 0, |row| row.1 as f64), Err(SplitError::ZeroParts));
+        let (_, totals) = try_split_rand_weight_balanced(rows, 2, |row| row.1 as f64).unwrap();
+        assert_eq!(totals, vec![5.0, 5.0]);
+    }
+
+    #[test]
+    fn test_try_variants_of_in_place_shuffles() {
+        let mut data: Vec<i32> = (0..5).collect();
+        assert_eq!(try_shuffle_partition_in_place(&mut data, 0), Err(SplitError::ZeroParts));
+        assert_eq!(try_shuffle_chunks_in_place(&mut data, 0), Err(SplitError::ZeroChunkSize));
+        assert_eq!(data, (0..5).collect::<Vec<_>>());
+        assert_eq!(try_shuffle_chunks_in_place(&mut data, 2), Ok(vec![0..2, 2..4, 4..5]));
+        assert_eq!(try_shuffle_partition_in_place(&mut data, 5).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_split_rand_btreeset_eq() {
+        let set: BTreeSet<i32> = (0..7).collect();
+        let parts = split_rand_btreeset_eq(set.clone(), 3);
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![3, 2, 2]);
+        assert_eq!(parts.concat().into_iter().collect::<BTreeSet<_>>(), set);
+
+        let empty = split_rand_btreeset_eq(BTreeSet::<i32>::new(), 3);
+        assert!(empty.len() == 3 && empty.iter().all(|p| p.is_empty()));
+    }
+
+    #[test]
+    fn test_split_rand_btreemap_eq() {
+        let map: BTreeMap<u32, char> = (0..5).map(|i| (i, char::from(b'a' + i as u8))).collect();
+        let parts = split_rand_btreemap_eq(map.clone(), 2);
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![3, 2]);
+        assert_eq!(parts.concat().into_iter().collect::<BTreeMap<_, _>>(), map);
+
+        let empty = split_rand_btreemap_eq(BTreeMap::<u32, char>::new(), 2);
+        assert!(empty.len() == 2 && empty.iter().all(|p| p.is_empty()));
+    }
+
+    #[test]
+    fn test_split_rand_slice_eq() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug, PartialEq)]
+        struct Large(String);
+        impl Clone for Large {
+            fn clone(&self) -> Self {
+                CLONES.fetch_add(1, Ordering::SeqCst);
+                Large(self.0.clone())
+            }
+        }
+
+        let items: Vec<Large> = (0..9).map(|i| Large(i.to_string().repeat(100_000))).collect();
+        let parts = split_rand_slice_eq(&items, 4);
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![3, 2, 2, 2]);
+        assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+        let mut found: Vec<&Large> = parts.concat();
+        found.sort_by(|a, b| a.0.cmp(&b.0));
+        assert!(found.iter().zip(&items).all(|(a, b)| std::ptr::eq(*a, b)));
+    }
+
+    #[test]
+    fn test_split_rand_indices() {
+        let groups = split_rand_indices(11, 3);
+        assert_eq!(groups.iter().map(|g| g.len()).collect::<Vec<_>>(), vec![4, 4, 3]);
+        let mut all = groups.concat();
+        all.sort();
+        assert_eq!(all, (0..11).collect::<Vec<_>>());
+        assert_eq!(split_rand_indices(0, 2), vec![Vec::<usize>::new(), Vec::new()]);
+    }
+
+    #[test]
+    fn test_split_rand_indices_chunks() {
+        let chunks = split_rand_indices_chunks(11, 3);
+        assert_eq!(chunks.iter().map(|c| c.len()).collect::<Vec<_>>(), vec![3, 3, 3, 2]);
+        let mut all = chunks.concat();
+        all.sort();
+        assert_eq!(all, (0..11).collect::<Vec<_>>());
+        assert!(split_rand_indices_chunks(0, 3).is_empty());
     }
 
     #[test]
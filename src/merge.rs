@@ -0,0 +1,253 @@
+//! Helpers for merging and rearranging split parts.
+
+use crate::SplitError;
+use alloc::vec::Vec;
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+/// Concatenates parts into a single vector, undoing a split.
+/// # Arguments
+/// * `parts` - The parts to merge.
+///
+/// # Returns
+/// The elements of all parts, in part order and then in element order.
+/// # Example
+/// ```
+/// use rust_helpers::flatten_parts;
+/// assert_eq!(flatten_parts(vec![vec![1, 2], vec![], vec![3]]), vec![1, 2, 3]);
+/// ```
+pub fn flatten_parts<T>(parts: Vec<Vec<T>>) -> Vec<T> {
+    let mut result = Vec::with_capacity(parts.iter().map(Vec::len).sum());
+    for part in parts {
+        result.extend(part);
+    }
+    result
+}
+
+/// Concatenates parts into a single vector and shuffles it.
+/// # Arguments
+/// * `parts` - The parts to merge.
+///
+/// # Returns
+/// The elements of all parts in random order.
+/// # Example
+/// ```
+/// use rust_helpers::flatten_parts_shuffled;
+/// let mut merged = flatten_parts_shuffled(vec![vec![1, 2], vec![3]]);
+/// merged.sort();
+/// assert_eq!(merged, vec![1, 2, 3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn flatten_parts_shuffled<T>(parts: Vec<Vec<T>>) -> Vec<T> {
+    flatten_parts_shuffled_with_rng(parts, &mut rand::rng())
+}
+
+/// Like [`flatten_parts_shuffled`], but shuffles with the given RNG.
+pub fn flatten_parts_shuffled_with_rng<T, R: Rng + ?Sized>(parts: Vec<Vec<T>>, rng: &mut R) -> Vec<T> {
+    let mut result = flatten_parts(parts);
+    result.shuffle(rng);
+    result
+}
+
+/// Merges parts by taking the first element of every part, then the second element of every part, and so on.
+///
+/// This mirrors the round-robin dealing done by [`split_rand_vec_eq`](crate::split_rand_vec_eq) and gives a fair
+/// serving order, for example to alternate questions between teams. Exhausted and empty parts are skipped.
+/// # Arguments
+/// * `parts` - The parts to merge.
+///
+/// # Returns
+/// A vector with all elements of `parts`, interleaved.
+/// # Example
+/// ```
+/// use rust_helpers::interleave_parts;
+/// let parts = vec![vec!['a', 'b', 'c'], vec![], vec!['x', 'y']];
+/// assert_eq!(interleave_parts(parts), vec!['a', 'x', 'b', 'y', 'c']);
+/// ```
+pub fn interleave_parts<T>(parts: Vec<Vec<T>>) -> Vec<T> {
+    let mut result = Vec::with_capacity(parts.iter().map(Vec::len).sum());
+    let mut parts: Vec<alloc::vec::IntoIter<T>> = parts.into_iter().map(Vec::into_iter).collect();
+    while !parts.is_empty() {
+        parts.retain_mut(|part| match part.next() {
+            Some(e) => {
+                result.push(e);
+                true
+            }
+            None => false,
+        });
+    }
+    result
+}
+
+/// Transposes parts, so that row `i` of the result holds the `i`-th element of every part that has one.
+///
+/// This turns, for example, three teams of four into four rounds of three. With parts of unequal size the trailing
+/// rows are shorter; use [`transpose_parts_padded`] to keep every row as long as there are parts.
+/// # Arguments
+/// * `parts` - The parts to transpose.
+///
+/// # Returns
+/// As many rows as the longest part has elements. Each row keeps the order of the parts.
+/// # Example
+/// ```
+/// use rust_helpers::transpose_parts;
+/// let teams = vec![vec!["Ann", "Ben"], vec!["Cem", "Dana"], vec!["Eli"]];
+/// assert_eq!(transpose_parts(teams), vec![vec!["Ann", "Cem", "Eli"], vec!["Ben", "Dana"]]);
+/// ```
+pub fn transpose_parts<T>(parts: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    transpose_parts_padded(parts).into_iter().map(|row| row.into_iter().flatten().collect()).collect()
+}
+
+/// Transposes parts like [`transpose_parts`], padding missing elements with `None`.
+/// # Arguments
+/// * `parts` - The parts to transpose.
+///
+/// # Returns
+/// As many rows as the longest part has elements, each with one entry per part. The entry is `None` where the part
+/// has no element at that position.
+/// # Example
+/// ```
+/// use rust_helpers::transpose_parts_padded;
+/// let teams = vec![vec![1, 2], vec![3]];
+/// assert_eq!(transpose_parts_padded(teams), vec![vec![Some(1), Some(3)], vec![Some(2), None]]);
+/// ```
+pub fn transpose_parts_padded<T>(parts: Vec<Vec<T>>) -> Vec<Vec<Option<T>>> {
+    let rows = parts.iter().map(Vec::len).max().unwrap_or(0);
+    let mut result: Vec<Vec<Option<T>>> = (0..rows).map(|_| Vec::with_capacity(parts.len())).collect();
+    for part in parts {
+        let mut elements = part.into_iter();
+        for row in result.iter_mut() {
+            row.push(elements.next());
+        }
+    }
+    result
+}
+
+/// Restores the original order of a split made with [`split_rand_vec_eq_indexed`](crate::split_rand_vec_eq_indexed).
+/// # Arguments
+/// * `parts` - Parts of `(index, element)` pairs, where `index` is the original position of the element.
+///
+/// # Returns
+/// The elements in the order of their indices, or an error if an index is not smaller than the total number of
+/// elements ([`SplitError::IndexOutOfRange`]) or occurs twice ([`SplitError::DuplicateIndex`]). Since there are as
+/// many indices as elements, a missing index always shows up as one of these two errors.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::{flatten_parts_indexed, split_rand_vec_eq_indexed_with_rng};
+/// let vec = vec!['a', 'b', 'c', 'd', 'e'];
+/// let parts = split_rand_vec_eq_indexed_with_rng(vec.clone(), 2, &mut StdRng::seed_from_u64(1));
+/// assert_eq!(flatten_parts_indexed(parts), Ok(vec));
+/// ```
+pub fn flatten_parts_indexed<T>(parts: Vec<Vec<(usize, T)>>) -> Result<Vec<T>, SplitError> {
+    let len = parts.iter().map(Vec::len).sum();
+    let mut slots: Vec<Option<T>> = (0..len).map(|_| None).collect();
+    for (index, e) in parts.into_iter().flatten() {
+        match slots.get_mut(index) {
+            None => return Err(SplitError::IndexOutOfRange { index, len }),
+            Some(Some(_)) => return Err(SplitError::DuplicateIndex { index }),
+            Some(slot) => *slot = Some(e),
+        }
+    }
+    // Every slot is filled: there are exactly `len` in-range indices and none of them repeats.
+    Ok(slots.into_iter().map(|slot| slot.expect("every index occurs once")).collect())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{split_rand_vec_eq, split_rand_vec_eq_indexed};
+
+    #[test]
+    fn test_flatten_parts() {
+        assert_eq!(flatten_parts(vec![vec!['a'], vec!['b', 'c'], vec![]]), vec!['a', 'b', 'c']);
+        assert!(flatten_parts(Vec::<Vec<i32>>::new()).is_empty());
+        let mut merged = flatten_parts(split_rand_vec_eq((0..10).collect(), 3));
+        merged.sort();
+        assert_eq!(merged, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_flatten_parts_shuffled() {
+        let parts: Vec<Vec<i32>> = vec![(0..25).collect(), (25..50).collect()];
+        let merged = flatten_parts_shuffled(parts);
+        assert_ne!(merged, (0..50).collect::<Vec<_>>());
+        let mut sorted = merged;
+        sorted.sort();
+        assert_eq!(sorted, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_interleave_parts() {
+        assert_eq!(interleave_parts(vec![vec![1, 4], vec![2, 5], vec![3]]), vec![1, 2, 3, 4, 5]);
+        assert_eq!(interleave_parts(vec![vec![], vec![1], vec![], vec![2, 3, 4]]), vec![1, 2, 3, 4]);
+        assert!(interleave_parts(vec![Vec::<i32>::new(), Vec::new()]).is_empty());
+        assert!(interleave_parts(Vec::<Vec<i32>>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_interleave_parts_of_split() {
+        for len in 0..20 {
+            for parts in 1..6 {
+                let split = split_rand_vec_eq((0..len).collect::<Vec<i32>>(), parts);
+                let total: usize = split.iter().map(Vec::len).sum();
+                let mut merged = interleave_parts(split);
+                assert_eq!(merged.len(), total);
+                merged.sort();
+                assert_eq!(merged, (0..len).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn test_transpose_parts() {
+        let teams: Vec<Vec<i32>> = vec![(0..4).collect(), (4..8).collect(), (8..12).collect()];
+        let rounds = transpose_parts(teams.clone());
+        assert_eq!(rounds, vec![vec![0, 4, 8], vec![1, 5, 9], vec![2, 6, 10], vec![3, 7, 11]]);
+        assert_eq!(transpose_parts(rounds), teams);
+    }
+
+    #[test]
+    fn test_transpose_parts_ragged() {
+        let parts = vec![vec![1], vec![2, 3, 4], vec![], vec![5, 6]];
+        assert_eq!(transpose_parts(parts.clone()), vec![vec![1, 2, 5], vec![3, 6], vec![4]]);
+        assert_eq!(
+            transpose_parts_padded(parts),
+            vec![
+                vec![Some(1), Some(2), None, Some(5)],
+                vec![None, Some(3), None, Some(6)],
+                vec![None, Some(4), None, None],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transpose_parts_empty() {
+        assert!(transpose_parts(Vec::<Vec<i32>>::new()).is_empty());
+        assert!(transpose_parts(vec![Vec::<i32>::new(), Vec::new()]).is_empty());
+        assert!(transpose_parts_padded(vec![Vec::<i32>::new()]).is_empty());
+    }
+
+    #[test]
+    fn test_flatten_parts_indexed_round_trip() {
+        for parts in 1..6 {
+            let vec: Vec<String> = (0..17).map(|i| format!("item {}", i)).collect();
+            let split = split_rand_vec_eq_indexed(vec.clone(), parts);
+            assert_eq!(flatten_parts_indexed(split), Ok(vec));
+        }
+        assert_eq!(flatten_parts_indexed(split_rand_vec_eq_indexed(Vec::<u8>::new(), 3)), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_flatten_parts_indexed_errors() {
+        assert_eq!(
+            flatten_parts_indexed(vec![vec![(0, 'a'), (1, 'b')], vec![(1, 'c')]]),
+            Err(SplitError::DuplicateIndex { index: 1 })
+        );
+        assert_eq!(
+            flatten_parts_indexed(vec![vec![(0, 'a')], vec![(5, 'c')]]),
+            Err(SplitError::IndexOutOfRange { index: 5, len: 2 })
+        );
+    }
+}
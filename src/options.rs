@@ -0,0 +1,158 @@
+//! Plain-data split configuration and the [`split`] entry point.
+
+use crate::RemainderStrategy;
+#[cfg(feature = "std")]
+use crate::{EmptyPartsPolicy, RandomSplitter, SplitError};
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
+/// What a [`SplitOptions`] splits by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SplitMode {
+    /// Split into this many parts of equal size, as in [`split_rand_vec_eq`](crate::split_rand_vec_eq).
+    Parts(usize),
+    /// Split into chunks of this size, as in [`split_rand_vec`](crate::split_rand_vec).
+    ChunkSize(usize),
+}
+
+impl Default for SplitMode {
+    fn default() -> Self {
+        SplitMode::Parts(2)
+    }
+}
+
+/// Configuration for [`split`].
+///
+/// Unlike [`RandomSplitter`] this is a plain struct with public fields, so it can be filled in from a
+/// configuration file or command line flags. With the `serde` feature it can also be serialized to record the
+/// exact configuration used for a split.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use rust_helpers::{split, SplitMode, SplitOptions};
+/// let opts = SplitOptions { mode: SplitMode::ChunkSize(3), seed: Some(42), ..Default::default() };
+/// let chunks = split((0..10).collect::<Vec<i32>>(), &opts).unwrap();
+/// assert_eq!(chunks.len(), 4);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplitOptions {
+    /// Whether to split into a number of parts or into chunks of a size. Defaults to two parts.
+    pub mode: SplitMode,
+    /// Seed for the shuffle. `None` uses the thread RNG.
+    pub seed: Option<u64>,
+    /// How leftover elements are handled in [`SplitMode::ChunkSize`] mode.
+    pub remainder: RemainderStrategy,
+    /// Whether [`SplitMode::Parts`] may produce empty parts when there are fewer elements than parts.
+    pub allow_empty_parts: bool,
+}
+
+impl Default for SplitOptions {
+    fn default() -> Self {
+        SplitOptions { mode: SplitMode::default(), seed: None, remainder: RemainderStrategy::default(), allow_empty_parts: true }
+    }
+}
+
+/// Randomly splits a vector as described by `opts`.
+///
+/// Produces the same parts as the specialized function for the chosen mode, e.g.
+/// [`split_rand_vec_eq_seeded`](crate::split_rand_vec_eq_seeded) for [`SplitMode::Parts`] with a seed.
+///
+/// # Arguments
+/// * `vec` - A vector of elements to be split.
+/// * `opts` - The split configuration.
+///
+/// # Returns
+/// The parts, or a [`SplitError`] if the split described by `opts` is invalid for `vec`.
+///
+/// # Example
+/// ```
+/// use rust_helpers::{split, split_rand_vec_eq_seeded, SplitMode, SplitOptions};
+/// let opts = SplitOptions { mode: SplitMode::Parts(3), seed: Some(7), ..Default::default() };
+/// let vec: Vec<i32> = (0..10).collect();
+/// assert_eq!(split(vec.clone(), &opts).unwrap(), split_rand_vec_eq_seeded(vec, 3, 7));
+/// ```
+#[cfg(feature = "std")]
+pub fn split<T>(vec: Vec<T>, opts: &SplitOptions) -> Result<Vec<Vec<T>>, SplitError> {
+    let mut splitter = match opts.mode {
+        SplitMode::Parts(parts) => RandomSplitter::new().parts(parts),
+        SplitMode::ChunkSize(chunk_size) => RandomSplitter::new().chunk_size(chunk_size),
+    };
+    splitter = splitter
+        .remainder(opts.remainder)
+        .empty_parts(if opts.allow_empty_parts { EmptyPartsPolicy::Allow } else { EmptyPartsPolicy::Error });
+    if let Some(seed) = opts.seed {
+        splitter = splitter.seed(seed);
+    }
+    splitter.split(vec).map(Vec::from)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{split_rand_vec_eq_seeded, split_rand_vec_seeded, split_rand_vec_with_strategy};
+
+    fn data() -> Vec<i32> {
+        (0..10).collect()
+    }
+
+    #[test]
+    fn test_split_defaults() {
+        let opts = SplitOptions::default();
+        assert_eq!(opts.mode, SplitMode::Parts(2));
+        assert!(opts.allow_empty_parts);
+        let parts = split(data(), &opts).unwrap();
+        assert_eq!(parts.iter().map(Vec::len).collect::<Vec<_>>(), vec![5, 5]);
+    }
+
+    #[test]
+    fn test_split_matches_specialized_functions() {
+        let opts = SplitOptions { mode: SplitMode::Parts(3), seed: Some(11), ..Default::default() };
+        assert_eq!(split(data(), &opts).unwrap(), split_rand_vec_eq_seeded(data(), 3, 11));
+
+        let opts = SplitOptions { mode: SplitMode::ChunkSize(4), seed: Some(11), ..Default::default() };
+        assert_eq!(split(data(), &opts).unwrap(), split_rand_vec_seeded(data(), 4, 11));
+
+        let opts = SplitOptions { mode: SplitMode::ChunkSize(4), remainder: RemainderStrategy::DropRemainder, ..Default::default() };
+        let dropped = split(data(), &opts).unwrap();
+        let expected = split_rand_vec_with_strategy(data(), 4, RemainderStrategy::DropRemainder).unwrap();
+        assert_eq!(dropped.len(), expected.len());
+        assert!(dropped.iter().all(|chunk| chunk.len() == 4));
+    }
+
+    #[test]
+    fn test_split_empty_parts() {
+        let opts = SplitOptions { mode: SplitMode::Parts(4), ..Default::default() };
+        assert_eq!(split(vec![1, 2], &opts).unwrap().len(), 4);
+        let opts = SplitOptions { allow_empty_parts: false, ..opts };
+        assert_eq!(split(vec![1, 2], &opts), Err(SplitError::TooManyParts { parts: 4, len: 2 }));
+    }
+
+    #[test]
+    fn test_split_errors() {
+        let opts = SplitOptions { mode: SplitMode::Parts(0), ..Default::default() };
+        assert_eq!(split(data(), &opts), Err(SplitError::ZeroParts));
+        let opts = SplitOptions { mode: SplitMode::ChunkSize(0), ..Default::default() };
+        assert_eq!(split(data(), &opts), Err(SplitError::ZeroChunkSize));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_split_options_serde_round_trip() {
+        let opts = SplitOptions {
+            mode: SplitMode::ChunkSize(3),
+            seed: Some(42),
+            remainder: RemainderStrategy::Distribute,
+            allow_empty_parts: false,
+        };
+        let json = serde_json::to_string(&opts).unwrap();
+        assert_eq!(serde_json::from_str::<SplitOptions>(&json).unwrap(), opts);
+        for remainder in [RemainderStrategy::LastSmaller, RemainderStrategy::DropRemainder, RemainderStrategy::Strict] {
+            let json = serde_json::to_string(&remainder).unwrap();
+            assert_eq!(serde_json::from_str::<RemainderStrategy>(&json).unwrap(), remainder);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Parallel versions of the split functions for very large vectors.
+//!
+//! Every element gets a random `u64` key, generated in parallel from per-chunk RNGs seeded by the caller's RNG. The
+//! elements are then sorted by key in parallel and moved into the parts without cloning. The permutation is
+//! uniformly random unless two keys tie, which for `n` elements happens with a probability of about `n² / 2^65`;
+//! tied elements end up in an arbitrary order. The results differ from the sequential functions for the same seed,
+//! but the part sizes follow the same rules.
+//!
+//! Sorting does more work than the sequential Fisher-Yates shuffle, so the parallel functions only pay off with
+//! several cores. On a single core, splitting 10 million `u64` into 8 parts took 0.77 s with
+//! [`par_split_rand_vec_eq_with_rng`] against 0.21 s with
+//! [`split_rand_vec_eq_with_rng`](crate::split_rand_vec_eq_with_rng) (best of six release runs). The speedup on
+//! several cores has not been measured, so benchmark on the target machine before switching.
+
+use crate::{chunk_sizes, random_eq_part_sizes, RemainderStrategy, SplitError};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
+
+/// The number of keys generated from one per-chunk RNG.
+const KEY_CHUNK: usize = 1 << 16;
+
+/// Like [`split_rand_vec`](crate::split_rand_vec), but shuffles in parallel.
+/// # Panics
+/// Panics if `chunk_size` is 0. Use [`try_par_split_rand_vec`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::par_split_rand_vec;
+/// let chunked = par_split_rand_vec((0..8).collect(), 3);
+/// assert_eq!(chunked.iter().map(Vec::len).collect::<Vec<_>>(), vec![3, 3, 2]);
+/// ```
+pub fn par_split_rand_vec<T: Send>(vec: Vec<T>, chunk_size: usize) -> Vec<Vec<T>> {
+    par_split_rand_vec_with_rng(vec, chunk_size, &mut rand::rng())
+}
+
+/// Like [`par_split_rand_vec`], but seeds the parallel shuffle from the given RNG.
+pub fn par_split_rand_vec_with_rng<T: Send, R: Rng + ?Sized>(
+    vec: Vec<T>,
+    chunk_size: usize,
+    rng: &mut R,
+) -> Vec<Vec<T>> {
+    assert!(chunk_size > 0, "chunk size must be greater than zero");
+    let sizes = chunk_sizes(vec.len(), chunk_size, RemainderStrategy::LastSmaller).unwrap_or_default();
+    carve(par_shuffle(vec, rng), &sizes)
+}
+
+/// Like [`par_split_rand_vec`], but returns [`SplitError::ZeroChunkSize`] instead of panicking if `chunk_size` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_par_split_rand_vec, SplitError};
+/// assert_eq!(try_par_split_rand_vec(vec![1, 2, 3], 0), Err(SplitError::ZeroChunkSize));
+/// assert_eq!(try_par_split_rand_vec(vec![1, 2, 3], 2).unwrap().len(), 2);
+/// ```
+pub fn try_par_split_rand_vec<T: Send>(vec: Vec<T>, chunk_size: usize) -> Result<Vec<Vec<T>>, SplitError> {
+    if chunk_size == 0 {
+        return Err(SplitError::ZeroChunkSize);
+    }
+    Ok(par_split_rand_vec(vec, chunk_size))
+}
+
+/// Like [`split_rand_vec_eq`](crate::split_rand_vec_eq), but shuffles in parallel.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_par_split_rand_vec_eq`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::par_split_rand_vec_eq;
+/// let parts = par_split_rand_vec_eq((0..7).collect(), 3);
+/// let mut sizes: Vec<usize> = parts.iter().map(Vec::len).collect();
+/// sizes.sort();
+/// assert_eq!(sizes, vec![2, 2, 3]);
+/// ```
+pub fn par_split_rand_vec_eq<T: Send>(vec: Vec<T>, parts: usize) -> Vec<Vec<T>> {
+    par_split_rand_vec_eq_with_rng(vec, parts, &mut rand::rng())
+}
+
+/// Like [`par_split_rand_vec_eq`], but seeds the parallel shuffle from the given RNG.
+pub fn par_split_rand_vec_eq_with_rng<T: Send, R: Rng + ?Sized>(vec: Vec<T>, parts: usize, rng: &mut R) -> Vec<Vec<T>> {
+    assert!(parts > 0, "number of parts must be greater than zero");
+    let sizes = random_eq_part_sizes(vec.len(), parts, rng);
+    carve(par_shuffle(vec, rng), &sizes)
+}
+
+/// Like [`par_split_rand_vec_eq`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_par_split_rand_vec_eq, SplitError};
+/// assert_eq!(try_par_split_rand_vec_eq(vec![1, 2, 3], 0), Err(SplitError::ZeroParts));
+/// assert_eq!(try_par_split_rand_vec_eq(vec![1, 2, 3], 2).unwrap().len(), 2);
+/// ```
+pub fn try_par_split_rand_vec_eq<T: Send>(vec: Vec<T>, parts: usize) -> Result<Vec<Vec<T>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(par_split_rand_vec_eq(vec, parts))
+}
+
+/// Shuffles `vec` by sorting it on random keys generated in parallel.
+fn par_shuffle<T: Send, R: Rng + ?Sized>(vec: Vec<T>, rng: &mut R) -> Vec<T> {
+    let seeds: Vec<u64> = (0..vec.len().div_ceil(KEY_CHUNK)).map(|_| rng.random()).collect();
+    let mut keys = vec![0_u64; vec.len()];
+    keys.par_chunks_mut(KEY_CHUNK).zip(seeds).for_each(|(chunk, seed)| {
+        let mut chunk_rng = StdRng::seed_from_u64(seed);
+        chunk.iter_mut().for_each(|key| *key = chunk_rng.random());
+    });
+    let mut keyed: Vec<(u64, T)> = keys.into_par_iter().zip(vec).collect();
+    keyed.par_sort_unstable_by_key(|(key, _)| *key);
+    keyed.into_par_iter().map(|(_, e)| e).collect()
+}
+
+/// Moves consecutive runs of `sizes` elements out of `vec`.
+fn carve<T>(vec: Vec<T>, sizes: &[usize]) -> Vec<Vec<T>> {
+    let mut elements = vec.into_iter();
+    sizes.iter().map(|&size| elements.by_ref().take(size).collect()).collect()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_split_rand_vec_eq_million_elements() {
+        let n = 1_000_000_u32;
+        let parts = par_split_rand_vec_eq((0..n).collect(), 7);
+        let mut sizes: Vec<usize> = parts.iter().map(Vec::len).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![142857, 142857, 142857, 142857, 142857, 142857, 142858]);
+        let mut all: Vec<u32> = parts.into_iter().flatten().collect();
+        all.sort();
+        assert!(all.into_iter().eq(0..n));
+    }
+
+    #[test]
+    fn test_par_split_rand_vec_million_elements() {
+        let n = 1_000_000_u32;
+        let chunks = par_split_rand_vec((0..n).collect(), 300_000);
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), vec![300_000, 300_000, 300_000, 100_000]);
+        let mut all: Vec<u32> = chunks.into_iter().flatten().collect();
+        all.sort();
+        assert!(all.into_iter().eq(0..n));
+    }
+
+    #[test]
+    fn test_par_split_rand_vec_eq_seeded_is_deterministic() {
+        let split = |seed| par_split_rand_vec_eq_with_rng((0..200).collect::<Vec<_>>(), 3, &mut StdRng::seed_from_u64(seed));
+        assert_eq!(split(5), split(5));
+        assert_ne!(split(5), split(6));
+    }
+
+    #[test]
+    fn test_par_split_rand_vec_eq_is_uniform() {
+        let mut first_counts = [0_u32; 4];
+        for _ in 0..4000 {
+            first_counts[par_split_rand_vec_eq(vec![0, 1, 2, 3], 1)[0][0]] += 1;
+        }
+        assert!(first_counts.iter().all(|&count| (800..1200).contains(&count)), "{:?}", first_counts);
+    }
+}
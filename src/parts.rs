@@ -0,0 +1,150 @@
+//! The [`Parts`] result type.
+
+use alloc::vec::Vec;
+use core::ops::Index;
+
+/// The result of a split: a list of parts, each holding some of the original elements.
+///
+/// `Parts<T>` converts from and into `Vec<Vec<T>>`, so it can be used wherever the plain split functions are used.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parts<T> {
+    parts: Vec<Vec<T>>,
+}
+
+impl<T> Parts<T> {
+    /// Returns the number of parts.
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    /// Returns `true` if there are no parts.
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Returns the number of elements in each part.
+    pub fn sizes(&self) -> Vec<usize> {
+        self.parts.iter().map(Vec::len).collect()
+    }
+
+    /// Returns the number of elements in all parts together.
+    pub fn total_len(&self) -> usize {
+        self.parts.iter().map(Vec::len).sum()
+    }
+
+    /// Returns an iterator over the parts.
+    pub fn iter(&self) -> core::slice::Iter<'_, Vec<T>> {
+        self.parts.iter()
+    }
+
+    /// Returns the part at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&Vec<T>> {
+        self.parts.get(index)
+    }
+
+    /// Returns the underlying vector of parts.
+    pub fn into_inner(self) -> Vec<Vec<T>> {
+        self.parts
+    }
+
+    /// Concatenates all parts into a single vector, in part order.
+    pub fn flatten(self) -> Vec<T> {
+        self.parts.into_iter().flatten().collect()
+    }
+
+    /// Returns `true` if the part sizes differ by at most one.
+    pub fn is_balanced(&self) -> bool {
+        let min = self.parts.iter().map(Vec::len).min().unwrap_or(0);
+        let max = self.parts.iter().map(Vec::len).max().unwrap_or(0);
+        max - min <= 1
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Parts<T> {
+    fn from(parts: Vec<Vec<T>>) -> Self {
+        Parts { parts }
+    }
+}
+
+impl<T> From<Parts<T>> for Vec<Vec<T>> {
+    fn from(parts: Parts<T>) -> Self {
+        parts.parts
+    }
+}
+
+impl<T> Index<usize> for Parts<T> {
+    type Output = Vec<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.parts[index]
+    }
+}
+
+impl<T> IntoIterator for Parts<T> {
+    type Item = Vec<T>;
+    type IntoIter = alloc::vec::IntoIter<Vec<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.parts.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Parts<T> {
+    type Item = &'a Vec<T>;
+    type IntoIter = core::slice::Iter<'a, Vec<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.parts.iter()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parts_accessors() {
+        let parts = Parts::from(vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]]);
+        assert_eq!(parts.len(), 3);
+        assert!(!parts.is_empty());
+        assert_eq!(parts.sizes(), vec![3, 2, 2]);
+        assert_eq!(parts.total_len(), 7);
+        assert_eq!(parts.get(1), Some(&vec![4, 5]));
+        assert_eq!(parts.get(3), None);
+        assert_eq!(parts[2], vec![6, 7]);
+        assert_eq!(parts.iter().count(), 3);
+        assert!(parts.is_balanced());
+        assert_eq!(parts.clone().flatten(), vec![1, 2, 3, 4, 5, 6, 7]);
+
+        let unbalanced = Parts::from(vec![vec![1, 2, 3], vec![4]]);
+        assert!(!unbalanced.is_balanced());
+        assert!(Parts::<i32>::default().is_balanced());
+        assert_eq!(format!("{:?}", unbalanced), "Parts { parts: [[1, 2, 3], [4]] }");
+    }
+
+    #[test]
+    fn test_parts_conversions() {
+        let raw = vec![vec!['a'], vec!['b', 'c']];
+        let parts: Parts<char> = raw.clone().into();
+        let back: Vec<Vec<char>> = parts.clone().into();
+        assert_eq!(back, raw);
+        assert_eq!(parts.clone().into_inner(), raw);
+
+        let mut seen = Vec::new();
+        for part in &parts {
+            seen.push(part.len());
+        }
+        assert_eq!(seen, vec![1, 2]);
+        assert_eq!(parts.into_iter().collect::<Vec<_>>(), raw);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parts_serde_round_trip() {
+        let parts = Parts::from(vec![vec!["Ann".to_string(), "Ben".to_string()], vec!["Cem".to_string()]]);
+        let json = serde_json::to_string(&parts).unwrap();
+        assert_eq!(json, r#"{"parts":[["Ann","Ben"],["Cem"]]}"#);
+        assert_eq!(serde_json::from_str::<Parts<String>>(&json).unwrap(), parts);
+    }
+}
@@ -0,0 +1,475 @@
+//! Helpers for random permutations of indices.
+
+use crate::{DerangeError, SplitError};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Index;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Returns the indices `0..n` in uniformly random order.
+///
+/// Applying the same permutation to several parallel slices keeps their elements aligned.
+/// # Arguments
+/// * `n` - The number of indices.
+///
+/// # Returns
+/// A vector containing every index in `0..n` exactly once.
+/// # Example
+/// ```
+/// use rust_helpers::random_permutation;
+/// let names = ["Ann", "Ben", "Cem"];
+/// let ages = [31, 27, 45];
+/// let order = random_permutation(names.len());
+/// let shuffled: Vec<(&str, u32)> = order.iter().map(|&i| (names[i], ages[i])).collect();
+/// assert_eq!(shuffled.len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn random_permutation(n: usize) -> Vec<usize> {
+    random_permutation_with_rng(n, &mut rand::rng())
+}
+
+/// Like [`random_permutation`], but shuffles with the given RNG.
+pub fn random_permutation_with_rng<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Vec<usize> {
+    let mut permutation: Vec<usize> = (0..n).collect();
+    permutation.shuffle(rng);
+    permutation
+}
+
+/// Like [`random_permutation`], but shuffles with an RNG seeded from `seed`, so the result is reproducible.
+/// # Example
+/// ```
+/// use rust_helpers::random_permutation_seeded;
+/// assert_eq!(random_permutation_seeded(10, 3), random_permutation_seeded(10, 3));
+/// ```
+pub fn random_permutation_seeded(n: usize, seed: u64) -> Vec<usize> {
+    random_permutation_with_rng(n, &mut StdRng::seed_from_u64(seed))
+}
+
+/// A reordering of the positions `0..len`.
+///
+/// A permutation `p` maps a slice `old` to `new` with `new[i] == old[p[i]]`. Recording a shuffle as a `Permutation`
+/// lets the same reordering be applied to several parallel datasets, or undone with [`Permutation::invert`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+impl Permutation {
+    /// Returns the permutation of `0..len` that keeps every element in place.
+    pub fn identity(len: usize) -> Self {
+        Permutation { indices: (0..len).collect() }
+    }
+
+    /// Wraps indices that are already known to be a permutation.
+    pub(crate) fn from_indices_unchecked(indices: Vec<usize>) -> Self {
+        debug_assert!(Permutation::try_from(indices.clone()).is_ok());
+        Permutation { indices }
+    }
+
+    /// Returns the number of positions.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `true` if the permutation has no positions.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the source position of each target position.
+    pub fn as_slice(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the underlying vector of source positions.
+    pub fn into_inner(self) -> Vec<usize> {
+        self.indices
+    }
+
+    /// Returns `true` if the permutation keeps every element in place.
+    pub fn is_identity(&self) -> bool {
+        self.indices.iter().enumerate().all(|(i, &p)| i == p)
+    }
+
+    /// Reorders a copy of `slice` according to the permutation.
+    /// # Arguments
+    /// * `slice` - The elements to reorder.
+    ///
+    /// # Returns
+    /// A vector `new` with `new[i] == slice[self[i]]`.
+    /// # Panics
+    /// Panics if `slice.len()` differs from the length of the permutation.
+    /// # Example
+    /// ```
+    /// use rust_helpers::Permutation;
+    /// let permutation = Permutation::try_from(vec![2, 0, 1]).unwrap();
+    /// assert_eq!(permutation.apply(&["a", "b", "c"]), vec!["c", "a", "b"]);
+    /// ```
+    pub fn apply<T: Clone>(&self, slice: &[T]) -> Vec<T> {
+        assert_eq!(slice.len(), self.len(), "slice length must match the permutation length");
+        self.indices.iter().map(|&p| slice[p].clone()).collect()
+    }
+
+    /// Reorders `slice` in place according to the permutation, without cloning any element.
+    /// # Arguments
+    /// * `slice` - The elements to reorder.
+    ///
+    /// # Panics
+    /// Panics if `slice.len()` differs from the length of the permutation.
+    /// # Example
+    /// ```
+    /// use rust_helpers::Permutation;
+    /// let permutation = Permutation::try_from(vec![2, 0, 1]).unwrap();
+    /// let mut letters = vec!["a", "b", "c"];
+    /// permutation.apply_in_place(&mut letters);
+    /// assert_eq!(letters, vec!["c", "a", "b"]);
+    /// ```
+    pub fn apply_in_place<T>(&self, slice: &mut [T]) {
+        assert_eq!(slice.len(), self.len(), "slice length must match the permutation length");
+        let mut visited = vec![false; self.len()];
+        for start in 0..self.len() {
+            let mut current = start;
+            while !visited[current] {
+                visited[current] = true;
+                let source = self.indices[current];
+                if source == start {
+                    break;
+                }
+                slice.swap(current, source);
+                current = source;
+            }
+        }
+    }
+
+    /// Returns the permutation that undoes this one.
+    ///
+    /// Applying `self` and then `self.invert()` restores the original order.
+    pub fn invert(&self) -> Self {
+        let mut inverse = vec![0; self.len()];
+        for (i, &p) in self.indices.iter().enumerate() {
+            inverse[p] = i;
+        }
+        Permutation { indices: inverse }
+    }
+
+    /// Combines two permutations into one.
+    ///
+    /// Applying the result is the same as applying `self` first and `other` second.
+    /// # Panics
+    /// Panics if the permutations differ in length.
+    /// # Example
+    /// ```
+    /// use rust_helpers::Permutation;
+    /// let first = Permutation::try_from(vec![1, 2, 0]).unwrap();
+    /// let second = Permutation::try_from(vec![0, 2, 1]).unwrap();
+    /// let letters = ["a", "b", "c"];
+    /// assert_eq!(first.compose(&second).apply(&letters), second.apply(&first.apply(&letters)));
+    /// ```
+    pub fn compose(&self, other: &Permutation) -> Self {
+        assert_eq!(self.len(), other.len(), "permutations must have the same length");
+        Permutation { indices: other.indices.iter().map(|&p| self.indices[p]).collect() }
+    }
+}
+
+impl TryFrom<Vec<usize>> for Permutation {
+    type Error = SplitError;
+
+    /// Checks that `indices` contains every position in `0..indices.len()` exactly once.
+    ///
+    /// Fails with [`SplitError::IndexOutOfRange`] or [`SplitError::DuplicateIndex`] otherwise.
+    fn try_from(indices: Vec<usize>) -> Result<Self, Self::Error> {
+        let len = indices.len();
+        let mut seen = vec![false; len];
+        for &index in &indices {
+            if index >= len {
+                return Err(SplitError::IndexOutOfRange { index, len });
+            }
+            if seen[index] {
+                return Err(SplitError::DuplicateIndex { index });
+            }
+            seen[index] = true;
+        }
+        Ok(Permutation { indices })
+    }
+}
+
+impl From<Permutation> for Vec<usize> {
+    fn from(permutation: Permutation) -> Self {
+        permutation.indices
+    }
+}
+
+impl Index<usize> for Permutation {
+    type Output = usize;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.indices[index]
+    }
+}
+
+/// Shuffles a slice in place and returns the permutation that was applied.
+///
+/// The returned permutation `p` satisfies `new[i] == old[p[i]]`; its [`Permutation::invert`] restores the original
+/// order.
+/// # Arguments
+/// * `slice` - The elements to shuffle. A `&mut Vec<T>` works as well.
+/// * `rng` - The random number generator used for shuffling.
+///
+/// # Returns
+/// The applied [`Permutation`] of `0..slice.len()`.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::shuffle_tracked;
+/// let questions = vec!["Q1", "Q2", "Q3", "Q4"];
+/// let mut shuffled = questions.clone();
+/// let permutation = shuffle_tracked(&mut shuffled, &mut StdRng::seed_from_u64(1));
+/// assert!((0..4).all(|i| shuffled[i] == questions[permutation[i]]));
+/// assert_eq!(permutation.invert().apply(&shuffled), questions);
+/// ```
+pub fn shuffle_tracked<T, R: Rng + ?Sized>(slice: &mut [T], rng: &mut R) -> Permutation {
+    let mut permutation: Vec<usize> = (0..slice.len()).collect();
+    for i in (1..slice.len()).rev() {
+        let j = rng.random_range(0..=i);
+        slice.swap(i, j);
+        permutation.swap(i, j);
+    }
+    Permutation { indices: permutation }
+}
+
+/// Shuffles a vec so that no element stays at its original position.
+///
+/// Every derangement is equally likely. Elements are compared by position, not by value, so equal elements may
+/// still swap places with each other.
+/// # Arguments
+/// * `vec` - The vector to derange.
+///
+/// # Returns
+/// The deranged vector. An empty vector is returned unchanged; a vector with one element cannot be deranged and
+/// yields [`DerangeError::SingleElement`].
+/// # Example
+/// ```
+/// use rust_helpers::{derange, DerangeError};
+/// let seats = vec!["Ann", "Ben", "Cem", "Dan"];
+/// let swapped = derange(seats.clone()).unwrap();
+/// assert!(seats.iter().zip(&swapped).all(|(old, new)| old != new));
+/// assert_eq!(derange(vec!["Eve"]), Err(DerangeError::SingleElement));
+/// ```
+#[cfg(feature = "std")]
+pub fn derange<T>(vec: Vec<T>) -> Result<Vec<T>, DerangeError> {
+    derange_with_rng(vec, &mut rand::rng())
+}
+
+/// Like [`derange`], but shuffles with the given RNG.
+pub fn derange_with_rng<T, R: Rng + ?Sized>(mut vec: Vec<T>, rng: &mut R) -> Result<Vec<T>, DerangeError> {
+    random_derangement(vec.len(), rng)?.apply_in_place(&mut vec);
+    Ok(vec)
+}
+
+/// Pairs every element with a random other element, as in a secret santa draw.
+///
+/// Every participant gives to exactly one participant and receives from exactly one, and nobody is assigned
+/// themselves.
+/// # Arguments
+/// * `names` - The participants.
+///
+/// # Returns
+/// One `(giver, receiver)` pair per participant, in the order of `names`.
+/// # Panics
+/// Panics if `names` has exactly one element. Use [`try_derange_map`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::derange_map;
+/// let names = ["Ann", "Ben", "Cem"];
+/// let pairs = derange_map(&names);
+/// assert_eq!(pairs.len(), 3);
+/// assert!(pairs.iter().all(|(giver, receiver)| giver != receiver));
+/// ```
+#[cfg(feature = "std")]
+pub fn derange_map<T: Clone>(names: &[T]) -> Vec<(T, T)> {
+    derange_map_with_rng(names, &mut rand::rng())
+}
+
+/// Like [`derange_map`], but draws with the given RNG.
+/// # Panics
+/// Panics if `names` has exactly one element. Use [`try_derange_map_with_rng`] to get an error instead.
+pub fn derange_map_with_rng<T: Clone, R: Rng + ?Sized>(names: &[T], rng: &mut R) -> Vec<(T, T)> {
+    try_derange_map_with_rng(names, rng).expect("a single name cannot be deranged")
+}
+
+/// Like [`derange_map`], but returns [`DerangeError::SingleElement`] instead of panicking if `names` has exactly one
+/// element.
+/// # Example
+/// ```
+/// use rust_helpers::{try_derange_map, DerangeError};
+/// assert_eq!(try_derange_map(&["Ann"]), Err(DerangeError::SingleElement));
+/// assert_eq!(try_derange_map(&["Ann", "Ben"]), Ok(vec![("Ann", "Ben"), ("Ben", "Ann")]));
+/// ```
+#[cfg(feature = "std")]
+pub fn try_derange_map<T: Clone>(names: &[T]) -> Result<Vec<(T, T)>, DerangeError> {
+    try_derange_map_with_rng(names, &mut rand::rng())
+}
+
+/// Like [`try_derange_map`], but draws with the given RNG.
+pub fn try_derange_map_with_rng<T: Clone, R: Rng + ?Sized>(names: &[T], rng: &mut R) -> Result<Vec<(T, T)>, DerangeError> {
+    let receivers = random_derangement(names.len(), rng)?.apply(names);
+    Ok(names.iter().cloned().zip(receivers).collect())
+}
+
+/// Draws a uniformly random permutation of `0..len` without fixed points by rejection sampling.
+///
+/// About `e` (2.718...) shuffles are needed on average, independent of `len`.
+fn random_derangement<R: Rng + ?Sized>(len: usize, rng: &mut R) -> Result<Permutation, DerangeError> {
+    if len == 1 {
+        return Err(DerangeError::SingleElement);
+    }
+    let mut indices: Vec<usize> = (0..len).collect();
+    loop {
+        indices.shuffle(rng);
+        if indices.iter().enumerate().all(|(i, &p)| i != p) {
+            return Ok(Permutation { indices });
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_permutation_is_permutation() {
+        for n in [0, 1, 2, 10, 100] {
+            let mut permutation = random_permutation(n);
+            permutation.sort();
+            assert_eq!(permutation, (0..n).collect::<Vec<_>>());
+        }
+        assert_eq!(random_permutation_seeded(20, 1), random_permutation_with_rng(20, &mut StdRng::seed_from_u64(1)));
+    }
+
+    #[test]
+    fn test_shuffle_tracked_reconstructs_original() {
+        let original: Vec<String> = (0..30).map(|i| i.to_string()).collect();
+        let mut shuffled = original.clone();
+        let mut rng = StdRng::seed_from_u64(3);
+        let permutation = shuffle_tracked(&mut shuffled, &mut rng);
+        assert_ne!(shuffled, original);
+        let mut restored = vec![String::new(); original.len()];
+        for (i, &p) in permutation.as_slice().iter().enumerate() {
+            restored[p] = shuffled[i].clone();
+        }
+        assert_eq!(restored, original);
+        assert!(shuffle_tracked::<u32, _>(&mut [], &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_permutation_apply_then_inverse_is_identity() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let original: Vec<u32> = (0..25).collect();
+        for _ in 0..20 {
+            let mut shuffled = original.clone();
+            let permutation = shuffle_tracked(&mut shuffled, &mut rng);
+            assert_eq!(permutation.apply(&original), shuffled);
+            assert_eq!(permutation.invert().apply(&shuffled), original);
+            assert!(permutation.compose(&permutation.invert()).is_identity());
+            assert!(permutation.invert().compose(&permutation).is_identity());
+
+            let mut in_place = original.clone();
+            permutation.apply_in_place(&mut in_place);
+            assert_eq!(in_place, shuffled);
+            permutation.invert().apply_in_place(&mut in_place);
+            assert_eq!(in_place, original);
+        }
+    }
+
+    #[test]
+    fn test_permutation_compose_applies_in_order() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let letters: Vec<char> = ('a'..='j').collect();
+        let first = shuffle_tracked(&mut letters.clone(), &mut rng);
+        let second = shuffle_tracked(&mut letters.clone(), &mut rng);
+        assert_eq!(first.compose(&second).apply(&letters), second.apply(&first.apply(&letters)));
+        assert!(Permutation::identity(4).is_identity());
+        assert!(!first.is_identity());
+    }
+
+    #[test]
+    fn test_permutation_try_from_rejects_invalid_input() {
+        assert_eq!(Permutation::try_from(vec![2, 0, 1]).map(Vec::from), Ok(vec![2, 0, 1]));
+        assert_eq!(Permutation::try_from(vec![]), Ok(Permutation::identity(0)));
+        assert_eq!(Permutation::try_from(vec![0, 3, 1]), Err(SplitError::IndexOutOfRange { index: 3, len: 3 }));
+        assert_eq!(Permutation::try_from(vec![1, 0, 1]), Err(SplitError::DuplicateIndex { index: 1 }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_permutation_apply_panics_on_length_mismatch() {
+        Permutation::identity(3).apply(&[1, 2]);
+    }
+
+    #[test]
+    fn test_derange_never_keeps_an_element_in_place() {
+        let mut rng = StdRng::seed_from_u64(12);
+        for len in 2..12 {
+            for _ in 0..200 {
+                let deranged = derange_with_rng((0..len).collect::<Vec<usize>>(), &mut rng).unwrap();
+                assert!(deranged.iter().enumerate().all(|(i, &e)| i != e));
+                let mut sorted = deranged.clone();
+                sorted.sort();
+                assert_eq!(sorted, (0..len).collect::<Vec<_>>());
+            }
+        }
+        assert_eq!(derange(vec!["only"]), Err(DerangeError::SingleElement));
+        assert_eq!(derange(Vec::<u8>::new()), Ok(vec![]));
+        assert_eq!(derange(vec![1, 2]), Ok(vec![2, 1]));
+    }
+
+    #[test]
+    fn test_derange_map_pairs_nobody_with_themselves() {
+        let names = ["Ann", "Ben", "Cem", "Dan", "Eve"];
+        let mut rng = StdRng::seed_from_u64(13);
+        for _ in 0..500 {
+            let pairs = derange_map_with_rng(&names, &mut rng);
+            assert!(pairs.iter().all(|(giver, receiver)| giver != receiver));
+            assert_eq!(pairs.iter().map(|(giver, _)| *giver).collect::<Vec<_>>(), names);
+            let mut receivers: Vec<&str> = pairs.iter().map(|(_, receiver)| *receiver).collect();
+            receivers.sort();
+            assert_eq!(receivers, names);
+        }
+        assert!(derange_map::<u8>(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_derange_map_panics_on_single_name() {
+        derange_map(&["Ann"]);
+    }
+
+    #[test]
+    fn test_try_derange_map() {
+        assert_eq!(try_derange_map(&["Ann"]), Err(DerangeError::SingleElement));
+        assert_eq!(try_derange_map::<u8>(&[]), Ok(vec![]));
+        let names = ["Ann", "Ben", "Cem", "Dan"];
+        let pairs = try_derange_map_with_rng(&names, &mut StdRng::seed_from_u64(4)).unwrap();
+        assert_eq!(pairs, derange_map_with_rng(&names, &mut StdRng::seed_from_u64(4)));
+    }
+
+    #[test]
+    fn test_random_permutation_positions_are_uniform() {
+        let n = 4;
+        let runs = 4000;
+        let mut counts = vec![vec![0_u32; n]; n];
+        let mut rng = StdRng::seed_from_u64(12);
+        for _ in 0..runs {
+            for (position, index) in random_permutation_with_rng(n, &mut rng).into_iter().enumerate() {
+                counts[index][position] += 1;
+            }
+        }
+        let expected = (runs / n) as f64;
+        let chi_square: f64 = counts.iter().flatten().map(|&c| (c as f64 - expected).powi(2) / expected).sum();
+        // 9 degrees of freedom; the 99.9% quantile of the chi-square distribution is about 27.9.
+        assert!(chi_square < 27.9, "{}: {:?}", chi_square, counts);
+    }
+}
@@ -0,0 +1,9 @@
+//! Convenience re-exports of the extension traits.
+//!
+//! ```
+//! use rust_helpers::prelude::*;
+//! let groups = vec![1, 2, 3, 4].split_rand_eq(2);
+//! assert_eq!(groups.len(), 2);
+//! ```
+
+pub use crate::ext::{SetRandExt, VecRandExt};
@@ -0,0 +1,857 @@
+//! Helpers for drawing random samples from a collection.
+
+use crate::shuffle::partial_shuffle_swaps;
+use crate::{partial_shuffle_k, SampleError};
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use rand::rngs::StdRng;
+use rand::seq::index;
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+/// Picks a uniformly random element of a slice.
+/// # Arguments
+/// * `items` - The elements to pick from.
+///
+/// # Returns
+/// A reference to the picked element, or `None` if `items` is empty.
+/// # Example
+/// ```
+/// use rust_helpers::choose_random;
+/// let names = ["Ann", "Ben", "Cem"];
+/// assert!(names.contains(choose_random(&names).unwrap()));
+/// assert_eq!(choose_random::<&str>(&[]), None);
+/// ```
+#[cfg(feature = "std")]
+pub fn choose_random<T>(items: &[T]) -> Option<&T> {
+    choose_random_with_rng(items, &mut rand::rng())
+}
+
+/// Like [`choose_random`], but picks with the given RNG.
+pub fn choose_random_with_rng<'a, T, R: Rng + ?Sized>(items: &'a [T], rng: &mut R) -> Option<&'a T> {
+    choose_random_index_with_rng(items.len(), rng).map(|i| &items[i])
+}
+
+/// Like [`choose_random`], but returns a mutable reference.
+/// # Example
+/// ```
+/// use rust_helpers::choose_random_mut;
+/// let mut scores = [0, 0, 0];
+/// *choose_random_mut(&mut scores).unwrap() += 1;
+/// assert_eq!(scores.iter().sum::<i32>(), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn choose_random_mut<T>(items: &mut [T]) -> Option<&mut T> {
+    choose_random_mut_with_rng(items, &mut rand::rng())
+}
+
+/// Like [`choose_random_mut`], but picks with the given RNG.
+pub fn choose_random_mut_with_rng<'a, T, R: Rng + ?Sized>(items: &'a mut [T], rng: &mut R) -> Option<&'a mut T> {
+    choose_random_index_with_rng(items.len(), rng).map(|i| &mut items[i])
+}
+
+/// Picks a uniformly random index in `0..len`, e.g. to pick the same position in several parallel slices.
+/// # Arguments
+/// * `len` - The number of indices to pick from.
+///
+/// # Returns
+/// The picked index, or `None` if `len` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::choose_random_index;
+/// let names = ["Ann", "Ben", "Cem"];
+/// let ages = [31, 27, 45];
+/// let i = choose_random_index(names.len()).unwrap();
+/// assert!(i < names.len() && i < ages.len());
+/// assert_eq!(choose_random_index(0), None);
+/// ```
+#[cfg(feature = "std")]
+pub fn choose_random_index(len: usize) -> Option<usize> {
+    choose_random_index_with_rng(len, &mut rand::rng())
+}
+
+/// Like [`choose_random_index`], but picks with the given RNG.
+pub fn choose_random_index_with_rng<R: Rng + ?Sized>(len: usize, rng: &mut R) -> Option<usize> {
+    (len > 0).then(|| rng.random_range(0..len))
+}
+
+/// Removes and returns a uniformly random element of a vec, like drawing a name out of a hat.
+///
+/// The element is swap-removed in constant time, so the last element takes its place. Use [`pop_random_stable`]
+/// to keep the order of the remaining elements.
+/// # Arguments
+/// * `vec` - The vec to remove the element from.
+///
+/// # Returns
+/// The removed element, or `None` if `vec` is empty.
+/// # Example
+/// ```
+/// use rust_helpers::pop_random;
+/// let mut hat = vec!["Ann", "Ben", "Cem"];
+/// let drawn = pop_random(&mut hat).unwrap();
+/// assert_eq!(hat.len(), 2);
+/// assert!(!hat.contains(&drawn));
+/// ```
+#[cfg(feature = "std")]
+pub fn pop_random<T>(vec: &mut Vec<T>) -> Option<T> {
+    pop_random_with_rng(vec, &mut rand::rng())
+}
+
+/// Like [`pop_random`], but picks with the given RNG.
+pub fn pop_random_with_rng<T, R: Rng + ?Sized>(vec: &mut Vec<T>, rng: &mut R) -> Option<T> {
+    choose_random_index_with_rng(vec.len(), rng).map(|i| vec.swap_remove(i))
+}
+
+/// Like [`pop_random`], but keeps the order of the remaining elements, which takes linear time.
+/// # Example
+/// ```
+/// use rust_helpers::pop_random_stable;
+/// let mut queue = vec![1, 2, 3, 4];
+/// let drawn = pop_random_stable(&mut queue).unwrap();
+/// assert!(queue.windows(2).all(|w| w[0] < w[1]));
+/// assert!(!queue.contains(&drawn));
+/// ```
+#[cfg(feature = "std")]
+pub fn pop_random_stable<T>(vec: &mut Vec<T>) -> Option<T> {
+    pop_random_stable_with_rng(vec, &mut rand::rng())
+}
+
+/// Like [`pop_random_stable`], but picks with the given RNG.
+pub fn pop_random_stable_with_rng<T, R: Rng + ?Sized>(vec: &mut Vec<T>, rng: &mut R) -> Option<T> {
+    choose_random_index_with_rng(vec.len(), rng).map(|i| vec.remove(i))
+}
+
+/// Removes `k` uniformly chosen elements from a vec and returns them in random order.
+///
+/// The elements are swap-removed one by one in `O(k)`, so the order of the elements left in `vec` changes. Use
+/// [`drain_random_n_stable`] to keep their order.
+/// # Arguments
+/// * `vec` - The vec to remove the elements from.
+/// * `k` - The number of elements to remove.
+///
+/// # Returns
+/// The removed elements, or [`SampleError::NotEnoughItems`] if `k` exceeds the length of `vec`, in which case
+/// `vec` is left unchanged.
+/// # Example
+/// ```
+/// use rust_helpers::drain_random_n;
+/// let mut pool: Vec<u32> = (1..=12).collect();
+/// let volunteers = drain_random_n(&mut pool, 5).unwrap();
+/// assert_eq!((volunteers.len(), pool.len()), (5, 7));
+/// assert!(volunteers.iter().all(|v| !pool.contains(v)));
+/// ```
+#[cfg(feature = "std")]
+pub fn drain_random_n<T>(vec: &mut Vec<T>, k: usize) -> Result<Vec<T>, SampleError> {
+    drain_random_n_with_rng(vec, k, &mut rand::rng())
+}
+
+/// Like [`drain_random_n`], but draws with the given RNG.
+pub fn drain_random_n_with_rng<T, R: Rng + ?Sized>(vec: &mut Vec<T>, k: usize, rng: &mut R) -> Result<Vec<T>, SampleError> {
+    if k > vec.len() {
+        return Err(SampleError::NotEnoughItems { requested: k, available: vec.len() });
+    }
+    Ok((0..k).map(|_| vec.swap_remove(rng.random_range(0..vec.len()))).collect())
+}
+
+/// Like [`drain_random_n`], but keeps the order of the elements left in `vec`, which takes linear time.
+/// # Example
+/// ```
+/// use rust_helpers::drain_random_n_stable;
+/// let mut pool: Vec<u32> = (1..=12).collect();
+/// let volunteers = drain_random_n_stable(&mut pool, 5).unwrap();
+/// assert_eq!(volunteers.len(), 5);
+/// assert!(pool.windows(2).all(|w| w[0] < w[1]));
+/// ```
+#[cfg(feature = "std")]
+pub fn drain_random_n_stable<T>(vec: &mut Vec<T>, k: usize) -> Result<Vec<T>, SampleError> {
+    drain_random_n_stable_with_rng(vec, k, &mut rand::rng())
+}
+
+/// Like [`drain_random_n_stable`], but draws with the given RNG.
+pub fn drain_random_n_stable_with_rng<T, R: Rng + ?Sized>(vec: &mut Vec<T>, k: usize, rng: &mut R) -> Result<Vec<T>, SampleError> {
+    if k > vec.len() {
+        return Err(SampleError::NotEnoughItems { requested: k, available: vec.len() });
+    }
+    let mut rank: Vec<Option<usize>> = vec![None; vec.len()];
+    for (r, i) in index::sample(rng, vec.len(), k).into_iter().enumerate() {
+        rank[i] = Some(r);
+    }
+    let mut drawn: Vec<Option<T>> = (0..k).map(|_| None).collect();
+    let mut kept = Vec::with_capacity(vec.len() - k);
+    for (i, e) in vec.drain(..).enumerate() {
+        match rank[i] {
+            Some(r) => drawn[r] = Some(e),
+            None => kept.push(e),
+        }
+    }
+    *vec = kept;
+    Ok(drawn.into_iter().flatten().collect())
+}
+
+/// Draws `k` distinct elements from a slice in random order.
+///
+/// Performs the first `k` steps of a Fisher-Yates shuffle like [`partial_shuffle_k`], but only on the positions and
+/// without moving the elements, so this costs `O(k log k)` even if `k` is much smaller than the length of `items`.
+/// With the same RNG, it draws the same elements as `partial_shuffle_k` on a copy of `items`.
+/// # Arguments
+/// * `items` - The elements to draw from. They must implement the `Clone` trait.
+/// * `k` - The number of elements to draw.
+///
+/// # Returns
+/// `k` clones of distinct elements of `items`, or [`SampleError::NotEnoughItems`] if `k` exceeds its length.
+/// # Example
+/// ```
+/// use rust_helpers::sample_n;
+/// let tickets = ["Ann", "Ben", "Cem", "Dana", "Eli"];
+/// let winners = sample_n(&tickets, 2).unwrap();
+/// assert_eq!(winners.len(), 2);
+/// assert_ne!(winners[0], winners[1]);
+/// assert!(sample_n(&tickets, 6).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn sample_n<T: Clone>(items: &[T], k: usize) -> Result<Vec<T>, SampleError> {
+    sample_n_with_rng(items, k, &mut rand::rng())
+}
+
+/// Like [`sample_n`], but draws with the given RNG.
+pub fn sample_n_with_rng<T: Clone, R: Rng + ?Sized>(items: &[T], k: usize, rng: &mut R) -> Result<Vec<T>, SampleError> {
+    if k > items.len() {
+        return Err(SampleError::NotEnoughItems { requested: k, available: items.len() });
+    }
+    // The positions that were swapped away from, mapped to the position of the element that is there now.
+    let mut moved: BTreeMap<usize, usize> = BTreeMap::new();
+    Ok(partial_shuffle_swaps(items.len(), k, rng)
+        .map(|(i, j)| {
+            let picked = moved.get(&j).copied().unwrap_or(j);
+            moved.insert(j, moved.get(&i).copied().unwrap_or(i));
+            items[picked].clone()
+        })
+        .collect())
+}
+
+/// Draws `k` distinct elements from a vec in random order, moving them out and dropping the rest.
+///
+/// Only the first `k` steps of a Fisher-Yates shuffle are performed (see [`partial_shuffle_k`]), so this costs
+/// `O(k)` and `T` doesn't need to implement `Clone`.
+/// # Arguments
+/// * `vec` - The elements to draw from.
+/// * `k` - The number of elements to draw.
+///
+/// # Returns
+/// `k` distinct elements of `vec`, or [`SampleError::NotEnoughItems`] if `k` exceeds its length.
+/// # Example
+/// ```
+/// use rust_helpers::sample_n_owned;
+/// let names = vec![String::from("Ann"), String::from("Ben"), String::from("Cem")];
+/// let picked = sample_n_owned(names, 2).unwrap();
+/// assert_eq!(picked.len(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn sample_n_owned<T>(vec: Vec<T>, k: usize) -> Result<Vec<T>, SampleError> {
+    sample_n_owned_with_rng(vec, k, &mut rand::rng())
+}
+
+/// Like [`sample_n_owned`], but draws with the given RNG.
+pub fn sample_n_owned_with_rng<T, R: Rng + ?Sized>(mut vec: Vec<T>, k: usize, rng: &mut R) -> Result<Vec<T>, SampleError> {
+    if k > vec.len() {
+        return Err(SampleError::NotEnoughItems { requested: k, available: vec.len() });
+    }
+    partial_shuffle_k(&mut vec, k, rng);
+    vec.truncate(k);
+    Ok(vec)
+}
+
+/// Draws `k` elements from a slice with replacement, so the same element may be drawn several times.
+/// # Arguments
+/// * `items` - The elements to draw from. They must implement the `Clone` trait.
+/// * `k` - The number of elements to draw. It may exceed the length of `items`.
+///
+/// # Returns
+/// Exactly `k` elements, each drawn uniformly from `items`. Returns [`SampleError::EmptyInput`] if `items` is
+/// empty and `k` is not 0.
+/// # Example
+/// ```
+/// use rust_helpers::sample_n_with_replacement;
+/// let dice = [1, 2, 3, 4, 5, 6];
+/// let rolls = sample_n_with_replacement(&dice, 10).unwrap();
+/// assert_eq!(rolls.len(), 10);
+/// assert!(rolls.iter().all(|roll| dice.contains(roll)));
+/// ```
+#[cfg(feature = "std")]
+pub fn sample_n_with_replacement<T: Clone>(items: &[T], k: usize) -> Result<Vec<T>, SampleError> {
+    sample_n_with_replacement_with_rng(items, k, &mut rand::rng())
+}
+
+/// Like [`sample_n_with_replacement`], but draws with the given RNG.
+pub fn sample_n_with_replacement_with_rng<T: Clone, R: Rng + ?Sized>(items: &[T], k: usize, rng: &mut R) -> Result<Vec<T>, SampleError> {
+    if items.is_empty() && k > 0 {
+        return Err(SampleError::EmptyInput);
+    }
+    Ok((0..k).map(|_| items[rng.random_range(0..items.len())].clone()).collect())
+}
+
+/// Like [`sample_n_with_replacement`], but draws with an RNG seeded from `seed`, so the result is reproducible.
+/// # Example
+/// ```
+/// use rust_helpers::sample_n_with_replacement_seeded;
+/// let first = sample_n_with_replacement_seeded(&["a", "b", "c"], 5, 42).unwrap();
+/// assert_eq!(first, sample_n_with_replacement_seeded(&["a", "b", "c"], 5, 42).unwrap());
+/// ```
+pub fn sample_n_with_replacement_seeded<T: Clone>(items: &[T], k: usize, seed: u64) -> Result<Vec<T>, SampleError> {
+    sample_n_with_replacement_with_rng(items, k, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Draws `k` elements from weighted items, using the weights as relative probabilities.
+///
+/// Without replacement this uses the algorithm of Efraimidis and Spirakis: every item gets the key `u^(1/w)`,
+/// compared as `ln(u) / w`, for a uniform `u` and its weight `w`. The `k` items with the largest keys are returned,
+/// in descending key order. This is equivalent to drawing the items one after another, each with probability proportional to its weight
+/// among the items not drawn yet. Items with a weight of zero are never drawn.
+/// # Arguments
+/// * `items` - The elements to draw from, each with its weight. The elements must implement the `Clone` trait.
+/// * `k` - The number of elements to draw.
+/// * `replacement` - Whether an element may be drawn more than once.
+///
+/// # Returns
+/// `k` drawn elements. Returns [`SampleError::InvalidWeight`] for a negative, infinite or NaN weight,
+/// [`SampleError::NotEnoughItems`] if `k` exceeds the number of items with a positive weight when sampling without
+/// replacement, and [`SampleError::ZeroWeightSum`] if all weights are zero when sampling with replacement.
+/// # Example
+/// ```
+/// use rust_helpers::sample_weighted;
+/// let tickets = [("Ann", 5.0), ("Ben", 1.0), ("Cem", 0.0)];
+/// let winners = sample_weighted(&tickets, 2, false).unwrap();
+/// assert_eq!(winners.len(), 2);
+/// assert!(!winners.contains(&"Cem"));
+/// assert!(sample_weighted(&tickets, 3, false).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn sample_weighted<T: Clone>(items: &[(T, f64)], k: usize, replacement: bool) -> Result<Vec<T>, SampleError> {
+    sample_weighted_with_rng(items, k, replacement, &mut rand::rng())
+}
+
+/// Like [`sample_weighted`], but draws with the given RNG.
+#[cfg(feature = "std")]
+pub fn sample_weighted_with_rng<T: Clone, R: Rng + ?Sized>(
+    items: &[(T, f64)],
+    k: usize,
+    replacement: bool,
+    rng: &mut R,
+) -> Result<Vec<T>, SampleError> {
+    if let Some(index) = items.iter().position(|(_, w)| !w.is_finite() || *w < 0.0) {
+        return Err(SampleError::InvalidWeight { index });
+    }
+    if replacement {
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+        if items.is_empty() {
+            return Err(SampleError::EmptyInput);
+        }
+        // Dividing by the largest weight first keeps the sum finite for huge weights.
+        let max = items.iter().map(|(_, w)| *w).fold(0.0, f64::max);
+        if max == 0.0 {
+            return Err(SampleError::ZeroWeightSum);
+        }
+        let cumulative: Vec<f64> = items
+            .iter()
+            .scan(0.0, |sum, (_, w)| {
+                *sum += w / max;
+                Some(*sum)
+            })
+            .collect();
+        let total = cumulative[items.len() - 1];
+        return Ok((0..k)
+            .map(|_| {
+                let target = rng.random_range(0.0..total);
+                let i = cumulative.partition_point(|&sum| sum <= target).min(items.len() - 1);
+                items[i].0.clone()
+            })
+            .collect());
+    }
+    let mut keyed: Vec<(f64, &T)> = items
+        .iter()
+        .filter(|(_, w)| *w > 0.0)
+        .map(|(e, w)| ((1.0 - rng.random::<f64>()).ln() / w, e))
+        .collect();
+    if k > keyed.len() {
+        return Err(SampleError::NotEnoughItems { requested: k, available: keyed.len() });
+    }
+    keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+    Ok(keyed.into_iter().take(k).map(|(_, e)| e.clone()).collect())
+}
+
+/// Draws up to `k` items uniformly from an iterator of unknown length in a single pass.
+///
+/// This is reservoir sampling with Algorithm R: the first `k` items fill the reservoir, and the `i`-th item after
+/// that replaces a random one of them with probability `k / i`. Only the reservoir is kept in memory, so the
+/// iterator can be much larger than the available memory. Every `k`-subset of the items is equally likely.
+/// # Arguments
+/// * `iter` - The items to draw from, consumed lazily.
+/// * `k` - The number of items to draw.
+/// * `rng` - The random number generator used for drawing.
+///
+/// # Returns
+/// `k` items in no particular order, or all items if the iterator yields fewer than `k`.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::reservoir_sample;
+/// let lines = "Ann\nBen\nCem\nDana\nEli".lines();
+/// let sample = reservoir_sample(lines, 2, &mut StdRng::seed_from_u64(1));
+/// assert_eq!(sample.len(), 2);
+/// ```
+pub fn reservoir_sample<I: Iterator, R: Rng + ?Sized>(iter: I, k: usize, rng: &mut R) -> Vec<I::Item> {
+    let mut reservoir = Vec::with_capacity(k.min(iter.size_hint().0));
+    for (i, item) in iter.enumerate() {
+        if i < k {
+            reservoir.push(item);
+        } else {
+            let j = rng.random_range(0..=i);
+            if j < k {
+                reservoir[j] = item;
+            }
+        }
+    }
+    reservoir
+}
+
+/// Picks `k` distinct elements of a HashSet as a new set.
+///
+/// Note that the iteration order of a `HashSet` is not stable, so a seeded RNG alone does not make the result
+/// reproducible.
+/// # Arguments
+/// * `set` - The set to pick from. Its elements must implement the `Clone` trait.
+/// * `k` - The number of elements to pick.
+/// * `rng` - The random number generator used for picking.
+///
+/// # Returns
+/// A set of `k` clones of uniformly chosen elements, or [`SampleError::NotEnoughItems`] if `k` exceeds the size of
+/// `set`.
+/// # Example
+/// ```
+/// use rust_helpers::sample_subset;
+/// use std::collections::HashSet;
+/// let guests: HashSet<&str> = ["Ann", "Ben", "Cem", "Dana"].into_iter().collect();
+/// let table = sample_subset(&guests, 2, &mut rand::rng()).unwrap();
+/// assert_eq!(table.len(), 2);
+/// assert!(table.is_subset(&guests));
+/// ```
+#[cfg(feature = "std")]
+pub fn sample_subset<T: Clone + Hash + Eq, R: Rng + ?Sized>(set: &HashSet<T>, k: usize, rng: &mut R) -> Result<HashSet<T>, SampleError> {
+    let items: Vec<&T> = set.iter().collect();
+    if k > items.len() {
+        return Err(SampleError::NotEnoughItems { requested: k, available: items.len() });
+    }
+    Ok(index::sample(rng, items.len(), k).into_iter().map(|i| items[i].clone()).collect())
+}
+
+/// Partitions a HashSet into `k` randomly chosen elements and the rest, moving the elements.
+/// # Arguments
+/// * `set` - The set to partition.
+/// * `k` - The number of elements to choose.
+///
+/// # Returns
+/// The sets `(chosen, rest)`, or [`SampleError::NotEnoughItems`] if `k` exceeds the size of `set`.
+/// # Example
+/// ```
+/// use rust_helpers::take_subset;
+/// use std::collections::HashSet;
+/// let guests: HashSet<String> = ["Ann", "Ben", "Cem"].into_iter().map(String::from).collect();
+/// let (table, rest) = take_subset(guests, 2).unwrap();
+/// assert_eq!((table.len(), rest.len()), (2, 1));
+/// ```
+#[cfg(feature = "std")]
+pub fn take_subset<T: Hash + Eq>(set: HashSet<T>, k: usize) -> Result<(HashSet<T>, HashSet<T>), SampleError> {
+    take_subset_with_rng(set, k, &mut rand::rng())
+}
+
+/// Like [`take_subset`], but chooses with the given RNG.
+#[cfg(feature = "std")]
+pub fn take_subset_with_rng<T: Hash + Eq, R: Rng + ?Sized>(set: HashSet<T>, k: usize, rng: &mut R) -> Result<(HashSet<T>, HashSet<T>), SampleError> {
+    if k > set.len() {
+        return Err(SampleError::NotEnoughItems { requested: k, available: set.len() });
+    }
+    let mut items: Vec<T> = set.into_iter().collect();
+    partial_shuffle_k(&mut items, k, rng);
+    let rest = items.split_off(k);
+    Ok((items.into_iter().collect(), rest.into_iter().collect()))
+}
+
+/// Marks `k` uniformly chosen positions out of `len`.
+///
+/// Useful to decide which rows of a dataset go to a holdout set without moving the rows themselves.
+/// # Arguments
+/// * `len` - The number of positions.
+/// * `k` - The number of positions to mark.
+/// * `rng` - The random number generator used for choosing.
+///
+/// # Returns
+/// A vector of `len` booleans with exactly `k` of them `true`, or [`SampleError::NotEnoughItems`] if `k` exceeds
+/// `len`.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::random_mask;
+/// let rows = ["r0", "r1", "r2", "r3", "r4"];
+/// let holdout = random_mask(rows.len(), 2, &mut StdRng::seed_from_u64(4)).unwrap();
+/// let held_out: Vec<&str> = rows.iter().zip(&holdout).filter(|(_, out)| **out).map(|(row, _)| *row).collect();
+/// assert_eq!(held_out.len(), 2);
+/// ```
+pub fn random_mask<R: Rng + ?Sized>(len: usize, k: usize, rng: &mut R) -> Result<Vec<bool>, SampleError> {
+    if k > len {
+        return Err(SampleError::NotEnoughItems { requested: k, available: len });
+    }
+    let mut mask = vec![false; len];
+    for i in index::sample(rng, len, k) {
+        mask[i] = true;
+    }
+    Ok(mask)
+}
+
+/// Marks every position out of `len` independently with probability `p`.
+///
+/// Unlike [`random_mask`], the number of marked positions is random itself.
+/// # Arguments
+/// * `len` - The number of positions.
+/// * `p` - The probability of each position to be marked.
+/// * `rng` - The random number generator used for choosing.
+///
+/// # Returns
+/// A vector of `len` booleans, or [`SampleError::InvalidProbability`] if `p` is not between 0.0 and 1.0.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::random_mask_p;
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let flagged = random_mask_p(1000, 0.1, &mut rng).unwrap();
+/// assert_eq!(flagged.len(), 1000);
+/// assert!(random_mask_p(10, 1.5, &mut rng).is_err());
+/// ```
+pub fn random_mask_p<R: Rng + ?Sized>(len: usize, p: f64, rng: &mut R) -> Result<Vec<bool>, SampleError> {
+    if !(0.0..=1.0).contains(&p) {
+        return Err(SampleError::InvalidProbability);
+    }
+    Ok((0..len).map(|_| rng.random_bool(p)).collect())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_choose_random() {
+        assert_eq!(choose_random::<u32>(&[]), None);
+        assert_eq!(choose_random(&[7]), Some(&7));
+        let items: Vec<usize> = (0..5).collect();
+        let mut counts = [0_u32; 5];
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..2500 {
+            counts[*choose_random_with_rng(&items, &mut rng).unwrap()] += 1;
+        }
+        assert!(counts.iter().all(|&count| (400..600).contains(&count)), "{:?}", counts);
+    }
+
+    #[test]
+    fn test_choose_random_mut() {
+        assert_eq!(choose_random_mut::<u32>(&mut []), None);
+        let mut single = [1];
+        *choose_random_mut(&mut single).unwrap() = 2;
+        assert_eq!(single, [2]);
+        let mut items = [0; 4];
+        for _ in 0..100 {
+            *choose_random_mut(&mut items).unwrap() += 1;
+        }
+        assert_eq!(items.iter().sum::<i32>(), 100);
+    }
+
+    #[test]
+    fn test_choose_random_index() {
+        assert_eq!(choose_random_index(0), None);
+        assert_eq!(choose_random_index(1), Some(0));
+        assert!((0..100).all(|_| choose_random_index(3).is_some_and(|i| i < 3)));
+        let mut rng = StdRng::seed_from_u64(4);
+        let first: Vec<_> = (0..10).map(|_| choose_random_index_with_rng(9, &mut rng)).collect();
+        let mut rng = StdRng::seed_from_u64(4);
+        assert!(first.into_iter().eq((0..10).map(|_| choose_random_index_with_rng(9, &mut rng))));
+    }
+
+    #[test]
+    fn test_pop_random_until_empty() {
+        let mut vec: Vec<u32> = (0..20).collect();
+        let mut popped = Vec::new();
+        while let Some(e) = pop_random(&mut vec) {
+            popped.push(e);
+        }
+        assert_ne!(popped, (0..20).rev().collect::<Vec<_>>());
+        popped.sort();
+        assert_eq!(popped, (0..20).collect::<Vec<_>>());
+        assert_eq!(pop_random::<u32>(&mut Vec::new()), None);
+    }
+
+    #[test]
+    fn test_pop_random_stable_until_empty() {
+        let mut vec: Vec<u32> = (0..20).collect();
+        let mut popped = Vec::new();
+        let mut rng = StdRng::seed_from_u64(1);
+        while let Some(e) = pop_random_stable_with_rng(&mut vec, &mut rng) {
+            assert!(vec.windows(2).all(|w| w[0] < w[1]));
+            popped.push(e);
+        }
+        popped.sort();
+        assert_eq!(popped, (0..20).collect::<Vec<_>>());
+        assert_eq!(pop_random_stable::<u32>(&mut Vec::new()), None);
+    }
+
+    #[test]
+    fn test_drain_random_n() {
+        let original: Vec<u32> = (0..15).collect();
+        for k in [0, 1, 6, 15] {
+            let mut vec = original.clone();
+            let drawn = drain_random_n(&mut vec, k).unwrap();
+            assert_eq!((drawn.len(), vec.len()), (k, 15 - k));
+            let mut all = [drawn, vec].concat();
+            all.sort();
+            assert_eq!(all, original);
+        }
+        let mut vec = original.clone();
+        assert_eq!(drain_random_n(&mut vec, 16), Err(SampleError::NotEnoughItems { requested: 16, available: 15 }));
+        assert_eq!(vec, original);
+    }
+
+    #[test]
+    fn test_drain_random_n_stable() {
+        let original: Vec<u32> = (0..15).collect();
+        let mut rng = StdRng::seed_from_u64(8);
+        for k in [0, 1, 6, 15] {
+            let mut vec = original.clone();
+            let drawn = drain_random_n_stable_with_rng(&mut vec, k, &mut rng).unwrap();
+            assert_eq!(drawn.len(), k);
+            assert!(vec.windows(2).all(|w| w[0] < w[1]));
+            let mut all = [drawn, vec].concat();
+            all.sort();
+            assert_eq!(all, original);
+        }
+        let mut vec = original.clone();
+        assert!(drain_random_n_stable(&mut vec, 16).is_err());
+        assert_eq!(vec, original);
+    }
+
+    #[test]
+    fn test_sample_n() {
+        let items: Vec<u32> = (0..10).collect();
+        assert!(sample_n(&items, 0).unwrap().is_empty());
+        let all = sample_n(&items, 10).unwrap();
+        assert_eq!(all.iter().collect::<HashSet<_>>().len(), 10);
+        assert_eq!(sample_n(&items, 11), Err(SampleError::NotEnoughItems { requested: 11, available: 10 }));
+        assert_eq!(sample_n::<u32>(&[], 0), Ok(Vec::new()));
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let picked = sample_n_with_rng(&items, 4, &mut rng).unwrap();
+        assert_eq!(picked, sample_n_with_rng(&items, 4, &mut StdRng::seed_from_u64(3)).unwrap());
+        assert_eq!(picked.iter().collect::<HashSet<_>>().len(), 4);
+    }
+
+    #[test]
+    fn test_sample_n_draws_like_partial_shuffle_k() {
+        let items: Vec<u32> = (0..20).collect();
+        for (k, seed) in [(0, 1), (1, 2), (5, 3), (19, 4), (20, 5)] {
+            let mut shuffled = items.clone();
+            let (front, _) = partial_shuffle_k(&mut shuffled, k, &mut StdRng::seed_from_u64(seed));
+            assert_eq!(sample_n_with_rng(&items, k, &mut StdRng::seed_from_u64(seed)).unwrap(), front);
+        }
+    }
+
+    #[test]
+    fn test_sample_n_owned() {
+        let vec: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+        assert!(sample_n_owned(vec.clone(), 0).unwrap().is_empty());
+        let mut all = sample_n_owned(vec.clone(), 10).unwrap();
+        all.sort();
+        let mut sorted = vec.clone();
+        sorted.sort();
+        assert_eq!(all, sorted);
+        assert_eq!(sample_n_owned(vec.clone(), 11), Err(SampleError::NotEnoughItems { requested: 11, available: 10 }));
+        let picked = sample_n_owned(vec.clone(), 3).unwrap();
+        assert_eq!(picked.iter().collect::<HashSet<_>>().len(), 3);
+        assert!(picked.iter().all(|name| vec.contains(name)));
+    }
+
+    #[test]
+    fn test_sample_n_with_replacement() {
+        let items: Vec<u32> = (0..5).collect();
+        for k in [0, 1, 5, 6, 50] {
+            assert_eq!(sample_n_with_replacement(&items, k).unwrap().len(), k);
+        }
+        let drawn = sample_n_with_replacement(&items, 20).unwrap();
+        assert!(drawn.iter().collect::<HashSet<_>>().len() < drawn.len());
+        assert!(drawn.iter().all(|x| items.contains(x)));
+
+        assert_eq!(sample_n_with_replacement::<u32>(&[], 3), Err(SampleError::EmptyInput));
+        assert_eq!(sample_n_with_replacement::<u32>(&[], 0), Ok(Vec::new()));
+        assert_eq!(
+            sample_n_with_replacement_seeded(&items, 8, 1),
+            sample_n_with_replacement_with_rng(&items, 8, &mut StdRng::seed_from_u64(1))
+        );
+    }
+
+    #[test]
+    fn test_sample_weighted_errors() {
+        let items = [("a", 1.0), ("b", 0.0)];
+        assert_eq!(sample_weighted(&items, 2, false), Err(SampleError::NotEnoughItems { requested: 2, available: 1 }));
+        assert_eq!(sample_weighted(&items, 1, false), Ok(vec!["a"]));
+        assert_eq!(sample_weighted(&[("a", 1.0), ("b", -1.0)], 1, false), Err(SampleError::InvalidWeight { index: 1 }));
+        assert_eq!(sample_weighted(&[("a", f64::NAN)], 1, true), Err(SampleError::InvalidWeight { index: 0 }));
+        assert_eq!(sample_weighted(&[("a", 0.0)], 1, true), Err(SampleError::ZeroWeightSum));
+        assert_eq!(sample_weighted::<&str>(&[], 1, true), Err(SampleError::EmptyInput));
+        assert_eq!(sample_weighted::<&str>(&[], 0, true), Ok(Vec::new()));
+        assert_eq!(sample_weighted(&items, 5, true), Ok(vec!["a"; 5]));
+    }
+
+    #[test]
+    fn test_sample_weighted_favors_heavy_items() {
+        let items = [("heavy", 100.0), ("light 1", 1.0), ("light 2", 1.0), ("never", 0.0)];
+        let mut rng = StdRng::seed_from_u64(9);
+        let mut first = 0;
+        let mut drawn = 0;
+        for _ in 0..1000 {
+            let winners = sample_weighted_with_rng(&items, 2, false, &mut rng).unwrap();
+            assert_ne!(winners[0], winners[1]);
+            assert!(!winners.contains(&"never"));
+            first += u32::from(winners[0] == "heavy");
+            let rolls = sample_weighted_with_rng(&items, 10, true, &mut rng).unwrap();
+            assert!(!rolls.contains(&"never"));
+            drawn += rolls.iter().filter(|&&roll| roll == "heavy").count();
+        }
+        // The heavy item wins the first draw with probability 100/102.
+        assert!(first > 950, "{}", first);
+        assert!(drawn > 9500, "{}", drawn);
+    }
+
+    #[test]
+    fn test_sample_weighted_with_huge_weights() {
+        let items = [("a", f64::MAX), ("b", f64::MAX), ("never", 0.0)];
+        let mut rng = StdRng::seed_from_u64(10);
+        let rolls = sample_weighted_with_rng(&items, 1000, true, &mut rng).unwrap();
+        let a = rolls.iter().filter(|&&roll| roll == "a").count();
+        assert!((400..600).contains(&a), "{}", a);
+        assert!(!rolls.contains(&"never"));
+        let mut picked = sample_weighted_with_rng(&items, 2, false, &mut rng).unwrap();
+        picked.sort();
+        assert_eq!(picked, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_reservoir_sample() {
+        let mut rng = StdRng::seed_from_u64(6);
+        let sample = reservoir_sample(0..1_000_000_u32, 10, &mut rng);
+        assert_eq!(sample.len(), 10);
+        assert!(sample.capacity() >= 10);
+        assert_eq!(sample.iter().collect::<HashSet<_>>().len(), 10);
+        assert!(sample.iter().any(|&x| x >= 10));
+
+        let mut short = reservoir_sample(0..3_u32, 10, &mut rng);
+        short.sort();
+        assert_eq!(short, vec![0, 1, 2]);
+        assert!(reservoir_sample(0..3_u32, 0, &mut rng).is_empty());
+        assert!(reservoir_sample(std::iter::empty::<u32>(), 2, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_reservoir_sample_is_uniform() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut counts = [0_u32; 6];
+        for _ in 0..3000 {
+            // The filter hides the length from the size hint, like a stream of unknown size.
+            for i in reservoir_sample((0..6_usize).filter(|_| true), 2, &mut rng) {
+                counts[i] += 1;
+            }
+        }
+        // Each item is expected 1000 times.
+        assert!(counts.iter().all(|&count| (880..1120).contains(&count)), "{:?}", counts);
+    }
+
+    #[test]
+    fn test_sample_subset_is_a_subset() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let set: HashSet<u32> = (0..20).collect();
+        for k in [0, 1, 7, 20] {
+            let subset = sample_subset(&set, k, &mut rng).unwrap();
+            assert_eq!(subset.len(), k);
+            assert!(subset.is_subset(&set));
+        }
+        assert_eq!(sample_subset(&set, 21, &mut rng), Err(SampleError::NotEnoughItems { requested: 21, available: 20 }));
+    }
+
+    #[test]
+    fn test_take_subset_partitions_the_set() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let set: HashSet<String> = (0..15).map(|i| i.to_string()).collect();
+        for k in [0, 4, 15] {
+            let (chosen, rest) = take_subset_with_rng(set.clone(), k, &mut rng).unwrap();
+            assert_eq!(chosen.len(), k);
+            assert!(chosen.is_disjoint(&rest));
+            assert_eq!(chosen.union(&rest).cloned().collect::<HashSet<_>>(), set);
+        }
+        assert_eq!(take_subset(set, 16), Err(SampleError::NotEnoughItems { requested: 16, available: 15 }));
+    }
+
+    #[test]
+    fn test_random_mask_marks_exactly_k() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let mut counts = [0_u32; 10];
+        for _ in 0..2000 {
+            let mask = random_mask(10, 3, &mut rng).unwrap();
+            assert_eq!(mask.len(), 10);
+            assert_eq!(mask.iter().filter(|&&marked| marked).count(), 3);
+            for (i, _) in mask.iter().enumerate().filter(|(_, marked)| **marked) {
+                counts[i] += 1;
+            }
+        }
+        // Each position is expected 600 times.
+        assert!(counts.iter().all(|&count| (520..680).contains(&count)), "{:?}", counts);
+        assert_eq!(random_mask(4, 4, &mut rng), Ok(vec![true; 4]));
+        assert_eq!(random_mask(0, 0, &mut rng), Ok(vec![]));
+        assert_eq!(random_mask(3, 4, &mut rng), Err(SampleError::NotEnoughItems { requested: 4, available: 3 }));
+    }
+
+    #[test]
+    fn test_random_mask_p_rate() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let marked = random_mask_p(10_000, 0.3, &mut rng).unwrap().into_iter().filter(|&m| m).count();
+        // The standard deviation of the count is about 46.
+        assert!((2800..3200).contains(&marked), "{}", marked);
+        assert_eq!(random_mask_p(5, 0.0, &mut rng), Ok(vec![false; 5]));
+        assert_eq!(random_mask_p(5, 1.0, &mut rng), Ok(vec![true; 5]));
+        assert_eq!(random_mask_p(5, -0.1, &mut rng), Err(SampleError::InvalidProbability));
+        assert_eq!(random_mask_p(5, f64::NAN, &mut rng), Err(SampleError::InvalidProbability));
+    }
+
+    #[test]
+    fn test_sample_n_picks_every_element() {
+        let items: Vec<usize> = (0..8).collect();
+        let mut counts = [0_u32; 8];
+        let mut owned_counts = [0_u32; 8];
+        for _ in 0..2000 {
+            for i in sample_n(&items, 2).unwrap() {
+                counts[i] += 1;
+            }
+            for i in sample_n_owned(items.clone(), 2).unwrap() {
+                owned_counts[i] += 1;
+            }
+        }
+        // Each element is expected 500 times.
+        assert!(counts.iter().all(|&count| (380..620).contains(&count)), "{:?}", counts);
+        assert!(owned_counts.iter().all(|&count| (380..620).contains(&count)), "{:?}", owned_counts);
+    }
+}
@@ -0,0 +1,512 @@
+//! Helpers for grouping and pairing people, including over several rounds.
+
+use crate::{eq_part_sizes, SplitError};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand::seq::SliceRandom;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// How many greedy assignments [`split_rand_vec_eq_avoiding`] tries before returning the best one.
+const AVOIDING_ATTEMPTS: usize = 20;
+
+/// Randomizes the order of a vec and splits it into parts of equal size, avoiding pairs that were together before.
+///
+/// Each attempt assigns the shuffled elements one by one to the non-full part with the fewest previous partners,
+/// breaking ties randomly. The best of a fixed number of attempts is returned, or the first one without repeated
+/// pairs, so the function always terminates even when every grouping repeats some pairs.
+/// # Arguments
+/// * `vec` - The vector to be split and randomized.
+/// * `parts` - The number of parts to split the vector into. The first `len % parts` parts contain one element
+///   more than the others.
+/// * `history` - Previous groupings. Elements that are not in `vec` are ignored.
+///
+/// # Returns
+/// A tuple with the `parts` vectors and the number of distinct pairs in them that already shared a part in
+/// `history`.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_split_rand_vec_eq_avoiding`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::split_rand_vec_eq_avoiding;
+/// let history = vec![vec![vec!["Ann", "Ben"], vec!["Cem", "Dana"]]];
+/// let (groups, repeats) = split_rand_vec_eq_avoiding(vec!["Ann", "Ben", "Cem", "Dana"], 2, &history);
+/// assert_eq!(repeats, 0);
+/// assert!(groups.iter().all(|group| !(group.contains(&"Ann") && group.contains(&"Ben"))));
+/// ```
+pub fn split_rand_vec_eq_avoiding<T: Hash + Eq>(vec: Vec<T>, parts: usize, history: &[Vec<Vec<T>>]) -> (Vec<Vec<T>>, usize) {
+    split_rand_vec_eq_avoiding_with_rng(vec, parts, history, &mut rand::rng())
+}
+
+/// Like [`split_rand_vec_eq_avoiding`], but shuffles with the given RNG.
+pub fn split_rand_vec_eq_avoiding_with_rng<T: Hash + Eq, R: Rng + ?Sized>(
+    vec: Vec<T>,
+    parts: usize,
+    history: &[Vec<Vec<T>>],
+    rng: &mut R,
+) -> (Vec<Vec<T>>, usize) {
+    let sizes = eq_part_sizes(vec.len(), parts);
+    let mut ids: HashMap<&T, usize> = HashMap::with_capacity(vec.len());
+    for (i, e) in vec.iter().enumerate() {
+        ids.entry(e).or_insert(i);
+    }
+    let met = pairs_together(history, &ids);
+
+    let mut best = assign_avoiding(vec.len(), &sizes, &met, rng);
+    for _ in 1..AVOIDING_ATTEMPTS {
+        if best.1 == 0 {
+            break;
+        }
+        let attempt = assign_avoiding(vec.len(), &sizes, &met, rng);
+        if attempt.1 < best.1 {
+            best = attempt;
+        }
+    }
+    let (members, repeats) = best;
+    let mut elements: Vec<Option<T>> = vec.into_iter().map(Some).collect();
+    let result = members
+        .into_iter()
+        .map(|part| part.into_iter().map(|i| elements[i].take().expect("every element is assigned once")).collect())
+        .collect();
+    (result, repeats)
+}
+
+/// Like [`split_rand_vec_eq_avoiding`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_split_rand_vec_eq_avoiding, SplitError};
+/// assert_eq!(try_split_rand_vec_eq_avoiding(vec![1, 2], 0, &[]), Err(SplitError::ZeroParts));
+/// assert_eq!(try_split_rand_vec_eq_avoiding(vec![1, 2], 2, &[]).unwrap().1, 0);
+/// ```
+pub fn try_split_rand_vec_eq_avoiding<T: Hash + Eq>(vec: Vec<T>, parts: usize, history: &[Vec<Vec<T>>]) -> Result<(Vec<Vec<T>>, usize), SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(split_rand_vec_eq_avoiding(vec, parts, history))
+}
+
+/// Produces `rounds` successive groupings of `vec` that let as many distinct pairs as possible share a part.
+///
+/// Every round is a split into parts of equal size made with [`split_rand_vec_eq_avoiding`], using the previous
+/// rounds as history. This is a greedy heuristic, not an exact solver, and different calls give different schedules.
+/// # Arguments
+/// * `vec` - The elements to be grouped in every round.
+/// * `parts` - The number of parts per round.
+/// * `rounds` - The number of rounds.
+///
+/// # Returns
+/// A vector of `rounds` groupings, each a vector of `parts` vectors.
+/// # Panics
+/// Panics if `parts` is 0. Use [`try_schedule_rounds`] to get an error instead.
+/// # Example
+/// ```
+/// use rust_helpers::{pair_coverage, schedule_rounds};
+/// let schedule = schedule_rounds(vec![1, 2, 3, 4], 2, 3);
+/// assert_eq!(schedule.len(), 3);
+/// assert_eq!(pair_coverage(&schedule), 6);
+/// ```
+pub fn schedule_rounds<T: Hash + Eq + Clone>(vec: Vec<T>, parts: usize, rounds: usize) -> Vec<Vec<Vec<T>>> {
+    schedule_rounds_with_rng(vec, parts, rounds, &mut rand::rng())
+}
+
+/// Like [`schedule_rounds`], but shuffles with the given RNG.
+pub fn schedule_rounds_with_rng<T: Hash + Eq + Clone, R: Rng + ?Sized>(vec: Vec<T>, parts: usize, rounds: usize, rng: &mut R) -> Vec<Vec<Vec<T>>> {
+    let mut schedule = Vec::with_capacity(rounds);
+    for _ in 0..rounds {
+        let (round, _) = split_rand_vec_eq_avoiding_with_rng(vec.clone(), parts, &schedule, rng);
+        schedule.push(round);
+    }
+    schedule
+}
+
+/// Like [`schedule_rounds`], but returns [`SplitError::ZeroParts`] instead of panicking if `parts` is 0.
+/// # Example
+/// ```
+/// use rust_helpers::{try_schedule_rounds, SplitError};
+/// assert_eq!(try_schedule_rounds(vec![1, 2], 0, 3), Err(SplitError::ZeroParts));
+/// assert_eq!(try_schedule_rounds(vec![1, 2], 2, 3).unwrap().len(), 3);
+/// ```
+pub fn try_schedule_rounds<T: Hash + Eq + Clone>(vec: Vec<T>, parts: usize, rounds: usize) -> Result<Vec<Vec<Vec<T>>>, SplitError> {
+    if parts == 0 {
+        return Err(SplitError::ZeroParts);
+    }
+    Ok(schedule_rounds(vec, parts, rounds))
+}
+
+/// Counts the distinct pairs of elements that share a part in at least one round.
+/// # Arguments
+/// * `schedule` - The rounds, each a vector of parts.
+///
+/// # Returns
+/// The number of distinct unordered pairs of different elements that were in the same part at least once.
+/// # Example
+/// ```
+/// use rust_helpers::pair_coverage;
+/// let schedule = vec![vec![vec![1, 2], vec![3, 4]], vec![vec![1, 2], vec![3, 5]]];
+/// assert_eq!(pair_coverage(&schedule), 3);
+/// ```
+pub fn pair_coverage<T: Hash + Eq>(schedule: &[Vec<Vec<T>>]) -> usize {
+    let mut ids: HashMap<&T, usize> = HashMap::new();
+    for e in schedule.iter().flatten().flatten() {
+        let next = ids.len();
+        ids.entry(e).or_insert(next);
+    }
+    pairs_together(schedule, &ids).len()
+}
+
+/// Shuffles a vec and pairs up consecutive elements.
+///
+/// Unlike `split_rand_vec(vec, 2)`, the pairs are typed and an odd element out is returned separately instead of as
+/// a trailing chunk of one.
+/// # Arguments
+/// * `vec` - The elements to be paired.
+///
+/// # Returns
+/// A tuple with the random pairs and the leftover element if the number of elements is odd.
+/// # Example
+/// ```
+/// use rust_helpers::pair_rand;
+/// let (pairs, leftover) = pair_rand(vec!["Ann", "Ben", "Cem", "Dana", "Eli"]);
+/// assert_eq!(pairs.len(), 2);
+/// assert!(leftover.is_some());
+/// ```
+pub fn pair_rand<T>(vec: Vec<T>) -> (Vec<(T, T)>, Option<T>) {
+    pair_rand_with_rng(vec, &mut rand::rng())
+}
+
+/// Like [`pair_rand`], but shuffles with the given RNG.
+pub fn pair_rand_with_rng<T, R: Rng + ?Sized>(mut vec: Vec<T>, rng: &mut R) -> (Vec<(T, T)>, Option<T>) {
+    vec.shuffle(rng);
+    let leftover = if vec.len() % 2 == 1 { vec.pop() } else { None };
+    let mut pairs = Vec::with_capacity(vec.len() / 2);
+    let mut elements = vec.into_iter();
+    while let (Some(a), Some(b)) = (elements.next(), elements.next()) {
+        pairs.push((a, b));
+    }
+    (pairs, leftover)
+}
+
+/// Shuffles a vec and groups it into pairs, folding an odd element out into one group of three.
+/// # Arguments
+/// * `vec` - The elements to be paired.
+///
+/// # Returns
+/// Random groups of two elements, with one group of three if the number of elements is odd. A single element forms a
+/// group of its own, and an empty vec gives no groups.
+/// # Example
+/// ```
+/// use rust_helpers::pair_rand_with_triple;
+/// let groups = pair_rand_with_triple(vec!["Ann", "Ben", "Cem", "Dana", "Eli"]);
+/// assert_eq!(groups.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 3]);
+/// ```
+pub fn pair_rand_with_triple<T>(vec: Vec<T>) -> Vec<Vec<T>> {
+    pair_rand_with_triple_with_rng(vec, &mut rand::rng())
+}
+
+/// Like [`pair_rand_with_triple`], but shuffles with the given RNG.
+pub fn pair_rand_with_triple_with_rng<T, R: Rng + ?Sized>(vec: Vec<T>, rng: &mut R) -> Vec<Vec<T>> {
+    let (pairs, leftover) = pair_rand_with_rng(vec, rng);
+    let mut groups: Vec<Vec<T>> = pairs.into_iter().map(|(a, b)| vec![a, b]).collect();
+    match (groups.last_mut(), leftover) {
+        (Some(last), Some(e)) => last.push(e),
+        (None, Some(e)) => groups.push(vec![e]),
+        (_, None) => {}
+    }
+    groups
+}
+
+/// The pairings of one round of a [`round_robin_schedule`]. A player without an opponent sits out the round.
+pub type Pairings<T> = Vec<(T, Option<T>)>;
+
+/// Produces a round-robin tournament schedule in which every player meets every other player exactly once.
+///
+/// The schedule follows the circle method with a random initial seating, so different calls give different schedules.
+/// With an odd number of players one player sits out each round, paired with `None`.
+/// # Arguments
+/// * `vec` - The players.
+///
+/// # Returns
+/// One vector of pairings per round. There are `len - 1` rounds for an even number of players and `len` rounds for
+/// an odd number. Every player appears at most once per round, and no pairing repeats.
+/// # Example
+/// ```
+/// use rust_helpers::round_robin_schedule;
+/// let schedule = round_robin_schedule(vec!["Ann", "Ben", "Cem"]);
+/// assert_eq!(schedule.len(), 3);
+/// assert!(schedule.iter().all(|round| round.iter().filter(|(_, opponent)| opponent.is_none()).count() == 1));
+/// ```
+pub fn round_robin_schedule<T: Clone>(vec: Vec<T>) -> Vec<Pairings<T>> {
+    round_robin_schedule_with_rng(vec, &mut rand::rng())
+}
+
+/// Like [`round_robin_schedule`], but shuffles with the given RNG.
+pub fn round_robin_schedule_with_rng<T: Clone, R: Rng + ?Sized>(vec: Vec<T>, rng: &mut R) -> Vec<Pairings<T>> {
+    let mut seats: Vec<Option<T>> = vec.into_iter().map(Some).collect();
+    if seats.len() % 2 == 1 {
+        seats.push(None);
+    }
+    seats.shuffle(rng);
+    let n = seats.len();
+    let mut schedule = Vec::with_capacity(n.saturating_sub(1));
+    for _ in 1..n {
+        let round = (0..n / 2)
+            .filter_map(|i| match (&seats[i], &seats[n - 1 - i]) {
+                (Some(a), b) => Some((a.clone(), b.clone())),
+                (None, Some(b)) => Some((b.clone(), None)),
+                (None, None) => None,
+            })
+            .collect();
+        schedule.push(round);
+        seats[1..].rotate_right(1);
+    }
+    schedule
+}
+
+/// Picks a random leader for each part.
+/// # Arguments
+/// * `parts` - The parts, for example from [`split_rand_vec_eq`](crate::split_rand_vec_eq).
+/// * `skip_empty` - Whether empty parts are left out of the result instead of causing an error.
+///
+/// # Returns
+/// For each part, a tuple of the leader, chosen uniformly at random, and the other members in their original order.
+/// Returns [`SplitError::EmptyPart`] if a part is empty and `skip_empty` is false.
+/// # Example
+/// ```
+/// use rust_helpers::assign_leaders;
+/// let groups = vec![vec!["Ann", "Ben"], vec!["Cem"]];
+/// let led = assign_leaders(groups, false).unwrap();
+/// assert_eq!(led[0].1.len(), 1);
+/// assert_eq!(led[1], ("Cem", vec![]));
+/// ```
+pub fn assign_leaders<T>(parts: Vec<Vec<T>>, skip_empty: bool) -> Result<Vec<(T, Vec<T>)>, SplitError> {
+    assign_leaders_with_rng(parts, skip_empty, &mut rand::rng())
+}
+
+/// Like [`assign_leaders`], but picks the leaders with the given RNG.
+pub fn assign_leaders_with_rng<T, R: Rng + ?Sized>(parts: Vec<Vec<T>>, skip_empty: bool, rng: &mut R) -> Result<Vec<(T, Vec<T>)>, SplitError> {
+    let mut result = Vec::with_capacity(parts.len());
+    for (index, mut part) in parts.into_iter().enumerate() {
+        if part.is_empty() {
+            if skip_empty {
+                continue;
+            }
+            return Err(SplitError::EmptyPart { index });
+        }
+        let leader = part.remove(rng.random_range(0..part.len()));
+        result.push((leader, part));
+    }
+    Ok(result)
+}
+
+/// Like [`assign_leaders`], but picks the leaders with an RNG seeded from `seed`, so equal inputs give equal leaders.
+/// # Example
+/// ```
+/// use rust_helpers::assign_leaders_seeded;
+/// let groups = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// assert_eq!(assign_leaders_seeded(groups.clone(), false, 4), assign_leaders_seeded(groups, false, 4));
+/// ```
+pub fn assign_leaders_seeded<T>(parts: Vec<Vec<T>>, skip_empty: bool, seed: u64) -> Result<Vec<(T, Vec<T>)>, SplitError> {
+    assign_leaders_with_rng(parts, skip_empty, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Collects the pairs of ids, smaller id first, of elements that share a part in any of the rounds.
+fn pairs_together<T: Hash + Eq>(rounds: &[Vec<Vec<T>>], ids: &HashMap<&T, usize>) -> HashSet<(usize, usize)> {
+    let mut pairs = HashSet::new();
+    for part in rounds.iter().flatten() {
+        let members: Vec<usize> = part.iter().filter_map(|e| ids.get(e).copied()).collect();
+        for (k, &a) in members.iter().enumerate() {
+            for &b in &members[k + 1..] {
+                if a != b {
+                    pairs.insert((a.min(b), a.max(b)));
+                }
+            }
+        }
+    }
+    pairs
+}
+
+/// Greedily assigns the ids `0..len` in random order to the non-full part with the fewest pairs in `met`.
+/// Returns the ids of every part and the number of pairs in `met` that ended up together.
+fn assign_avoiding<R: Rng + ?Sized>(len: usize, sizes: &[usize], met: &HashSet<(usize, usize)>, rng: &mut R) -> (Vec<Vec<usize>>, usize) {
+    let mut order: Vec<usize> = (0..len).collect();
+    order.shuffle(rng);
+    let mut members: Vec<Vec<usize>> = sizes.iter().map(|&size| Vec::with_capacity(size)).collect();
+    let mut repeats = 0;
+    for i in order {
+        let scores: Vec<(usize, usize)> = (0..sizes.len())
+            .filter(|&part| members[part].len() < sizes[part])
+            .map(|part| (part, members[part].iter().filter(|&&j| met.contains(&(i.min(j), i.max(j)))).count()))
+            .collect();
+        let lowest = scores.iter().map(|&(_, score)| score).min().expect("there is a free slot for every element");
+        let candidates: Vec<usize> = scores.iter().filter(|&&(_, score)| score == lowest).map(|&(part, _)| part).collect();
+        members[candidates[rng.random_range(0..candidates.len())]].push(i);
+        repeats += lowest;
+    }
+    (members, repeats)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_rand_vec_eq_avoiding() {
+        let people = vec!['a', 'b', 'c', 'd'];
+        let mut history = vec![vec![vec!['a', 'b'], vec!['c', 'd']], vec![vec!['a', 'c'], vec!['b', 'd']]];
+        for _ in 0..50 {
+            let (groups, repeats) = split_rand_vec_eq_avoiding(people.clone(), 2, &history);
+            assert_eq!(repeats, 0);
+            let mut groups: Vec<Vec<char>> = groups
+                .into_iter()
+                .map(|mut group| {
+                    group.sort();
+                    group
+                })
+                .collect();
+            groups.sort();
+            assert_eq!(groups, vec![vec!['a', 'd'], vec!['b', 'c']]);
+        }
+
+        history.push(vec![vec!['a', 'd'], vec!['b', 'c']]);
+        let (groups, repeats) = split_rand_vec_eq_avoiding(people, 2, &history);
+        assert_eq!(groups.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 2]);
+        assert_eq!(repeats, 2);
+    }
+
+    #[test]
+    fn test_split_rand_vec_eq_avoiding_ignores_unknown_elements() {
+        let history = vec![vec![vec![1, 9], vec![2, 8]]];
+        let (groups, repeats) = split_rand_vec_eq_avoiding(vec![1, 2, 3], 1, &history);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(repeats, 0);
+    }
+
+    #[test]
+    fn test_schedule_rounds() {
+        let schedule = schedule_rounds((0..6).collect::<Vec<u32>>(), 1, 1);
+        assert_eq!(pair_coverage(&schedule), 15);
+
+        let schedule = schedule_rounds((0..12).collect::<Vec<u32>>(), 3, 5);
+        for round in &schedule {
+            assert_eq!(round.iter().map(Vec::len).collect::<Vec<_>>(), vec![4, 4, 4]);
+        }
+        let coverage: Vec<usize> = (1..=schedule.len()).map(|k| pair_coverage(&schedule[..k])).collect();
+        assert_eq!(coverage[0], 18);
+        assert!(coverage.windows(2).all(|w| w[0] <= w[1]), "coverage {:?}", coverage);
+        assert!(coverage[4] > coverage[0]);
+    }
+
+    #[test]
+    fn test_schedule_rounds_is_random() {
+        let schedules: HashSet<Vec<Vec<Vec<u32>>>> = (0..20).map(|_| schedule_rounds((0..8).collect(), 2, 2)).collect();
+        assert!(schedules.len() > 1);
+    }
+
+    #[test]
+    fn test_pair_rand() {
+        let (pairs, leftover) = pair_rand((0..6).collect::<Vec<u32>>());
+        assert_eq!((pairs.len(), leftover), (3, None));
+        let mut all: Vec<u32> = pairs.into_iter().flat_map(|(a, b)| [a, b]).collect();
+        all.sort();
+        assert_eq!(all, (0..6).collect::<Vec<_>>());
+
+        let (pairs, leftover) = pair_rand((0..7).collect::<Vec<u32>>());
+        assert_eq!(pairs.len(), 3);
+        let mut all: Vec<u32> = pairs.into_iter().flat_map(|(a, b)| [a, b]).chain(leftover).collect();
+        all.sort();
+        assert_eq!(all, (0..7).collect::<Vec<_>>());
+
+        assert_eq!(pair_rand(Vec::<u32>::new()), (Vec::new(), None));
+    }
+
+    #[test]
+    fn test_pair_rand_with_triple() {
+        let sizes = |groups: Vec<Vec<u32>>| groups.iter().map(Vec::len).collect::<Vec<_>>();
+        assert_eq!(sizes(pair_rand_with_triple((0..6).collect())), vec![2, 2, 2]);
+        assert_eq!(sizes(pair_rand_with_triple((0..7).collect())), vec![2, 2, 3]);
+        assert_eq!(sizes(pair_rand_with_triple(vec![0])), vec![1]);
+        assert!(pair_rand_with_triple(Vec::<u32>::new()).is_empty());
+        let mut all: Vec<u32> = pair_rand_with_triple((0..9).collect()).concat();
+        all.sort();
+        assert_eq!(all, (0..9).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_round_robin_schedule() {
+        for players in [4u32, 5, 6] {
+            let schedule = round_robin_schedule((0..players).collect());
+            let expected_rounds = if players % 2 == 0 { players - 1 } else { players };
+            assert_eq!(schedule.len(), expected_rounds as usize);
+
+            let mut met = HashSet::new();
+            let mut byes = HashSet::new();
+            for round in &schedule {
+                let mut seen = HashSet::new();
+                for &(a, b) in round {
+                    assert!(seen.insert(a));
+                    match b {
+                        Some(b) => {
+                            assert!(seen.insert(b));
+                            assert!(met.insert((a.min(b), a.max(b))), "{} and {} meet twice", a, b);
+                        }
+                        None => assert!(byes.insert(a)),
+                    }
+                }
+            }
+            assert_eq!(met.len() as u32, players * (players - 1) / 2);
+            assert_eq!(byes.len() as u32, if players % 2 == 0 { 0 } else { players });
+        }
+    }
+
+    #[test]
+    fn test_round_robin_schedule_small() {
+        assert!(round_robin_schedule(Vec::<u32>::new()).is_empty());
+        assert_eq!(round_robin_schedule(vec![7]), vec![vec![(7, None)]]);
+        let schedules: HashSet<Vec<Pairings<u32>>> = (0..20).map(|_| round_robin_schedule((0..6).collect())).collect();
+        assert!(schedules.len() > 1);
+    }
+
+    #[test]
+    fn test_assign_leaders() {
+        let parts = vec![vec![1, 2, 3], vec![4, 5], vec![6]];
+        let led = assign_leaders(parts.clone(), false).unwrap();
+        for ((leader, members), part) in led.into_iter().zip(&parts) {
+            let mut rebuilt = members.clone();
+            rebuilt.push(leader);
+            rebuilt.sort();
+            assert_eq!(&rebuilt, part);
+            assert!(members.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+
+    #[test]
+    fn test_assign_leaders_is_uniform() {
+        let mut counts = [0; 4];
+        for _ in 0..4000 {
+            let led = assign_leaders(vec![vec![0, 1, 2, 3]], false).unwrap();
+            counts[led[0].0] += 1;
+        }
+        assert!(counts.iter().all(|&count| (800..1200).contains(&count)), "leader counts {:?}", counts);
+    }
+
+    #[test]
+    fn test_assign_leaders_empty_parts() {
+        let parts = vec![vec![1], vec![], vec![2]];
+        assert_eq!(assign_leaders(parts.clone(), false), Err(SplitError::EmptyPart { index: 1 }));
+        assert_eq!(assign_leaders(parts, true).unwrap(), vec![(1, vec![]), (2, vec![])]);
+    }
+
+    #[test]
+    fn test_assign_leaders_seeded() {
+        let parts: Vec<Vec<u32>> = vec![(0..10).collect(), (10..20).collect()];
+        let first = assign_leaders_seeded(parts.clone(), false, 9).unwrap();
+        assert_eq!(first, assign_leaders_seeded(parts, false, 9).unwrap());
+    }
+
+    #[test]
+    fn test_pair_coverage() {
+        assert_eq!(pair_coverage::<u32>(&[]), 0);
+        let schedule = vec![vec![vec![1, 2, 3]], vec![vec![3, 2], vec![4]]];
+        assert_eq!(pair_coverage(&schedule), 3);
+    }
+}
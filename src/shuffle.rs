@@ -0,0 +1,676 @@
+//! Shuffles with extra constraints on the resulting order.
+
+#[cfg(feature = "std")]
+use crate::NoValidArrangement;
+use crate::RangeError;
+#[cfg(feature = "std")]
+use crate::SampleError;
+#[cfg(feature = "std")]
+use alloc::collections::BinaryHeap;
+#[cfg(feature = "std")]
+use alloc::vec;
+use alloc::vec::Vec;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use core::ops::Range;
+use rand::seq::{index, SliceRandom};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+/// Shuffles the entries of a HashMap into a vec.
+///
+/// The iteration order of a `HashMap` looks random, but it is neither uniform nor controllable; this function
+/// shuffles explicitly with the given RNG instead. Note that the iteration order also differs between instances, so
+/// a seeded RNG alone does not make the result reproducible. Use [`shuffled_entries_seeded`] for that.
+/// # Arguments
+/// * `map` - The map whose entries are shuffled.
+/// * `rng` - The random number generator used for shuffling.
+///
+/// # Returns
+/// All `(key, value)` pairs of `map` in uniformly random order.
+/// # Example
+/// ```
+/// use rust_helpers::shuffled_entries;
+/// use std::collections::HashMap;
+/// let scores = HashMap::from([("Ann", 3), ("Ben", 5), ("Cem", 4)]);
+/// let entries = shuffled_entries(scores, &mut rand::rng());
+/// assert_eq!(entries.len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn shuffled_entries<K, V, R: Rng + ?Sized>(map: HashMap<K, V>, rng: &mut R) -> Vec<(K, V)> {
+    let mut entries: Vec<(K, V)> = map.into_iter().collect();
+    entries.shuffle(rng);
+    entries
+}
+
+/// Like [`shuffled_entries`], but returns only the keys.
+#[cfg(feature = "std")]
+pub fn shuffled_keys<K, V, R: Rng + ?Sized>(map: HashMap<K, V>, rng: &mut R) -> Vec<K> {
+    shuffled_entries(map, rng).into_iter().map(|(k, _)| k).collect()
+}
+
+/// Like [`shuffled_entries`], but returns only the values.
+#[cfg(feature = "std")]
+pub fn shuffled_values<K, V, R: Rng + ?Sized>(map: HashMap<K, V>, rng: &mut R) -> Vec<V> {
+    shuffled_entries(map, rng).into_iter().map(|(_, v)| v).collect()
+}
+
+/// Shuffles the entries of a HashMap into a vec with a seeded RNG.
+///
+/// The entries are sorted by key before shuffling, because the iteration order of a `HashMap` differs between
+/// instances. This way the same map and seed always produce identical output.
+/// # Arguments
+/// * `map` - The map whose entries are shuffled. Its keys must implement the `Ord` trait.
+/// * `seed` - The seed used to initialize the random number generator.
+///
+/// # Returns
+/// All `(key, value)` pairs of `map` in random order.
+/// # Example
+/// ```
+/// use rust_helpers::shuffled_entries_seeded;
+/// use std::collections::HashMap;
+/// let scores = HashMap::from([("Ann", 3), ("Ben", 5), ("Cem", 4)]);
+/// assert_eq!(shuffled_entries_seeded(scores.clone(), 42), shuffled_entries_seeded(scores, 42));
+/// ```
+#[cfg(feature = "std")]
+pub fn shuffled_entries_seeded<K: Ord, V>(map: HashMap<K, V>, seed: u64) -> Vec<(K, V)> {
+    let mut entries: Vec<(K, V)> = map.into_iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries.shuffle(&mut StdRng::seed_from_u64(seed));
+    entries
+}
+
+/// Like [`shuffled_entries_seeded`], but returns only the keys.
+#[cfg(feature = "std")]
+pub fn shuffled_keys_seeded<K: Ord, V>(map: HashMap<K, V>, seed: u64) -> Vec<K> {
+    shuffled_entries_seeded(map, seed).into_iter().map(|(k, _)| k).collect()
+}
+
+/// Like [`shuffled_entries_seeded`], but returns only the values.
+#[cfg(feature = "std")]
+pub fn shuffled_values_seeded<K: Ord, V>(map: HashMap<K, V>, seed: u64) -> Vec<V> {
+    shuffled_entries_seeded(map, seed).into_iter().map(|(_, v)| v).collect()
+}
+
+/// Moves `k` uniformly chosen elements of a slice to its front, in random order.
+///
+/// Only the first `k` steps of a Fisher-Yates shuffle are performed, so this costs `O(k)` regardless of the length
+/// of the slice. The elements after the first `k` are the ones that were not chosen, in unspecified order.
+/// # Arguments
+/// * `slice` - The elements to shuffle. A `&mut Vec<T>` works as well.
+/// * `k` - The number of elements to choose. If `k` is at least the length of the slice, the whole slice is shuffled.
+/// * `rng` - The random number generator used for shuffling.
+///
+/// # Returns
+/// The `k` chosen elements and the remaining elements, as two halves of `slice`.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::partial_shuffle_k;
+/// let mut tickets: Vec<u32> = (1..=1000).collect();
+/// let (winners, rest) = partial_shuffle_k(&mut tickets, 3, &mut StdRng::seed_from_u64(7));
+/// assert_eq!(winners.len(), 3);
+/// assert_eq!(rest.len(), 997);
+/// ```
+pub fn partial_shuffle_k<'a, T, R: Rng + ?Sized>(slice: &'a mut [T], k: usize, rng: &mut R) -> (&'a mut [T], &'a mut [T]) {
+    for (i, j) in partial_shuffle_swaps(slice.len(), k, rng) {
+        slice.swap(i, j);
+    }
+    slice.split_at_mut(k.min(slice.len()))
+}
+
+/// The swaps of the first `k` steps of a Fisher-Yates shuffle of `len` elements, shared by [`partial_shuffle_k`] and
+/// [`sample_n`](crate::sample_n) so that both consume the RNG in the same way.
+pub(crate) fn partial_shuffle_swaps<R: Rng + ?Sized>(len: usize, k: usize, rng: &mut R) -> impl Iterator<Item = (usize, usize)> {
+    (0..k.min(len)).map(move |i| (i, rng.random_range(i..len)))
+}
+
+/// Like [`partial_shuffle_k`], but shuffles with an RNG seeded from `seed`, so the result is reproducible.
+/// # Example
+/// ```
+/// use rust_helpers::partial_shuffle_k_seeded;
+/// let mut first: Vec<u32> = (0..100).collect();
+/// let mut second = first.clone();
+/// assert_eq!(partial_shuffle_k_seeded(&mut first, 5, 42).0, partial_shuffle_k_seeded(&mut second, 5, 42).0);
+/// ```
+pub fn partial_shuffle_k_seeded<T>(slice: &mut [T], k: usize, seed: u64) -> (&mut [T], &mut [T]) {
+    partial_shuffle_k(slice, k, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Shuffles only the elements of a slice that lie within `range`, leaving all others in place.
+/// # Arguments
+/// * `slice` - The elements to shuffle. A `&mut Vec<T>` works as well.
+/// * `range` - The positions to shuffle.
+/// * `rng` - The random number generator used for shuffling.
+///
+/// # Returns
+/// [`RangeError`] if `range` is decreasing or reaches past the end of `slice`. The slice is not changed then.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::{shuffle_range, RangeError};
+/// let mut rng = StdRng::seed_from_u64(3);
+/// let mut lineup = vec!["Intro", "A", "B", "C", "D", "Outro"];
+/// shuffle_range(&mut lineup, 1..5, &mut rng).unwrap();
+/// assert_eq!((lineup[0], lineup[5]), ("Intro", "Outro"));
+/// assert_eq!(shuffle_range(&mut lineup, 4..7, &mut rng), Err(RangeError { start: 4, end: 7, len: 6 }));
+/// ```
+pub fn shuffle_range<T, R: Rng + ?Sized>(slice: &mut [T], range: Range<usize>, rng: &mut R) -> Result<(), RangeError> {
+    let Range { start, end } = range;
+    match slice.get_mut(start..end) {
+        Some(part) => {
+            part.shuffle(rng);
+            Ok(())
+        }
+        None => Err(RangeError { start, end, len: slice.len() }),
+    }
+}
+
+/// Shuffles the elements of a slice from `from_index` on, keeping the ones before it in place.
+///
+/// Shorthand for [`shuffle_range`] with the range `from_index..slice.len()`, e.g. to keep a captain or header in
+/// front.
+/// # Returns
+/// [`RangeError`] if `from_index` is greater than the length of `slice`.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::shuffle_tail;
+/// let mut team = vec!["Captain", "Ann", "Ben", "Cem"];
+/// shuffle_tail(&mut team, 1, &mut StdRng::seed_from_u64(1)).unwrap();
+/// assert_eq!(team[0], "Captain");
+/// ```
+pub fn shuffle_tail<T, R: Rng + ?Sized>(slice: &mut [T], from_index: usize, rng: &mut R) -> Result<(), RangeError> {
+    let len = slice.len();
+    shuffle_range(slice, from_index..len.max(from_index), rng)
+}
+
+/// Rotates a slice to the left by a uniformly random offset, keeping the circular order of its elements.
+///
+/// Useful for "who starts first" situations: the order stays the same, only the starting point is random.
+/// # Arguments
+/// * `slice` - The elements to rotate. A `&mut Vec<T>` works as well.
+/// * `rng` - The random number generator used for choosing the offset.
+///
+/// # Returns
+/// The offset in `0..slice.len()`, so that the element at index `offset` moved to the front. Slices with fewer than
+/// two elements are left as they are, and 0 is returned.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::rotate_random;
+/// let mut players = vec!["Ann", "Ben", "Cem", "Dana"];
+/// let offset = rotate_random(&mut players, &mut StdRng::seed_from_u64(1));
+/// assert_eq!(players[(4 - offset) % 4], "Ann");
+/// ```
+pub fn rotate_random<T, R: Rng + ?Sized>(slice: &mut [T], rng: &mut R) -> usize {
+    if slice.len() < 2 {
+        return 0;
+    }
+    let offset = rng.random_range(0..slice.len());
+    slice.rotate_left(offset);
+    offset
+}
+
+/// Like [`rotate_random`], but takes ownership of the vec and returns it together with the offset.
+/// # Example
+/// ```
+/// use rust_helpers::rotated_random;
+/// let (players, offset) = rotated_random(vec![1, 2, 3]);
+/// assert_eq!(players[0], offset + 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn rotated_random<T>(vec: Vec<T>) -> (Vec<T>, usize) {
+    rotated_random_with_rng(vec, &mut rand::rng())
+}
+
+/// Like [`rotated_random`], but rotates with the given RNG.
+pub fn rotated_random_with_rng<T, R: Rng + ?Sized>(mut vec: Vec<T>, rng: &mut R) -> (Vec<T>, usize) {
+    let offset = rotate_random(&mut vec, rng);
+    (vec, offset)
+}
+
+/// Swaps `swaps` random pairs of elements, as a small perturbation of an existing order.
+///
+/// Each swap exchanges two different positions, so every swap changes the order unless the two elements are equal.
+/// Slices with fewer than two elements are left as they are.
+/// # Arguments
+/// * `slice` - The elements to perturb. A `&mut Vec<T>` works as well.
+/// * `swaps` - The number of swaps to perform.
+/// * `rng` - The random number generator used for choosing the pairs.
+///
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::swap_random_pairs;
+/// let mut order = vec![1, 2, 3, 4, 5];
+/// swap_random_pairs(&mut order, 1, &mut StdRng::seed_from_u64(1));
+/// assert_eq!(order.iter().enumerate().filter(|(i, e)| **e != i + 1).count(), 2);
+/// ```
+pub fn swap_random_pairs<T, R: Rng + ?Sized>(slice: &mut [T], swaps: usize, rng: &mut R) {
+    if slice.len() < 2 {
+        return;
+    }
+    for _ in 0..swaps {
+        let pair = index::sample(rng, slice.len(), 2);
+        slice.swap(pair.index(0), pair.index(1));
+    }
+}
+
+/// Swaps one random element of a random part with one random element of another part.
+///
+/// The number of parts and the size of every part stay the same, which makes this a move for local search over
+/// group assignments.
+/// # Arguments
+/// * `parts` - The parts to perturb.
+/// * `rng` - The random number generator used for choosing the elements.
+///
+/// # Returns
+/// The positions `(part, index)` of the two swapped elements, or `None` if fewer than two parts are non-empty. The
+/// parts are not changed then. Swapping the same positions again undoes the swap.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::swap_between_parts;
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let mut teams = vec![vec!["Ann", "Ben"], vec!["Cem", "Dana"]];
+/// let [(a, _), (b, _)] = swap_between_parts(&mut teams, &mut rng).unwrap();
+/// assert_ne!(a, b);
+/// assert_eq!(teams.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 2]);
+/// assert!(swap_between_parts(&mut vec![vec![1, 2], vec![]], &mut rng).is_none());
+/// ```
+pub fn swap_between_parts<T, R: Rng + ?Sized>(parts: &mut [Vec<T>], rng: &mut R) -> Option<[(usize, usize); 2]> {
+    let non_empty: Vec<usize> = (0..parts.len()).filter(|&p| !parts[p].is_empty()).collect();
+    if non_empty.len() < 2 {
+        return None;
+    }
+    let pair = index::sample(rng, non_empty.len(), 2);
+    let (a, b) = (non_empty[pair.index(0)], non_empty[pair.index(1)]);
+    let (i, j) = (rng.random_range(0..parts[a].len()), rng.random_range(0..parts[b].len()));
+    let (low, high) = if a < b { ((a, i), (b, j)) } else { ((b, j), (a, i)) };
+    let (left, right) = parts.split_at_mut(high.0);
+    core::mem::swap(&mut left[low.0][low.1], &mut right[0][high.1]);
+    Some([(a, i), (b, j)])
+}
+
+/// Shuffles weighted items so that items with a larger weight tend to come first.
+///
+/// Every item gets the key `u^(1/w)`, compared as `ln(u) / w`, for a uniform `u` and its weight `w`, and the items
+/// are sorted by descending key. The resulting order is the same as drawing the items one after another, each with
+/// probability proportional to its weight among the items not drawn yet, as in [`crate::sample_weighted`].
+///
+/// Items with a weight of zero are never drawn this way; they are placed after all other items, in their original
+/// order.
+/// # Arguments
+/// * `items` - The elements to shuffle, each with its weight.
+/// * `rng` - The random number generator used for shuffling.
+///
+/// # Returns
+/// The elements in their new order, or [`SampleError::InvalidWeight`] for a negative, infinite or NaN weight.
+/// # Example
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use rust_helpers::weighted_shuffle;
+/// let queue = vec![("urgent", 10.0), ("normal", 2.0), ("someday", 0.0), ("low", 1.0)];
+/// let order = weighted_shuffle(queue, &mut StdRng::seed_from_u64(5)).unwrap();
+/// assert_eq!(order.len(), 4);
+/// assert_eq!(order[3], "someday");
+/// ```
+#[cfg(feature = "std")]
+pub fn weighted_shuffle<T, R: Rng + ?Sized>(items: Vec<(T, f64)>, rng: &mut R) -> Result<Vec<T>, SampleError> {
+    if let Some(index) = items.iter().position(|(_, w)| !w.is_finite() || *w < 0.0) {
+        return Err(SampleError::InvalidWeight { index });
+    }
+    let mut keyed: Vec<(f64, T)> = items
+        .into_iter()
+        .map(|(e, w)| {
+            let key = if w > 0.0 { (1.0 - rng.random::<f64>()).ln() / w } else { f64::NEG_INFINITY };
+            (key, e)
+        })
+        .collect();
+    keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+    Ok(keyed.into_iter().map(|(_, e)| e).collect())
+}
+
+/// Shuffles a vec so that no two neighbouring elements share the same key.
+///
+/// Useful for playlists or quizzes where two songs by the same artist or two questions of the same category should
+/// not follow each other. The order is drawn at random among the valid arrangements: at every position, a random
+/// remaining element is picked, except when one key has to be placed next to keep the rest arrangeable.
+/// # Arguments
+/// * `vec` - The vector to shuffle.
+/// * `key_fn` - Returns the key of an element. Neighbours must have different keys.
+///
+/// # Returns
+/// The shuffled vector, or [`NoValidArrangement`] if one key holds more than half of the elements, rounded up.
+/// # Example
+/// ```
+/// use rust_helpers::{shuffle_no_adjacent, NoValidArrangement};
+/// let songs = vec![("Abba", "Waterloo"), ("Abba", "SOS"), ("Queen", "Innuendo"), ("Muse", "Uprising")];
+/// let playlist = shuffle_no_adjacent(songs, |song| song.0).unwrap();
+/// assert!(playlist.windows(2).all(|pair| pair[0].0 != pair[1].0));
+///
+/// let err = shuffle_no_adjacent(vec![1, 1, 1, 2], |&n| n).unwrap_err();
+/// assert_eq!(err, NoValidArrangement { count: 3, len: 4 });
+/// ```
+#[cfg(feature = "std")]
+pub fn shuffle_no_adjacent<T, K: Hash + Eq, F: Fn(&T) -> K>(vec: Vec<T>, key_fn: F) -> Result<Vec<T>, NoValidArrangement> {
+    shuffle_no_adjacent_with_rng(vec, key_fn, &mut rand::rng())
+}
+
+/// Like [`shuffle_no_adjacent`], but shuffles with the given RNG.
+#[cfg(feature = "std")]
+pub fn shuffle_no_adjacent_with_rng<T, K: Hash + Eq, F: Fn(&T) -> K, R: Rng + ?Sized>(vec: Vec<T>, key_fn: F, rng: &mut R) -> Result<Vec<T>, NoValidArrangement> {
+    let len = vec.len();
+    let mut group_of_key = HashMap::new();
+    let mut groups: Vec<Vec<T>> = Vec::new();
+    for e in vec {
+        let group = *group_of_key.entry(key_fn(&e)).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(e);
+    }
+    let count = groups.iter().map(Vec::len).max().unwrap_or(0);
+    if count > len.div_ceil(2) {
+        return Err(NoValidArrangement { count, len });
+    }
+    for group in &mut groups {
+        group.shuffle(rng);
+    }
+
+    // One ticket per element names its group, so drawing a ticket picks a group weighted by its size. Tickets of
+    // elements that were placed because their group was forced are discarded lazily through `stale`.
+    let mut tickets: Vec<usize> = groups.iter().enumerate().flat_map(|(g, group)| vec![g; group.len()]).collect();
+    let mut stale = vec![0; groups.len()];
+    let mut largest: BinaryHeap<(usize, usize)> = groups.iter().enumerate().map(|(g, group)| (group.len(), g)).collect();
+    let mut previous = None;
+    let mut shuffled = Vec::with_capacity(len);
+    for remaining in (1..=len).rev() {
+        while let Some(&(size, g)) = largest.peek() {
+            if size == groups[g].len() {
+                break;
+            }
+            largest.pop();
+        }
+        // A group holding more than half of the remaining elements must take every other position from here on.
+        let group = match largest.peek() {
+            Some(&(size, g)) if 2 * size > remaining => {
+                stale[g] += 1;
+                g
+            }
+            _ => loop {
+                let ticket = rng.random_range(0..tickets.len());
+                let g = tickets[ticket];
+                if stale[g] > 0 {
+                    stale[g] -= 1;
+                    tickets.swap_remove(ticket);
+                } else if Some(g) != previous {
+                    tickets.swap_remove(ticket);
+                    break g;
+                }
+            },
+        };
+        shuffled.extend(groups[group].pop());
+        largest.push((groups[group].len(), group));
+        previous = Some(group);
+    }
+    Ok(shuffled)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn assert_no_equal_neighbours(order: &[(char, usize)]) {
+        assert!(order.windows(2).all(|pair| pair[0].0 != pair[1].0), "{:?}", order);
+    }
+
+    fn tagged(counts: &[(char, usize)]) -> Vec<(char, usize)> {
+        counts.iter().flat_map(|&(key, count)| (0..count).map(move |i| (key, i))).collect()
+    }
+
+    #[test]
+    fn test_shuffled_entries_preserves_entries() {
+        let map: HashMap<u32, String> = (0..50).map(|i| (i, i.to_string())).collect();
+        let mut entries = shuffled_entries(map.clone(), &mut StdRng::seed_from_u64(28));
+        entries.sort();
+        assert_eq!(entries, (0..50).map(|i| (i, i.to_string())).collect::<Vec<_>>());
+        let mut keys = shuffled_keys(map.clone(), &mut StdRng::seed_from_u64(28));
+        keys.sort();
+        assert_eq!(keys, (0..50).collect::<Vec<_>>());
+        let mut values = shuffled_values(map, &mut StdRng::seed_from_u64(28));
+        values.sort();
+        let mut expected: Vec<String> = (0..50).map(|i| i.to_string()).collect();
+        expected.sort();
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_shuffled_entries_seeded_is_reproducible() {
+        // Two maps built separately use different hash seeds and usually iterate in different orders.
+        let first: HashMap<u32, char> = (0..30).map(|i| (i, char::from(b'a' + (i % 26) as u8))).collect();
+        let second: HashMap<u32, char> = (0..30).rev().map(|i| (i, char::from(b'a' + (i % 26) as u8))).collect();
+        let entries = shuffled_entries_seeded(first.clone(), 9);
+        assert_eq!(entries, shuffled_entries_seeded(second.clone(), 9));
+        assert_ne!(entries.iter().map(|e| e.0).collect::<Vec<_>>(), (0..30).collect::<Vec<_>>());
+        assert_eq!(shuffled_keys_seeded(first.clone(), 9), entries.iter().map(|e| e.0).collect::<Vec<_>>());
+        assert_eq!(shuffled_values_seeded(second, 9), entries.iter().map(|e| e.1).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_partial_shuffle_k_chooses_uniformly() {
+        let n = 6;
+        let runs = 6000;
+        let mut counts = vec![0_u32; n];
+        let mut rng = StdRng::seed_from_u64(22);
+        for _ in 0..runs {
+            let mut items: Vec<usize> = (0..n).collect();
+            let (chosen, rest) = partial_shuffle_k(&mut items, 2, &mut rng);
+            assert_eq!((chosen.len(), rest.len()), (2, 4));
+            for &e in chosen.iter() {
+                counts[e] += 1;
+            }
+        }
+        let expected = (runs * 2 / n) as f64;
+        let chi_square: f64 = counts.iter().map(|&c| (c as f64 - expected).powi(2) / expected).sum();
+        // 5 degrees of freedom; the 99.9% quantile of the chi-square distribution is about 20.5.
+        assert!(chi_square < 20.5, "{}: {:?}", chi_square, counts);
+    }
+
+    #[test]
+    fn test_partial_shuffle_k_beyond_len_shuffles_everything() {
+        let mut rng = StdRng::seed_from_u64(23);
+        let mut items: Vec<u32> = (0..20).collect();
+        let (chosen, rest) = partial_shuffle_k(&mut items, 50, &mut rng);
+        assert_eq!(chosen.len(), 20);
+        assert!(rest.is_empty());
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+        assert_eq!(partial_shuffle_k::<u8, _>(&mut [], 3, &mut rng).0.len(), 0);
+
+        let mut first: Vec<u32> = (0..20).collect();
+        let mut second = first.clone();
+        partial_shuffle_k_seeded(&mut first, 20, 9);
+        partial_shuffle_k(&mut second, 20, &mut StdRng::seed_from_u64(9));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_shuffle_range_touches_only_the_range() {
+        let mut rng = StdRng::seed_from_u64(24);
+        let original: Vec<u32> = (0..30).collect();
+        for _ in 0..50 {
+            let mut items = original.clone();
+            shuffle_range(&mut items, 10..20, &mut rng).unwrap();
+            assert_eq!(items[..10], original[..10]);
+            assert_eq!(items[20..], original[20..]);
+            let mut inside = items[10..20].to_vec();
+            inside.sort();
+            assert_eq!(inside, original[10..20]);
+        }
+        let mut items = original.clone();
+        shuffle_tail(&mut items, 1, &mut rng).unwrap();
+        assert_eq!(items[0], 0);
+        assert_ne!(items, original);
+        assert_eq!(shuffle_tail(&mut items, 30, &mut rng), Ok(()));
+    }
+
+    #[test]
+    fn test_shuffle_range_rejects_out_of_bounds() {
+        let mut rng = StdRng::seed_from_u64(25);
+        let mut items = vec![1, 2, 3];
+        assert_eq!(shuffle_range(&mut items, 1..4, &mut rng), Err(RangeError { start: 1, end: 4, len: 3 }));
+        #[allow(clippy::reversed_empty_ranges)]
+        let decreasing = 2..1;
+        assert_eq!(shuffle_range(&mut items, decreasing, &mut rng), Err(RangeError { start: 2, end: 1, len: 3 }));
+        assert_eq!(shuffle_tail(&mut items, 4, &mut rng), Err(RangeError { start: 4, end: 4, len: 3 }));
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_rotate_random_is_a_uniform_rotation() {
+        let mut rng = StdRng::seed_from_u64(29);
+        let original: Vec<u32> = (0..5).collect();
+        let mut counts = [0_u32; 5];
+        for _ in 0..2500 {
+            let (rotated, offset) = rotated_random_with_rng(original.clone(), &mut rng);
+            let mut expected = original.clone();
+            expected.rotate_left(offset);
+            assert_eq!(rotated, expected);
+            counts[offset] += 1;
+        }
+        // Each offset is expected 500 times.
+        assert!(counts.iter().all(|&count| (420..580).contains(&count)), "{:?}", counts);
+    }
+
+    #[test]
+    fn test_rotate_random_short_slices() {
+        let mut rng = StdRng::seed_from_u64(30);
+        let mut single = vec!['x'];
+        assert_eq!(rotate_random(&mut single, &mut rng), 0);
+        assert_eq!(single, vec!['x']);
+        assert_eq!(rotate_random::<char, _>(&mut [], &mut rng), 0);
+        assert_eq!(rotated_random(Vec::<u8>::new()), (vec![], 0));
+    }
+
+    #[test]
+    fn test_swap_random_pairs_preserves_elements() {
+        let mut rng = StdRng::seed_from_u64(31);
+        let original: Vec<u32> = (0..10).collect();
+        let mut items = original.clone();
+        swap_random_pairs(&mut items, 1, &mut rng);
+        assert_eq!(items.iter().zip(&original).filter(|(a, b)| a != b).count(), 2);
+        swap_random_pairs(&mut items, 25, &mut rng);
+        let mut sorted = items.clone();
+        sorted.sort();
+        assert_eq!(sorted, original);
+        let mut single = vec![1];
+        swap_random_pairs(&mut single, 5, &mut rng);
+        assert_eq!(single, vec![1]);
+    }
+
+    #[test]
+    fn test_swap_between_parts_keeps_sizes() {
+        let mut rng = StdRng::seed_from_u64(32);
+        let original: Vec<Vec<u32>> = vec![(0..3).collect(), vec![], (3..8).collect(), (8..10).collect()];
+        let mut parts = original.clone();
+        for _ in 0..100 {
+            let [(a, i), (b, j)] = swap_between_parts(&mut parts, &mut rng).unwrap();
+            assert_ne!(a, b);
+            assert!(a != 1 && b != 1);
+            assert!(i < parts[a].len() && j < parts[b].len());
+            assert_eq!(parts.iter().map(Vec::len).collect::<Vec<_>>(), vec![3, 0, 5, 2]);
+        }
+        let mut all: Vec<u32> = parts.concat();
+        all.sort();
+        assert_eq!(all, (0..10).collect::<Vec<_>>());
+
+        let mut parts = original.clone();
+        let [(a, i), (b, j)] = swap_between_parts(&mut parts, &mut rng).unwrap();
+        assert_eq!((parts[a][i], parts[b][j]), (original[b][j], original[a][i]));
+
+        let mut lonely = vec![vec![1, 2, 3], vec![]];
+        assert_eq!(swap_between_parts(&mut lonely, &mut rng), None);
+        assert_eq!(lonely, vec![vec![1, 2, 3], vec![]]);
+    }
+
+    #[test]
+    fn test_weighted_shuffle_prefers_heavy_items() {
+        let mut rng = StdRng::seed_from_u64(26);
+        let runs = 2000;
+        let mut heavy_first = 0;
+        for _ in 0..runs {
+            let order = weighted_shuffle(vec![("light", 1.0), ("heavy", 10.0)], &mut rng).unwrap();
+            if order[0] == "heavy" {
+                heavy_first += 1;
+            }
+        }
+        // The heavy item comes first with probability 10 / 11, i.e. about 1818 times.
+        assert!((1740..1890).contains(&heavy_first), "{}", heavy_first);
+    }
+
+    #[test]
+    fn test_weighted_shuffle_zero_and_invalid_weights() {
+        let mut rng = StdRng::seed_from_u64(27);
+        let items = vec![("z1", 0.0), ("a", 1.0), ("z2", 0.0), ("b", 3.0), ("z3", 0.0)];
+        for _ in 0..50 {
+            let order = weighted_shuffle(items.clone(), &mut rng).unwrap();
+            assert_eq!(order[2..], ["z1", "z2", "z3"]);
+            assert!(order[..2].contains(&"a") && order[..2].contains(&"b"));
+        }
+        assert_eq!(weighted_shuffle(vec![(1, 1.0), (2, -1.0)], &mut rng), Err(SampleError::InvalidWeight { index: 1 }));
+        assert_eq!(weighted_shuffle(vec![(1, f64::NAN)], &mut rng), Err(SampleError::InvalidWeight { index: 0 }));
+        assert_eq!(weighted_shuffle(Vec::<(u8, f64)>::new(), &mut rng), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_shuffle_no_adjacent_keeps_equal_keys_apart() {
+        let mut rng = StdRng::seed_from_u64(20);
+        let input = tagged(&[('a', 5), ('b', 3), ('c', 3), ('d', 1)]);
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..500 {
+            let order = shuffle_no_adjacent_with_rng(input.clone(), |e| e.0, &mut rng).unwrap();
+            assert_no_equal_neighbours(&order);
+            let mut sorted = order.clone();
+            sorted.sort();
+            assert_eq!(sorted, input);
+            seen.insert(order.iter().map(|e| e.0).collect::<String>());
+        }
+        assert!(seen.len() > 50, "only {} different key patterns", seen.len());
+    }
+
+    #[test]
+    fn test_shuffle_no_adjacent_tight_case() {
+        let mut rng = StdRng::seed_from_u64(21);
+        for _ in 0..200 {
+            let order = shuffle_no_adjacent_with_rng(tagged(&[('a', 4), ('b', 2), ('c', 1)]), |e| e.0, &mut rng).unwrap();
+            assert_no_equal_neighbours(&order);
+            assert_eq!(order.iter().step_by(2).filter(|e| e.0 == 'a').count(), 4);
+
+            let order = shuffle_no_adjacent_with_rng(tagged(&[('a', 3), ('b', 3)]), |e| e.0, &mut rng).unwrap();
+            assert_no_equal_neighbours(&order);
+        }
+    }
+
+    #[test]
+    fn test_shuffle_no_adjacent_impossible_case() {
+        let input = tagged(&[('a', 5), ('b', 2), ('c', 1)]);
+        assert_eq!(shuffle_no_adjacent(input, |e| e.0), Err(NoValidArrangement { count: 5, len: 8 }));
+        assert_eq!(shuffle_no_adjacent(vec![7, 7], |&n| n), Err(NoValidArrangement { count: 2, len: 2 }));
+        assert_eq!(shuffle_no_adjacent(vec![7], |&n| n), Ok(vec![7]));
+        assert_eq!(shuffle_no_adjacent(Vec::<u8>::new(), |&n| n), Ok(vec![]));
+    }
+}
@@ -0,0 +1,142 @@
+//! Checks that a split kept every element exactly once and balanced its parts.
+
+use crate::SplitError;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// The differences between an original collection and the parts it was split into, returned by [`verify_split`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplitMismatch<T> {
+    /// Elements of the original that are missing from the parts, with the number of missing copies, in the order of
+    /// their first occurrence in the original.
+    pub missing: Vec<(T, usize)>,
+    /// Elements that occur in the parts more often than in the original, with the index of the part holding each
+    /// surplus copy.
+    pub extra: Vec<(T, usize)>,
+}
+
+impl<T: fmt::Debug> fmt::Display for SplitMismatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let missing = self.missing.iter().map(|(e, count)| format!("{} missing {:?}", count, e));
+        let extra = self.extra.iter().map(|(e, part)| format!("extra {:?} in part {}", e, part));
+        write!(f, "split does not match the original: {}", missing.chain(extra).collect::<Vec<_>>().join(", "))
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for SplitMismatch<T> {}
+
+/// Checks that `parts` contain exactly the elements of `original`, each as often as in the original.
+///
+/// Runs in linear time by counting elements in a [`HashMap`].
+/// # Arguments
+/// * `original` - The elements before the split.
+/// * `parts` - The parts after the split.
+///
+/// # Returns
+/// `Ok(())` if the parts are a rearrangement of the original, or a [`SplitMismatch`] naming every missing element
+/// and every surplus copy together with the part it is in.
+/// # Example
+/// ```
+/// use rust_helpers::{split_rand_vec_eq, verify_split};
+/// let vec = vec![1, 2, 3, 4, 5];
+/// let parts = split_rand_vec_eq(vec.clone(), 2);
+/// assert!(verify_split(&vec, &parts).is_ok());
+///
+/// let corrupted = vec![vec![1, 2, 2], vec![4, 5]];
+/// let mismatch = verify_split(&vec, &corrupted).unwrap_err();
+/// assert_eq!(mismatch.missing, vec![(3, 1)]);
+/// assert_eq!(mismatch.extra, vec![(2, 0)]);
+/// ```
+pub fn verify_split<T: Hash + Eq + Clone>(original: &[T], parts: &[Vec<T>]) -> Result<(), SplitMismatch<T>> {
+    let mut remaining: HashMap<&T, usize> = HashMap::with_capacity(original.len());
+    for e in original {
+        *remaining.entry(e).or_insert(0) += 1;
+    }
+    let mut extra = Vec::new();
+    for (index, part) in parts.iter().enumerate() {
+        for e in part {
+            match remaining.get_mut(e) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => extra.push((e.clone(), index)),
+            }
+        }
+    }
+    let mut missing = Vec::new();
+    for e in original {
+        if let Some(count) = remaining.get_mut(e).filter(|count| **count > 0) {
+            missing.push((e.clone(), *count));
+            *count = 0;
+        }
+    }
+    if missing.is_empty() && extra.is_empty() { Ok(()) } else { Err(SplitMismatch { missing, extra }) }
+}
+
+/// Checks that the sizes of `parts` differ by at most `max_diff`.
+/// # Arguments
+/// * `parts` - The parts after a split.
+/// * `max_diff` - The largest allowed difference between the largest and the smallest part.
+///
+/// # Returns
+/// `Ok(())` if the parts are balanced, or [`SplitError::Unbalanced`] with the smallest and largest part size.
+/// No parts at all count as balanced.
+/// # Example
+/// ```
+/// use rust_helpers::{split_rand_vec_eq, verify_balanced};
+/// let parts = split_rand_vec_eq((0..10).collect::<Vec<i32>>(), 3);
+/// assert!(verify_balanced(&parts, 1).is_ok());
+/// assert!(verify_balanced(&[vec![1, 2, 3], vec![4]], 1).is_err());
+/// ```
+pub fn verify_balanced<T>(parts: &[Vec<T>], max_diff: usize) -> Result<(), SplitError> {
+    let smallest = parts.iter().map(Vec::len).min().unwrap_or(0);
+    let largest = parts.iter().map(Vec::len).max().unwrap_or(0);
+    if largest - smallest > max_diff { Err(SplitError::Unbalanced { smallest, largest }) } else { Ok(()) }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::split_rand_vec_eq;
+
+    #[test]
+    fn test_verify_split() {
+        let vec = vec!["a", "b", "b", "c"];
+        assert_eq!(verify_split(&vec, &split_rand_vec_eq(vec.clone(), 3)), Ok(()));
+        assert_eq!(verify_split::<i32>(&[], &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_split_pinpoints_corruption() {
+        let vec = vec!["a", "b", "b", "c"];
+        let lost = vec![vec!["a", "b"], vec!["c"]];
+        assert_eq!(verify_split(&vec, &lost), Err(SplitMismatch { missing: vec![("b", 1)], extra: vec![] }));
+
+        let duplicated = vec![vec!["a", "b"], vec!["c", "b"], vec!["a"]];
+        assert_eq!(verify_split(&vec, &duplicated), Err(SplitMismatch { missing: vec![], extra: vec![("a", 2)] }));
+
+        let swapped = vec![vec!["a", "d"], vec!["b", "b"]];
+        let mismatch = verify_split(&vec, &swapped).unwrap_err();
+        assert_eq!(mismatch, SplitMismatch { missing: vec![("c", 1)], extra: vec![("d", 0)] });
+        assert_eq!(mismatch.to_string(), "split does not match the original: 1 missing \"c\", extra \"d\" in part 0");
+    }
+
+    #[test]
+    fn test_verify_balanced() {
+        assert_eq!(verify_balanced(&[vec![1, 2], vec![3]], 1), Ok(()));
+        assert_eq!(verify_balanced::<i32>(&[], 0), Ok(()));
+        assert_eq!(
+            verify_balanced(&[vec![1, 2, 3], vec![4], vec![5, 6]], 1),
+            Err(SplitError::Unbalanced { smallest: 1, largest: 3 })
+        );
+        assert_eq!(verify_balanced(&[vec![1, 2, 3], vec![4]], 2), Ok(()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_split_mismatch_serde_round_trip() {
+        let mismatch = verify_split(&[1, 2, 3], &[vec![1, 1]]).unwrap_err();
+        let json = serde_json::to_string(&mismatch).unwrap();
+        assert_eq!(serde_json::from_str::<SplitMismatch<i32>>(&json).unwrap(), mismatch);
+    }
+}
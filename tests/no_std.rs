@@ -0,0 +1,57 @@
+//! Uses only the API that is available without the `std` feature, run with `cargo test --no-default-features`.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rust_helpers::{
+    check_sufficient_items, derange_with_rng, flatten_parts_indexed, sample_n_with_rng,
+    split_rand_vec_eq_indexed_with_rng, split_rand_vec_eq_seeded, split_rand_vec_with_rng, train_test_split_seeded,
+    try_split_rand_vec_eq_with_rng, RandomSplitter, SplitError, Strategy,
+};
+
+#[test]
+fn test_splits_with_rng_and_seed() {
+    let vec: Vec<u32> = (0..10).collect();
+    let parts = split_rand_vec_eq_seeded(vec.clone(), 3, 7);
+    assert_eq!(parts, split_rand_vec_eq_seeded(vec.clone(), 3, 7));
+    assert_eq!(parts.iter().map(Vec::len).sum::<usize>(), 10);
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let chunks = split_rand_vec_with_rng(vec.clone(), 4, &mut rng);
+    assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), vec![4, 4, 2]);
+    assert_eq!(try_split_rand_vec_eq_with_rng(vec, 0, &mut rng), Err(SplitError::ZeroParts));
+}
+
+#[test]
+fn test_builder_with_rng() {
+    let splitter = RandomSplitter::new().parts(4).strategy(Strategy::Contiguous);
+    let parts = splitter.split_with_rng((0..10).collect::<Vec<i32>>(), &mut StdRng::seed_from_u64(1)).unwrap();
+    assert_eq!(parts.sizes(), vec![3, 3, 2, 2]);
+}
+
+#[test]
+fn test_indexed_round_trip() {
+    let vec = vec!['a', 'b', 'c', 'd', 'e'];
+    let parts = split_rand_vec_eq_indexed_with_rng(vec.clone(), 2, &mut StdRng::seed_from_u64(2));
+    assert_eq!(flatten_parts_indexed(parts), Ok(vec));
+}
+
+#[test]
+fn test_train_test_split_seeded() {
+    let (train, test) = train_test_split_seeded((0..10).collect::<Vec<_>>(), 0.2, 3).unwrap();
+    assert_eq!((train.len(), test.len()), (8, 2));
+}
+
+#[test]
+fn test_sampling_and_permutations() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let sample = sample_n_with_rng(&[1, 2, 3, 4, 5], 3, &mut rng).unwrap();
+    assert_eq!(sample.len(), 3);
+    let deranged = derange_with_rng(vec![1, 2, 3, 4], &mut rng).unwrap();
+    assert!(deranged.iter().zip([1, 2, 3, 4]).all(|(a, b)| *a != b));
+}
+
+#[test]
+fn test_check_sufficient_items() {
+    assert!(check_sufficient_items(&[("egg", 2)], &["egg", "milk", "egg"]));
+    assert!(!check_sufficient_items(&[("egg", 3)], &["egg", "milk", "egg"]));
+}
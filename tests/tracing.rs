@@ -0,0 +1,100 @@
+//! Checks the events emitted with the `tracing` feature, run with `cargo test --features tracing`.
+#![cfg(all(feature = "tracing", feature = "std"))]
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use rust_helpers::{check_sufficient_items, split_rand_vec_eq_seeded, split_rand_vec_seeded};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Records the name of every span and the fields of every event as `name=value` strings.
+#[derive(Clone, Default)]
+struct Recorder {
+    spans: Arc<Mutex<Vec<String>>>,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+struct FieldsVisitor(Vec<String>);
+
+impl Visit for FieldsVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span.metadata().name().to_string());
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = FieldsVisitor(Vec::new());
+        event.record(&mut visitor);
+        self.events.lock().unwrap().push(visitor.0.join(" "));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn record<F: FnOnce()>(f: F) -> (Vec<String>, Vec<String>) {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), f);
+    let spans = recorder.spans.lock().unwrap().clone();
+    let events = recorder.events.lock().unwrap().clone();
+    (spans, events)
+}
+
+#[test]
+fn test_check_sufficient_items_emits_counts() {
+    let (spans, events) = record(|| {
+        assert!(!check_sufficient_items(&[("apple", 1), ("pear", 2)], &["apple", "pear"]));
+    });
+    assert_eq!(spans, vec!["check_sufficient_items"]);
+    assert_eq!(
+        events,
+        vec![
+            "message=counted requirement required=1 found=1",
+            "message=counted requirement required=2 found=1",
+            "message=requirement not met required=2 found=1",
+        ]
+    );
+}
+
+#[test]
+fn test_split_functions_emit_part_sizes() {
+    let (_, events) = record(|| {
+        split_rand_vec_eq_seeded((0..7).collect::<Vec<u32>>(), 3, 1);
+        split_rand_vec_seeded((0..7).collect::<Vec<u32>>(), 3, 1);
+    });
+    assert_eq!(
+        events,
+        vec![
+            "message=dealt into parts len=7 parts=3 sizes=[3, 2, 2]",
+            "message=splitting into parts len=7 parts=3 sizes=[3, 3, 1]",
+        ]
+    );
+}
+
+#[test]
+fn test_logged_sizes_match_the_returned_parts() {
+    for seed in 0..20 {
+        let mut parts = Vec::new();
+        let (_, events) = record(|| parts = split_rand_vec_eq_seeded((0..7).collect::<Vec<u32>>(), 3, seed));
+        let sizes: Vec<usize> = parts.iter().map(Vec::len).collect();
+        assert_eq!(events, vec![format!("message=dealt into parts len=7 parts=3 sizes={:?}", sizes)]);
+    }
+}
@@ -0,0 +1,22 @@
+//! Browser tests for `wasm32-unknown-unknown`, run with `wasm-pack test --headless --firefox --features wasm`.
+#![cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+
+use rust_helpers::{split_rand_vec_eq, split_rand_vec_eq_seeded};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn test_split_rand_vec_eq_seeded_in_browser() {
+    let vec: Vec<u32> = (0..10).collect();
+    let parts = split_rand_vec_eq_seeded(vec.clone(), 3, 7);
+    assert_eq!(parts, split_rand_vec_eq_seeded(vec, 3, 7));
+    assert_eq!(parts.iter().map(Vec::len).sum::<usize>(), 10);
+}
+
+#[wasm_bindgen_test]
+fn test_split_rand_vec_eq_uses_browser_entropy() {
+    let parts = split_rand_vec_eq((0..10).collect::<Vec<u32>>(), 2);
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts.iter().map(Vec::len).sum::<usize>(), 10);
+}